@@ -34,11 +34,11 @@ pub mod v1 {
     use ruma_events::AnyTimelineEvent;
     #[cfg(feature = "unstable-msc2409")]
     use ruma_events::{receipt::Receipt, AnyToDeviceEvent};
-    #[cfg(feature = "unstable-msc2409")]
-    use serde::Deserializer;
     #[cfg(any(feature = "unstable-msc2409", feature = "unstable-msc3202"))]
     use serde::{Deserialize, Serialize};
     #[cfg(feature = "unstable-msc2409")]
+    use serde::{Deserializer, Serializer};
+    #[cfg(feature = "unstable-msc2409")]
     use serde_json::{value::RawValue as RawJsonValue, Value as JsonValue};
 
     const METADATA: Metadata = metadata! {
@@ -175,7 +175,7 @@ pub mod v1 {
 
     /// Type for passing ephemeral data to homeservers.
     #[cfg(feature = "unstable-msc2409")]
-    #[derive(Clone, Debug, Serialize)]
+    #[derive(Clone, Debug)]
     #[non_exhaustive]
     pub enum Edu {
         /// An EDU representing presence updates for users of the sending homeserver.
@@ -188,7 +188,6 @@ pub mod v1 {
         Typing(TypingContent),
 
         #[doc(hidden)]
-        #[serde(skip)]
         _Custom(JsonValue),
     }
 
@@ -213,11 +212,39 @@ pub mod v1 {
                 "m.presence" => Self::Presence(from_raw_json_value(&content)?),
                 "m.receipt" => Self::Receipt(from_raw_json_value(&content)?),
                 "m.typing" => Self::Typing(from_raw_json_value(&content)?),
-                _ => Self::_Custom(from_raw_json_value(&content)?),
+                _ => Self::_Custom(from_raw_json_value(&json)?),
             })
         }
     }
 
+    #[derive(Serialize)]
+    #[cfg(feature = "unstable-msc2409")]
+    struct EduSerHelper<'a, T> {
+        r#type: &'a str,
+        content: &'a T,
+    }
+
+    #[cfg(feature = "unstable-msc2409")]
+    impl Serialize for Edu {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Self::Presence(content) => {
+                    EduSerHelper { r#type: "m.presence", content }.serialize(serializer)
+                }
+                Self::Receipt(content) => {
+                    EduSerHelper { r#type: "m.receipt", content }.serialize(serializer)
+                }
+                Self::Typing(content) => {
+                    EduSerHelper { r#type: "m.typing", content }.serialize(serializer)
+                }
+                Self::_Custom(json) => json.serialize(serializer),
+            }
+        }
+    }
+
     /// The content for "m.presence" Edu.
     #[cfg(feature = "unstable-msc2409")]
     #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -386,6 +413,85 @@ pub mod v1 {
         }
     }
 
+    #[cfg(all(test, feature = "unstable-msc2409"))]
+    mod edu_tests {
+        use assert_matches2::assert_matches;
+        use js_int::uint;
+        use ruma_common::{owned_room_id, owned_user_id, presence::PresenceState};
+        use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+        use super::{Edu, PresenceContent, PresenceUpdate, TypingContent};
+
+        #[test]
+        fn serialize_edu() {
+            let typing = Edu::Typing(TypingContent::new(
+                owned_room_id!("!room:example.org"),
+                owned_user_id!("@alice:example.org"),
+                true,
+            ));
+            assert_eq!(
+                to_json_value(typing).unwrap(),
+                json!({
+                    "type": "m.typing",
+                    "content": {
+                        "room_id": "!room:example.org",
+                        "user_id": "@alice:example.org",
+                        "typing": true,
+                    },
+                })
+            );
+
+            let presence = Edu::Presence(PresenceContent::new(vec![PresenceUpdate::new(
+                owned_user_id!("@alice:example.org"),
+                PresenceState::Online,
+                uint!(5000),
+            )]));
+            assert_eq!(
+                to_json_value(presence).unwrap(),
+                json!({
+                    "type": "m.presence",
+                    "content": {
+                        "push": [{
+                            "user_id": "@alice:example.org",
+                            "presence": "online",
+                            "last_active_ago": 5000,
+                        }],
+                    },
+                })
+            );
+        }
+
+        #[test]
+        fn deserialize_edu() {
+            let json = json!({
+                "type": "m.receipt",
+                "content": {
+                    "!room:example.org": {
+                        "m.read": {
+                            "@alice:example.org": {
+                                "data": { "ts": 1 },
+                                "event_ids": ["$event:example.org"],
+                            },
+                        },
+                    },
+                },
+            });
+            let edu = from_json_value::<Edu>(json.clone()).unwrap();
+            assert_matches!(&edu, Edu::Receipt(receipt));
+            let receipt_map = receipt.get(&owned_room_id!("!room:example.org")).unwrap();
+            let data = receipt_map.read.get(&owned_user_id!("@alice:example.org")).unwrap();
+            assert_eq!(data.event_ids, ["$event:example.org"]);
+            assert_eq!(to_json_value(&edu).unwrap(), json);
+
+            let json = json!({
+                "type": "dev.ruma.custom",
+                "content": { "foo": "bar" },
+            });
+            let edu = from_json_value::<Edu>(json.clone()).unwrap();
+            assert_eq!(to_json_value(edu).unwrap(), json);
+        }
+    }
+
     #[cfg(feature = "server")]
     #[cfg(test)]
     mod tests {
@@ -59,4 +59,40 @@ pub mod v1 {
             Self { users }
         }
     }
+
+    #[cfg(all(test, feature = "client", feature = "server"))]
+    mod tests {
+        use std::collections::BTreeMap;
+
+        use ruma_common::api::{
+            IncomingRequest as _, MatrixVersion, OutgoingRequest as _, SendAccessToken,
+        };
+
+        use super::Request;
+
+        #[test]
+        fn request_roundtrip() {
+            let mut request = Request::new("irc".to_owned());
+            request.fields = BTreeMap::from([
+                ("nick".to_owned(), "alice".to_owned()),
+                ("server".to_owned(), "irc.example.org".to_owned()),
+            ]);
+
+            let http_request = request
+                .try_into_http_request::<Vec<u8>>(
+                    "https://appservice.example.org",
+                    SendAccessToken::IfRequired("hs_token"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(http_request.uri().path(), "/_matrix/app/v1/thirdparty/user/irc");
+            assert_eq!(http_request.uri().query(), Some("nick=alice&server=irc.example.org"));
+
+            let request = Request::try_from_http_request(http_request, &["irc"]).unwrap();
+            assert_eq!(request.protocol, "irc");
+            assert_eq!(request.fields.len(), 2);
+            assert_eq!(request.fields["nick"], "alice");
+            assert_eq!(request.fields["server"], "irc.example.org");
+        }
+    }
 }
@@ -61,3 +61,31 @@ fn config_with_optional_url() {
     assert_matches!(serde_yaml::from_str(registration_config).unwrap(), Registration { url, .. });
     assert_eq!(url, None);
 }
+
+#[test]
+#[cfg(feature = "unstable-msc2409")]
+fn config_with_receive_ephemeral() {
+    let registration_config = r#"
+        id: "IRC Bridge"
+        url: "http://127.0.0.1:1234"
+        as_token: "30c05ae90a248a4188e620216fa72e349803310ec83e2a77b34fe90be6081f46"
+        hs_token: "312df522183efd404ec1cd22d2ffa4bbc76a8c1ccf541dd692eef281356bb74e"
+        sender_localpart: "_irc_bot"
+        namespaces: {}
+        receive_ephemeral: true
+        "#;
+    let observed: Registration = serde_yaml::from_str(registration_config).unwrap();
+    assert!(observed.receive_ephemeral);
+
+    let registration_config = r#"
+        id: "IRC Bridge"
+        url: "http://127.0.0.1:1234"
+        as_token: "30c05ae90a248a4188e620216fa72e349803310ec83e2a77b34fe90be6081f46"
+        hs_token: "312df522183efd404ec1cd22d2ffa4bbc76a8c1ccf541dd692eef281356bb74e"
+        sender_localpart: "_irc_bot"
+        namespaces: {}
+        de.sorunome.msc2409.push_ephemeral: true
+        "#;
+    let observed: Registration = serde_yaml::from_str(registration_config).unwrap();
+    assert!(observed.receive_ephemeral);
+}
@@ -45,7 +45,7 @@ impl IdentityServerInfo {
 
 /// Possible values for deleting or unbinding 3PIDs.
 #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/doc/string_enum.md"))]
-#[derive(Clone, StringEnum)]
+#[derive(Clone, PartialEq, Eq, StringEnum)]
 #[ruma_enum(rename_all = "kebab-case")]
 #[non_exhaustive]
 pub enum ThirdPartyIdRemovalStatus {
@@ -66,4 +66,53 @@ pub mod v3 {
             Self {}
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use assign::assign;
+        use ruma_common::api::{MatrixVersion, OutgoingRequest, SendAccessToken};
+        use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+        use super::Request;
+        use crate::uiaa::{AuthData, Password, UserIdentifier};
+
+        #[test]
+        fn serialize_request() {
+            let mut password = Password::new(
+                UserIdentifier::UserIdOrLocalpart("alice".to_owned()),
+                "0ld_p4ssw0rd".to_owned(),
+            );
+            password.session = Some("xxxxxx".to_owned());
+            let request = assign!(Request::new("n3w_p4ssw0rd".to_owned()), {
+                logout_devices: false,
+                auth: Some(AuthData::Password(password)),
+            });
+
+            let http_request = request
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(http_request.uri().path(), "/_matrix/client/v3/account/password");
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_request.body()).unwrap(),
+                json!({
+                    "new_password": "n3w_p4ssw0rd",
+                    "logout_devices": false,
+                    "auth": {
+                        "type": "m.login.password",
+                        "identifier": {
+                            "type": "m.id.user",
+                            "user": "alice",
+                        },
+                        "password": "0ld_p4ssw0rd",
+                        "session": "xxxxxx",
+                    },
+                })
+            );
+        }
+    }
 }
@@ -68,4 +68,59 @@ pub mod v3 {
             Self { id_server_unbind_result }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use assign::assign;
+        use ruma_common::api::{IncomingResponse, MatrixVersion, OutgoingRequest, SendAccessToken};
+        use serde_json::{
+            from_slice as from_json_slice, json, to_vec as to_json_vec, Value as JsonValue,
+        };
+
+        use super::{Request, Response};
+        use crate::{
+            account::ThirdPartyIdRemovalStatus,
+            uiaa::{AuthData, Dummy},
+        };
+
+        #[test]
+        fn serialize_request() {
+            let request = assign!(Request::new(), {
+                auth: Some(AuthData::Dummy(assign!(Dummy::new(), { session: Some("xxxxxx".to_owned()) }))),
+                id_server: Some("example.org".to_owned()),
+                erase: true,
+            });
+
+            let http_request = request
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(http_request.uri().path(), "/_matrix/client/v3/account/deactivate");
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_request.body()).unwrap(),
+                json!({
+                    "auth": {
+                        "type": "m.login.dummy",
+                        "session": "xxxxxx",
+                    },
+                    "id_server": "example.org",
+                    "erase": true,
+                })
+            );
+        }
+
+        #[test]
+        fn deserialize_response() {
+            let body = json!({ "id_server_unbind_result": "no-support" });
+            let http_response =
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap();
+
+            let response = Response::try_from_http_response(http_response).unwrap();
+            assert_eq!(response.id_server_unbind_result, ThirdPartyIdRemovalStatus::NoSupport);
+        }
+    }
 }
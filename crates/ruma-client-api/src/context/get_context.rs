@@ -50,6 +50,10 @@ pub mod v3 {
         pub limit: UInt,
 
         /// A RoomEventFilter to filter returned events with.
+        ///
+        /// The filter is only applied to `events_before`, `events_after` and `state`. It can be
+        /// used to enable lazy-loading of room members with
+        /// [`RoomEventFilter::with_lazy_loading()`].
         #[ruma_api(query)]
         #[serde(
             with = "ruma_common::serde::json_string",
@@ -112,4 +116,150 @@ pub mod v3 {
     fn is_default_limit(val: &UInt) -> bool {
         *val == default_limit()
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request_with_lazy_loading() {
+            use js_int::uint;
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+                owned_event_id, owned_room_id,
+            };
+
+            use super::Request;
+            use crate::filter::RoomEventFilter;
+
+            let mut req = Request::new(
+                owned_room_id!("!roomid:example.org"),
+                owned_event_id!("$event:example.org"),
+            );
+            req.limit = uint!(4);
+            req.filter = RoomEventFilter::with_lazy_loading();
+
+            let request: http::Request<Vec<u8>> = req
+                .try_into_http_request(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(
+                request.uri().path(),
+                "/_matrix/client/v3/rooms/!roomid:example.org/context/$event:example.org"
+            );
+            assert_eq!(
+                request.uri().query(),
+                Some("limit=4&filter=%7B%22lazy_load_members%22%3Atrue%7D")
+            );
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_default_request() {
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+                owned_event_id, owned_room_id,
+            };
+
+            use super::Request;
+
+            let request: http::Request<Vec<u8>> = Request::new(
+                owned_room_id!("!roomid:example.org"),
+                owned_event_id!("$event:example.org"),
+            )
+            .try_into_http_request(
+                "https://homeserver.tld",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+            assert_eq!(request.uri().query(), None);
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use js_int::uint;
+            use ruma_common::api::IncomingRequest;
+
+            use super::Request;
+            use crate::filter::LazyLoadOptions;
+
+            let uri = http::Uri::builder()
+                .scheme("https")
+                .authority("example.org")
+                .path_and_query(
+                    "/_matrix/client/v3/rooms/!roomid%3Aexample.org/context/$event%3Aexample.org\
+                     ?limit=4\
+                     &filter=%7B%22lazy_load_members%22%3Atrue%7D",
+                )
+                .build()
+                .unwrap();
+
+            let req = Request::try_from_http_request(
+                http::Request::builder().uri(uri).body(&[] as &[u8]).unwrap(),
+                &["!roomid:example.org", "$event:example.org"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id, "!roomid:example.org");
+            assert_eq!(req.event_id, "$event:example.org");
+            assert_eq!(req.limit, uint!(4));
+            assert_eq!(
+                req.filter.lazy_load_options,
+                LazyLoadOptions::Enabled { include_redundant_members: false }
+            );
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::api::IncomingResponse;
+            use serde_json::json;
+
+            use super::Response;
+
+            let body = serde_json::to_vec(&json!({
+                "start": "t27-54_2_0_2",
+                "end": "t29-57_2_0_2",
+                "event": {
+                    "content": { "body": "Jump here", "msgtype": "m.text" },
+                    "event_id": "$event:example.org",
+                    "origin_server_ts": 1_432_735_824_653_u64,
+                    "room_id": "!roomid:example.org",
+                    "sender": "@alice:example.org",
+                    "type": "m.room.message",
+                },
+                "events_before": [{
+                    "content": { "body": "Before", "msgtype": "m.text" },
+                    "event_id": "$before:example.org",
+                    "origin_server_ts": 1_432_735_824_600_u64,
+                    "room_id": "!roomid:example.org",
+                    "sender": "@alice:example.org",
+                    "type": "m.room.message",
+                }],
+                "state": [{
+                    "content": { "displayname": "Alice", "membership": "join" },
+                    "event_id": "$member:example.org",
+                    "origin_server_ts": 1_432_735_824_000_u64,
+                    "room_id": "!roomid:example.org",
+                    "sender": "@alice:example.org",
+                    "state_key": "@alice:example.org",
+                    "type": "m.room.member",
+                }],
+            }))
+            .unwrap();
+
+            let res = Response::try_from_http_response(http::Response::new(body)).unwrap();
+            assert_eq!(res.start.as_deref(), Some("t27-54_2_0_2"));
+            assert_eq!(res.end.as_deref(), Some("t29-57_2_0_2"));
+            assert_eq!(res.event.unwrap().deserialize().unwrap().event_id(), "$event:example.org");
+            assert_eq!(res.events_before.len(), 1);
+            assert_eq!(res.events_after.len(), 0);
+            assert_eq!(res.state.len(), 1);
+            assert_eq!(res.state[0].deserialize().unwrap().state_key(), "@alice:example.org");
+        }
+    }
 }
@@ -29,7 +29,7 @@ pub mod unstable {
     #[request(error = crate::Error)]
     pub struct Request {}
 
-    /// Request type for the `GET` `dehydrated_device` endpoint.
+    /// Response type for the `GET` `dehydrated_device` endpoint.
     #[response(error = crate::Error)]
     pub struct Response {
         /// The unique ID of the device.
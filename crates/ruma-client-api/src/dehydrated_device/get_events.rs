@@ -30,6 +30,7 @@ pub mod unstable {
         /// The unique ID of the device for which we would like to fetch events.
         #[ruma_api(path)]
         pub device_id: OwnedDeviceId,
+
         /// A point in time to continue getting events from.
         ///
         /// Should be a token from the `next_batch` field of a previous `/events`
@@ -38,11 +39,12 @@ pub mod unstable {
         pub next_batch: Option<String>,
     }
 
-    /// Request type for the `dehydrated_device/{device_id}/events` endpoint.
+    /// Response type for the `dehydrated_device/{device_id}/events` endpoint.
     #[response(error = crate::Error)]
     pub struct Response {
-        /// The batch token to supply in the `since` param of the next `/events` request. Will be
-        /// none if no further events can be found.
+        /// The batch token to supply in the `next_batch` field of the next `/events` request.
+        ///
+        /// Will be `None` if no further events can be found.
         pub next_batch: Option<String>,
 
         /// Messages sent directly between devices.
@@ -35,6 +35,7 @@ pub mod unstable {
         pub device_id: OwnedDeviceId,
 
         /// The display name of the device.
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub initial_device_display_name: Option<String>,
 
         /// The data of the dehydrated device, containing the serialized and encrypted private
@@ -53,7 +54,7 @@ pub mod unstable {
         pub fallback_keys: BTreeMap<OwnedDeviceKeyId, Raw<OneTimeKey>>,
     }
 
-    /// Response type for the `upload_keys` endpoint.
+    /// Response type for the `PUT` `dehydrated_device` endpoint.
     #[response(error = crate::Error)]
     pub struct Response {
         /// The unique ID of the device.
@@ -79,9 +80,79 @@ pub mod unstable {
     }
 
     impl Response {
-        /// Creates a new `Response` with the given one time key counts.
+        /// Creates a new `Response` with the given device ID.
         pub fn new(device_id: OwnedDeviceId) -> Self {
             Self { device_id }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use std::collections::BTreeMap;
+
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+            encryption::DeviceKeys,
+            owned_device_id, owned_device_key_id, owned_user_id,
+            serde::Raw,
+            EventEncryptionAlgorithm,
+        };
+        use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+        use super::Request;
+        use crate::dehydrated_device::{DehydratedDeviceData, DehydratedDeviceV1};
+
+        #[test]
+        fn serialize_request() {
+            let device_id = owned_device_id!("DEHYDRATED");
+            let mut device_keys = DeviceKeys::new(
+                owned_user_id!("@alice:example.org"),
+                device_id.clone(),
+                vec![EventEncryptionAlgorithm::OlmV1Curve25519AesSha2],
+                BTreeMap::from([(owned_device_key_id!("curve25519:DEHYDRATED"), "key".to_owned())]),
+                BTreeMap::new(),
+            );
+            device_keys.dehydrated = true;
+
+            let request = Request::new(
+                device_id,
+                Raw::new(&DehydratedDeviceData::V1(DehydratedDeviceV1::new("pickle".to_owned())))
+                    .unwrap(),
+                Raw::new(&device_keys).unwrap(),
+            );
+
+            let http_request = request
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(
+                http_request.uri().path(),
+                "/_matrix/client/unstable/org.matrix.msc3814.v1/dehydrated_device"
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_request.body()).unwrap(),
+                json!({
+                    "device_id": "DEHYDRATED",
+                    "device_data": {
+                        "algorithm": "org.matrix.msc3814.v1.olm",
+                        "device_pickle": "pickle",
+                    },
+                    "device_keys": {
+                        "user_id": "@alice:example.org",
+                        "device_id": "DEHYDRATED",
+                        "algorithms": ["m.olm.v1.curve25519-aes-sha2"],
+                        "keys": {
+                            "curve25519:DEHYDRATED": "key",
+                        },
+                        "signatures": {},
+                        "dehydrated": true,
+                    },
+                })
+            );
+        }
+    }
 }
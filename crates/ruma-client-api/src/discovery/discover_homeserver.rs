@@ -4,11 +4,19 @@
 //!
 //! Get discovery information about the domain.
 
+use std::collections::BTreeMap;
+
 use ruma_common::{
     api::{request, response, Metadata},
     metadata,
 };
+#[cfg(feature = "unstable-msc2965")]
+use ruma_common::{serde::StringEnum, DeviceId};
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+#[cfg(feature = "unstable-msc2965")]
+use crate::PrivOwnedStr;
 
 const METADATA: Metadata = metadata! {
     method: GET,
@@ -57,6 +65,13 @@ pub struct Response {
     #[cfg(feature = "unstable-msc3575")]
     #[serde(rename = "org.matrix.msc3575.proxy", skip_serializing_if = "Option::is_none")]
     pub sliding_sync_proxy: Option<SlidingSyncProxyInfo>,
+
+    /// Any other custom properties of the discovery information, as arbitrary JSON values.
+    ///
+    /// The discovery information can be extended with properties outside of the specification,
+    /// which should be labeled using the Java package naming convention.
+    #[serde(flatten)]
+    pub custom: BTreeMap<String, JsonValue>,
 }
 
 impl Request {
@@ -78,6 +93,7 @@ impl Response {
             authentication: None,
             #[cfg(feature = "unstable-msc3575")]
             sliding_sync_proxy: None,
+            custom: BTreeMap::new(),
         }
     }
 }
@@ -151,6 +167,55 @@ impl AuthenticationServerInfo {
     pub fn new(issuer: String, account: Option<String>) -> Self {
         Self { issuer, account }
     }
+
+    /// Returns the URL of the account management page for the given action, if `account` is set.
+    ///
+    /// The `device_id` should be set for the actions that act on a single session.
+    pub fn account_management_url(
+        &self,
+        action: AccountManagementAction,
+        device_id: Option<&DeviceId>,
+    ) -> Option<String> {
+        #[derive(Serialize)]
+        struct Query<'a> {
+            action: AccountManagementAction,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            device_id: Option<&'a DeviceId>,
+        }
+
+        let account = self.account.as_deref()?;
+        let separator = if account.contains('?') { '&' } else { '?' };
+        // Serializing a struct of strings to a query string cannot fail.
+        let query = serde_html_form::to_string(Query { action, device_id }).ok()?;
+
+        Some(format!("{account}{separator}{query}"))
+    }
+}
+
+/// An action to perform on the account management page of the OpenID Connect Provider.
+#[cfg(feature = "unstable-msc2965")]
+#[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/doc/string_enum.md"))]
+#[derive(Clone, PartialEq, Eq, StringEnum)]
+#[non_exhaustive]
+pub enum AccountManagementAction {
+    /// View the user's profile.
+    #[ruma_enum(rename = "org.matrix.profile")]
+    Profile,
+
+    /// View the list of the user's sessions.
+    #[ruma_enum(rename = "org.matrix.sessions_list")]
+    SessionsList,
+
+    /// View the details of a single session.
+    #[ruma_enum(rename = "org.matrix.session_view")]
+    SessionView,
+
+    /// End a single session.
+    #[ruma_enum(rename = "org.matrix.session_end")]
+    SessionEnd,
+
+    #[doc(hidden)]
+    _Custom(PrivOwnedStr),
 }
 
 /// Information about a discovered sliding sync proxy.
@@ -169,3 +234,67 @@ impl SlidingSyncProxyInfo {
         Self { url }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_response_with_custom_properties() {
+        use ruma_common::api::IncomingResponse;
+        use serde_json::json;
+
+        let body = serde_json::to_vec(&json!({
+            "m.homeserver": { "base_url": "https://matrix.example.org" },
+            "m.identity_server": { "base_url": "https://identity.example.org" },
+            "org.example.custom.property": { "app_url": "https://custom.app.example.org" },
+        }))
+        .unwrap();
+
+        let res = super::Response::try_from_http_response(http::Response::new(body)).unwrap();
+        assert_eq!(res.homeserver.base_url, "https://matrix.example.org");
+        assert_eq!(res.identity_server.unwrap().base_url, "https://identity.example.org");
+        assert_eq!(res.custom.len(), 1);
+        assert_eq!(
+            res.custom["org.example.custom.property"],
+            json!({ "app_url": "https://custom.app.example.org" })
+        );
+    }
+
+    #[cfg(feature = "unstable-msc2965")]
+    #[test]
+    fn account_management_url() {
+        use ruma_common::device_id;
+
+        use super::{AccountManagementAction, AuthenticationServerInfo};
+
+        let info = AuthenticationServerInfo::new(
+            "https://auth.example.org/".to_owned(),
+            Some("https://auth.example.org/account".to_owned()),
+        );
+
+        assert_eq!(
+            info.account_management_url(AccountManagementAction::Profile, None).unwrap(),
+            "https://auth.example.org/account?action=org.matrix.profile"
+        );
+        assert_eq!(
+            info.account_management_url(
+                AccountManagementAction::SessionEnd,
+                Some(device_id!("ABCDEF"))
+            )
+            .unwrap(),
+            "https://auth.example.org/account?action=org.matrix.session_end&device_id=ABCDEF"
+        );
+
+        let info = AuthenticationServerInfo::new(
+            "https://auth.example.org/".to_owned(),
+            Some("https://auth.example.org/?page=account".to_owned()),
+        );
+        assert_eq!(
+            info.account_management_url(AccountManagementAction::SessionsList, None).unwrap(),
+            "https://auth.example.org/?page=account&action=org.matrix.sessions_list"
+        );
+
+        let info = AuthenticationServerInfo::new("https://auth.example.org/".to_owned(), None);
+        assert_eq!(info.account_management_url(AccountManagementAction::Profile, None), None);
+    }
+}
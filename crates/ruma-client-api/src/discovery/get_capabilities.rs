@@ -8,12 +8,12 @@
 use std::{borrow::Cow, collections::BTreeMap};
 
 use maplit::btreemap;
-use ruma_common::{serde::StringEnum, RoomVersionId};
+pub use ruma_common::room::RoomVersionStability;
+use ruma_common::RoomVersionId;
 use serde::{Deserialize, Serialize};
 use serde_json::{from_value as from_json_value, to_value as to_json_value, Value as JsonValue};
 
 use self::iter::{CapabilitiesIter, CapabilityRef};
-use crate::PrivOwnedStr;
 
 pub mod iter;
 pub mod v3;
@@ -197,22 +197,6 @@ impl Default for RoomVersionsCapability {
     }
 }
 
-/// The stability of a room version.
-#[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/doc/string_enum.md"))]
-#[derive(Clone, PartialEq, Eq, StringEnum)]
-#[ruma_enum(rename_all = "lowercase")]
-#[non_exhaustive]
-pub enum RoomVersionStability {
-    /// Support for the given version is stable.
-    Stable,
-
-    /// Support for the given version is unstable.
-    Unstable,
-
-    #[doc(hidden)]
-    _Custom(PrivOwnedStr),
-}
-
 /// Information about the `m.set_displayname` capability
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
@@ -192,6 +192,17 @@ pub enum ErrorKind {
     _Custom { errcode: PrivOwnedStr, extra: Extra },
 }
 
+impl ErrorKind {
+    /// If this is an `M_LIMIT_EXCEEDED` error, returns how long the client should wait before
+    /// trying the request again, if the server specified it.
+    ///
+    /// Endpoints that are subject to rate limiting have the `rate_limited` field of their
+    /// [`Metadata`](ruma_common::api::Metadata) set to `true`.
+    pub fn retry_after(&self) -> Option<Duration> {
+        as_variant!(self, Self::LimitExceeded { retry_after_ms } => *retry_after_ms).flatten()
+    }
+}
+
 #[doc(hidden)]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Extra(BTreeMap<String, JsonValue>);
@@ -519,6 +530,8 @@ impl FromHttpResponseErrorExt for FromHttpResponseError<Error> {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use assert_matches2::assert_matches;
     use serde_json::{from_value as from_json_value, json};
 
@@ -550,6 +563,26 @@ mod tests {
         assert_eq!(deserialized.message, "Wrong backup version.");
     }
 
+    #[test]
+    fn retry_after() {
+        let deserialized: StandardErrorBody = from_json_value(json!({
+            "errcode": "M_LIMIT_EXCEEDED",
+            "error": "Too many requests",
+            "retry_after_ms": 2000,
+        }))
+        .unwrap();
+        assert_eq!(deserialized.kind.retry_after(), Some(Duration::from_secs(2)));
+
+        let deserialized: StandardErrorBody = from_json_value(json!({
+            "errcode": "M_LIMIT_EXCEEDED",
+            "error": "Too many requests",
+        }))
+        .unwrap();
+        assert_eq!(deserialized.kind.retry_after(), None);
+
+        assert_eq!(ErrorKind::Forbidden.retry_after(), None);
+    }
+
     #[cfg(feature = "unstable-msc2967")]
     #[test]
     fn custom_authenticate_error_sanity() {
@@ -233,7 +233,8 @@ impl<'de> Visitor<'de> for ErrorKindVisitor {
             ErrCode::BadStatus => ErrorKind::BadStatus {
                 status: status
                     .map(|s| {
-                        from_json_value::<u16>(s)
+                        let status = from_json_value::<UInt>(s).map_err(de::Error::custom)?;
+                        u16::try_from(u64::from(status))
                             .map_err(de::Error::custom)?
                             .try_into()
                             .map_err(de::Error::custom)
@@ -340,6 +341,17 @@ impl Serialize for ErrorKind {
             Self::ResourceLimitExceeded { admin_contact } => {
                 st.serialize_entry("admin_contact", admin_contact)?;
             }
+            Self::BadStatus { status, body } => {
+                if let Some(status) = status {
+                    st.serialize_entry("status", &status.as_u16())?;
+                }
+                if let Some(body) = body {
+                    st.serialize_entry("body", body)?;
+                }
+            }
+            Self::WrongRoomKeysVersion { current_version } => {
+                st.serialize_entry("current_version", current_version)?;
+            }
             Self::_Custom { extra, .. } => {
                 for (k, v) in &extra.0 {
                     st.serialize_entry(k, v)?;
@@ -354,7 +366,7 @@ impl Serialize for ErrorKind {
 #[cfg(test)]
 mod tests {
     use ruma_common::room_version_id;
-    use serde_json::{from_value as from_json_value, json};
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
     use super::ErrorKind;
 
@@ -388,4 +400,50 @@ mod tests {
             ErrorKind::IncompatibleRoomVersion { room_version: room_version_id!("7") }
         );
     }
+
+    #[test]
+    fn serialize_wrong_room_keys_version() {
+        let kind = ErrorKind::WrongRoomKeysVersion { current_version: Some("42".to_owned()) };
+
+        assert_eq!(
+            to_json_value(kind).unwrap(),
+            json!({
+                "errcode": "M_WRONG_ROOM_KEYS_VERSION",
+                "current_version": "42",
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_bad_status() {
+        let kind = ErrorKind::BadStatus {
+            status: Some(http::StatusCode::BAD_GATEWAY),
+            body: Some("<html>Bad gateway</html>".to_owned()),
+        };
+        let json = json!({
+            "errcode": "M_BAD_STATUS",
+            "status": 502,
+            "body": "<html>Bad gateway</html>",
+        });
+
+        assert_eq!(to_json_value(&kind).unwrap(), json);
+        assert_eq!(from_json_value::<ErrorKind>(json).unwrap(), kind);
+
+        assert_eq!(
+            to_json_value(ErrorKind::BadStatus { status: None, body: None }).unwrap(),
+            json!({ "errcode": "M_BAD_STATUS" })
+        );
+    }
+
+    #[test]
+    fn custom_roundtrip() {
+        let json = json!({
+            "errcode": "ORG_EXAMPLE_CUSTOM",
+            "custom_field": [1, 2],
+        });
+
+        let kind = from_json_value::<ErrorKind>(json.clone()).unwrap();
+        assert_eq!(kind.as_ref(), "ORG_EXAMPLE_CUSTOM");
+        assert_eq!(to_json_value(kind).unwrap(), json);
+    }
 }
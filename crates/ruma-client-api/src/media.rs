@@ -1,4 +1,15 @@
 //! Endpoints for the media repository.
+//!
+//! The request and response types of the endpoints that transfer files hold the whole file in
+//! memory. To stream large files instead, these types can be converted to and from HTTP requests
+//! and responses with a body of any type, like a stream of bytes, with the
+//! [`OutgoingRawBodyRequest`], [`IncomingRawBodyResponse`], [`IncomingRawBodyRequest`] and
+//! [`OutgoingRawBodyResponse`] traits.
+//!
+//! [`OutgoingRawBodyRequest`]: ruma_common::api::OutgoingRawBodyRequest
+//! [`IncomingRawBodyResponse`]: ruma_common::api::IncomingRawBodyResponse
+//! [`IncomingRawBodyRequest`]: ruma_common::api::IncomingRawBodyRequest
+//! [`OutgoingRawBodyResponse`]: ruma_common::api::OutgoingRawBodyResponse
 
 use std::time::Duration;
 
@@ -12,6 +12,7 @@ pub mod v3 {
     use http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
     use ruma_common::{
         api::{request, response, Metadata},
+        http_headers::ContentDisposition,
         metadata, IdParseError, MxcUri, OwnedServerName,
     };
 
@@ -82,12 +83,8 @@ pub mod v3 {
 
         /// The value of the `Content-Disposition` HTTP header, possibly containing the name of the
         /// file that was previously uploaded.
-        ///
-        /// See [MDN] for the syntax.
-        ///
-        /// [MDN]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Disposition#Syntax
         #[ruma_api(header = CONTENT_DISPOSITION)]
-        pub content_disposition: Option<String>,
+        pub content_disposition: Option<ContentDisposition>,
 
         /// The value of the `Cross-Origin-Resource-Policy` HTTP header.
         ///
@@ -131,4 +128,53 @@ pub mod v3 {
             }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        use ruma_common::http_headers::{ContentDisposition, ContentDispositionType};
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::api::IncomingResponse;
+
+            let http_response = http::Response::builder()
+                .header(http::header::CONTENT_TYPE, "image/png")
+                .header(
+                    http::header::CONTENT_DISPOSITION,
+                    "attachment; filename*=utf-8''%E2%82%AC%20rates.png",
+                )
+                .body(b"image".to_vec())
+                .unwrap();
+
+            let response = super::Response::try_from_http_response(http_response).unwrap();
+            assert_eq!(response.file, b"image");
+            assert_eq!(response.content_type.as_deref(), Some("image/png"));
+            assert_eq!(
+                response.content_disposition,
+                Some(
+                    ContentDisposition::new(ContentDispositionType::Attachment)
+                        .with_filename(Some("€ rates.png".to_owned()))
+                )
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn serialize_response() {
+            use ruma_common::api::OutgoingResponse;
+
+            let mut response = super::Response::new(b"image".to_vec());
+            response.content_disposition = Some(
+                ContentDisposition::new(ContentDispositionType::Inline)
+                    .with_filename(Some("image.png".to_owned())),
+            );
+
+            let http_response = response.try_into_http_response::<Vec<u8>>().unwrap();
+            assert_eq!(
+                http_response.headers().get(http::header::CONTENT_DISPOSITION).unwrap(),
+                r#"inline; filename="image.png""#
+            );
+        }
+    }
 }
@@ -9,10 +9,11 @@ pub mod v3 {
 
     use std::time::Duration;
 
-    use http::header::CONTENT_TYPE;
+    use http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
     use js_int::UInt;
     use ruma_common::{
         api::{request, response, Metadata},
+        http_headers::ContentDisposition,
         metadata,
         serde::StringEnum,
         IdParseError, MxcUri, OwnedServerName,
@@ -87,6 +88,19 @@ pub mod v3 {
         #[ruma_api(query)]
         #[serde(default, skip_serializing_if = "ruma_common::serde::is_default")]
         pub allow_redirect: bool,
+
+        /// Whether the server should return an animated thumbnail.
+        ///
+        /// When `Some(true)`, the server should return an animated thumbnail if possible and
+        /// supported. When `Some(false)`, the server must not return an animated thumbnail. When
+        /// `None`, the server should not return an animated thumbnail.
+        ///
+        /// This uses the unstable prefix in
+        /// [MSC2705](https://github.com/matrix-org/matrix-spec-proposals/pull/2705).
+        #[ruma_api(query)]
+        #[cfg(feature = "unstable-msc2705")]
+        #[serde(rename = "org.matrix.msc2705.animated", skip_serializing_if = "Option::is_none")]
+        pub animated: Option<bool>,
     }
 
     /// Response type for the `get_content_thumbnail` endpoint.
@@ -100,6 +114,11 @@ pub mod v3 {
         #[ruma_api(header = CONTENT_TYPE)]
         pub content_type: Option<String>,
 
+        /// The value of the `Content-Disposition` HTTP header, possibly containing the name of the
+        /// thumbnail.
+        #[ruma_api(header = CONTENT_DISPOSITION)]
+        pub content_disposition: Option<ContentDisposition>,
+
         /// The value of the `Cross-Origin-Resource-Policy` HTTP header.
         ///
         /// See [MDN] for the syntax.
@@ -127,6 +146,8 @@ pub mod v3 {
                 allow_remote: true,
                 timeout_ms: crate::media::default_download_timeout(),
                 allow_redirect: false,
+                #[cfg(feature = "unstable-msc2705")]
+                animated: None,
             }
         }
 
@@ -147,6 +168,7 @@ pub mod v3 {
             Self {
                 file,
                 content_type: None,
+                content_disposition: None,
                 cross_origin_resource_policy: Some("cross-origin".to_owned()),
             }
         }
@@ -167,4 +189,54 @@ pub mod v3 {
         #[doc(hidden)]
         _Custom(PrivOwnedStr),
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use std::time::Duration;
+
+        use js_int::uint;
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+            mxc_uri,
+        };
+
+        use super::{Method, Request};
+
+        #[test]
+        fn serialize_request() {
+            let mut req =
+                Request::from_url(mxc_uri!("mxc://example.org/abcdef"), uint!(64), uint!(32))
+                    .unwrap();
+            req.method = Some(Method::Scale);
+            req.allow_remote = false;
+            req.timeout_ms = Duration::from_secs(5);
+            #[cfg(feature = "unstable-msc2705")]
+            {
+                req.animated = Some(true);
+            }
+
+            let request: http::Request<Vec<u8>> = req
+                .try_into_http_request(
+                    "https://homeserver.tld",
+                    SendAccessToken::None,
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(request.uri().path(), "/_matrix/media/v3/thumbnail/example.org/abcdef");
+
+            #[cfg(not(feature = "unstable-msc2705"))]
+            assert_eq!(
+                request.uri().query(),
+                Some("method=scale&width=64&height=32&allow_remote=false&timeout_ms=5000")
+            );
+            #[cfg(feature = "unstable-msc2705")]
+            assert_eq!(
+                request.uri().query(),
+                Some(
+                    "method=scale&width=64&height=32&allow_remote=false&timeout_ms=5000\
+                     &org.matrix.msc2705.animated=true"
+                )
+            );
+        }
+    }
 }
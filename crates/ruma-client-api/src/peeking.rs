@@ -0,0 +1,31 @@
+//! Endpoints for peeking into rooms without joining them.
+
+pub mod get_events;
+pub mod get_room_initial_sync;
+
+use ruma_common::serde::Raw;
+use ruma_events::AnyTimelineEvent;
+use serde::{Deserialize, Serialize};
+
+/// A chunk of paginated room events.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct PaginationChunk {
+    /// The token the pagination starts from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+
+    /// The token the pagination ends at.
+    pub end: String,
+
+    /// A list of room events.
+    #[serde(default)]
+    pub chunk: Vec<Raw<AnyTimelineEvent>>,
+}
+
+impl PaginationChunk {
+    /// Creates a new `PaginationChunk` with the given end token and events.
+    pub fn new(end: String, chunk: Vec<Raw<AnyTimelineEvent>>) -> Self {
+        Self { start: None, end, chunk }
+    }
+}
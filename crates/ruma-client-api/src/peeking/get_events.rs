@@ -0,0 +1,126 @@
+//! `GET /_matrix/client/*/events`
+//!
+//! Listen for new events in a room the user is not joined to.
+
+pub mod v3 {
+    //! `/v3/` ([spec])
+    //!
+    //! [spec]: https://spec.matrix.org/latest/client-server-api/#get_matrixclientv3events
+
+    use std::time::Duration;
+
+    use ruma_common::{
+        api::{request, response, Metadata},
+        metadata,
+        serde::Raw,
+        OwnedRoomId,
+    };
+    use ruma_events::AnyTimelineEvent;
+
+    const METADATA: Metadata = metadata! {
+        method: GET,
+        rate_limited: false,
+        authentication: AccessToken,
+        history: {
+            1.0 => "/_matrix/client/r0/events",
+            1.1 => "/_matrix/client/v3/events",
+        }
+    };
+
+    /// Request type for the `get_events` endpoint.
+    #[request(error = crate::Error)]
+    pub struct Request {
+        /// The token to stream from.
+        ///
+        /// This token is either from a previous request to this endpoint, or from the `end` token
+        /// of the [`messages`] returned by the [`get_room_initial_sync`] endpoint.
+        ///
+        /// [`messages`]: crate::peeking::get_room_initial_sync::v3::Response::messages
+        /// [`get_room_initial_sync`]: crate::peeking::get_room_initial_sync
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[ruma_api(query)]
+        pub from: Option<String>,
+
+        /// The room ID for which events should be returned.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[ruma_api(query)]
+        pub room_id: Option<OwnedRoomId>,
+
+        /// The maximum time to wait for an event.
+        #[serde(
+            with = "ruma_common::serde::duration::opt_ms",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        #[ruma_api(query)]
+        pub timeout: Option<Duration>,
+    }
+
+    /// Response type for the `get_events` endpoint.
+    #[response(error = crate::Error)]
+    #[derive(Default)]
+    pub struct Response {
+        /// A token which correlates to the first value in `chunk`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub start: Option<String>,
+
+        /// A token which correlates to the last value in `chunk`.
+        ///
+        /// This token should be used in the next request to this endpoint.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub end: Option<String>,
+
+        /// An array of events.
+        #[serde(default)]
+        pub chunk: Vec<Raw<AnyTimelineEvent>>,
+    }
+
+    impl Request {
+        /// Creates a new `Request` to peek into the room with the given ID.
+        pub fn new(room_id: OwnedRoomId) -> Self {
+            Self { from: None, room_id: Some(room_id), timeout: None }
+        }
+    }
+
+    impl Response {
+        /// Creates an empty `Response`.
+        pub fn new() -> Self {
+            Default::default()
+        }
+    }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use std::time::Duration;
+
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+            owned_room_id,
+        };
+
+        use super::Request;
+
+        #[test]
+        fn serialize_request() {
+            let request = Request {
+                from: Some("s3456_9_0".to_owned()),
+                timeout: Some(Duration::from_secs(30)),
+                ..Request::new(owned_room_id!("!room:example.org"))
+            };
+
+            let http_request = request
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(http_request.uri().path(), "/_matrix/client/v3/events");
+            assert_eq!(
+                http_request.uri().query(),
+                Some("from=s3456_9_0&room_id=%21room%3Aexample.org&timeout=30000")
+            );
+        }
+    }
+}
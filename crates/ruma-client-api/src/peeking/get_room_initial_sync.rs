@@ -0,0 +1,172 @@
+//! `GET /_matrix/client/*/rooms/{roomId}/initialSync`
+//!
+//! Get a snapshot of the state of a room.
+
+pub mod v3 {
+    //! `/v3/` ([spec])
+    //!
+    //! [spec]: https://spec.matrix.org/latest/client-server-api/#get_matrixclientv3roomsroomidinitialsync
+
+    use ruma_common::{
+        api::{request, response, Metadata},
+        metadata,
+        serde::Raw,
+        OwnedRoomId,
+    };
+    use ruma_events::{
+        presence::PresenceEvent, room::member::MembershipState, AnyRoomAccountDataEvent,
+        AnyStateEvent,
+    };
+
+    use crate::{peeking::PaginationChunk, room::Visibility};
+
+    const METADATA: Metadata = metadata! {
+        method: GET,
+        rate_limited: false,
+        authentication: AccessToken,
+        history: {
+            1.0 => "/_matrix/client/r0/rooms/:room_id/initialSync",
+            1.1 => "/_matrix/client/v3/rooms/:room_id/initialSync",
+        }
+    };
+
+    /// Request type for the `get_room_initial_sync` endpoint.
+    #[request(error = crate::Error)]
+    pub struct Request {
+        /// The room to get a snapshot of.
+        #[ruma_api(path)]
+        pub room_id: OwnedRoomId,
+    }
+
+    /// Response type for the `get_room_initial_sync` endpoint.
+    #[response(error = crate::Error)]
+    pub struct Response {
+        /// The ID of this room.
+        pub room_id: OwnedRoomId,
+
+        /// The user's membership state in this room.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub membership: Option<MembershipState>,
+
+        /// The pagination chunk for this room.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub messages: Option<PaginationChunk>,
+
+        /// The state of the room.
+        ///
+        /// If the user has left the room then this will be the state of the room when they left
+        /// it.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub state: Vec<Raw<AnyStateEvent>>,
+
+        /// Whether this room is visible to the room directory.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub visibility: Option<Visibility>,
+
+        /// The private data that this user has attached to this room.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub account_data: Vec<Raw<AnyRoomAccountDataEvent>>,
+
+        /// The presence of the members of this room.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub presence: Vec<Raw<PresenceEvent>>,
+    }
+
+    impl Request {
+        /// Creates a new `Request` with the given room ID.
+        pub fn new(room_id: OwnedRoomId) -> Self {
+            Self { room_id }
+        }
+    }
+
+    impl Response {
+        /// Creates a new `Response` with the given room ID.
+        pub fn new(room_id: OwnedRoomId) -> Self {
+            Self {
+                room_id,
+                membership: None,
+                messages: None,
+                state: Vec::new(),
+                visibility: None,
+                account_data: Vec::new(),
+                presence: Vec::new(),
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use assert_matches2::assert_matches;
+        use ruma_common::api::IncomingResponse;
+        use ruma_events::room::member::MembershipState;
+        use serde_json::{json, to_vec as to_json_vec};
+
+        use super::Response;
+        use crate::room::Visibility;
+
+        #[test]
+        fn deserialize_response() {
+            let body = json!({
+                "room_id": "!636q39766251:example.com",
+                "membership": "join",
+                "visibility": "public",
+                "messages": {
+                    "start": "t335-28_0_0",
+                    "end": "s3456_9_0",
+                    "chunk": [
+                        {
+                            "content": {
+                                "body": "This is an example text message",
+                                "msgtype": "m.text",
+                            },
+                            "event_id": "$143273582443PhrSn:example.org",
+                            "origin_server_ts": 1_432_735_824_653_u64,
+                            "room_id": "!636q39766251:example.com",
+                            "sender": "@example:example.org",
+                            "type": "m.room.message",
+                        },
+                    ],
+                },
+                "state": [
+                    {
+                        "content": {
+                            "join_rule": "public",
+                        },
+                        "event_id": "$143273582443PhrSn:example.org",
+                        "origin_server_ts": 1_432_735_824_653_u64,
+                        "room_id": "!636q39766251:example.com",
+                        "sender": "@example:example.org",
+                        "state_key": "",
+                        "type": "m.room.join_rules",
+                    },
+                ],
+                "account_data": [
+                    {
+                        "content": {
+                            "tags": {
+                                "work": {
+                                    "order": 0.5,
+                                },
+                            },
+                        },
+                        "type": "m.tag",
+                    },
+                ],
+            });
+            let http_response =
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap();
+
+            let response = Response::try_from_http_response(http_response).unwrap();
+            assert_eq!(response.room_id, "!636q39766251:example.com");
+            assert_eq!(response.membership, Some(MembershipState::Join));
+            assert_eq!(response.visibility, Some(Visibility::Public));
+            assert_matches!(response.messages, Some(messages));
+            assert_eq!(messages.start.as_deref(), Some("t335-28_0_0"));
+            assert_eq!(messages.end, "s3456_9_0");
+            assert_eq!(messages.chunk.len(), 1);
+            assert_eq!(response.state.len(), 1);
+            assert_eq!(response.account_data.len(), 1);
+            assert!(response.presence.is_empty());
+        }
+    }
+}
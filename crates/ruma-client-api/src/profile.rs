@@ -3,5 +3,9 @@
 pub mod get_avatar_url;
 pub mod get_display_name;
 pub mod get_profile;
+#[cfg(feature = "unstable-msc4133")]
+pub mod get_profile_key;
 pub mod set_avatar_url;
 pub mod set_display_name;
+#[cfg(feature = "unstable-msc4133")]
+pub mod set_profile_key;
@@ -7,10 +7,13 @@ pub mod v3 {
     //!
     //! [spec]: https://spec.matrix.org/latest/client-server-api/#get_matrixclientv3profileuserid
 
+    use std::collections::BTreeMap;
+
     use ruma_common::{
         api::{request, response, Metadata},
         metadata, OwnedMxcUri, OwnedUserId,
     };
+    use serde_json::Value as JsonValue;
 
     const METADATA: Metadata = metadata! {
         method: GET,
@@ -56,6 +59,14 @@ pub mod v3 {
         #[cfg(feature = "unstable-msc2448")]
         #[serde(rename = "xyz.amorgan.blurhash", skip_serializing_if = "Option::is_none")]
         pub blurhash: Option<String>,
+
+        /// Any other custom profile fields of the user, as arbitrary JSON values.
+        ///
+        /// Profiles can be extended with fields outside of the specification, which should be
+        /// labeled using the Java package naming convention. They are kept here so they are not
+        /// lost when the profile is forwarded or serialized again.
+        #[serde(flatten)]
+        pub custom_fields: BTreeMap<String, JsonValue>,
     }
 
     impl Request {
@@ -73,7 +84,55 @@ pub mod v3 {
                 displayname,
                 #[cfg(feature = "unstable-msc2448")]
                 blurhash: None,
+                custom_fields: BTreeMap::new(),
             }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response_with_custom_fields() {
+            use ruma_common::{api::IncomingResponse, mxc_uri};
+            use serde_json::json;
+
+            let body = serde_json::to_vec(&json!({
+                "avatar_url": "mxc://example.org/SEsfnsuifSDFSSEF",
+                "displayname": "Alice Margatroid",
+                "org.example.pronouns": "she/her",
+                "org.example.status": { "text": "Busy" },
+            }))
+            .unwrap();
+
+            let res = super::Response::try_from_http_response(http::Response::new(body)).unwrap();
+            assert_eq!(
+                res.avatar_url.as_deref(),
+                Some(mxc_uri!("mxc://example.org/SEsfnsuifSDFSSEF"))
+            );
+            assert_eq!(res.displayname.as_deref(), Some("Alice Margatroid"));
+            assert_eq!(res.custom_fields.len(), 2);
+            assert_eq!(res.custom_fields["org.example.pronouns"], json!("she/her"));
+            assert_eq!(res.custom_fields["org.example.status"], json!({ "text": "Busy" }));
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn serialize_response_with_custom_fields() {
+            use ruma_common::api::OutgoingResponse;
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            let mut res = super::Response::new(None, Some("Alice Margatroid".to_owned()));
+            res.custom_fields.insert("org.example.pronouns".to_owned(), json!("she/her"));
+
+            let http_res = res.try_into_http_response::<Vec<u8>>().unwrap();
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_res.body()).unwrap(),
+                json!({
+                    "displayname": "Alice Margatroid",
+                    "org.example.pronouns": "she/her",
+                })
+            );
+        }
+    }
 }
@@ -0,0 +1,128 @@
+//! `GET /_matrix/client/*/profile/{userId}/{keyName}`
+//!
+//! Get a single field of the profile of a user, according to [MSC4133].
+//!
+//! [MSC4133]: https://github.com/matrix-org/matrix-spec-proposals/pull/4133
+
+pub mod unstable {
+    //! `uk.tcpip.msc4133` ([MSC])
+    //!
+    //! [MSC]: https://github.com/matrix-org/matrix-spec-proposals/pull/4133
+
+    use std::collections::BTreeMap;
+
+    use ruma_common::{
+        api::{request, response, Metadata},
+        metadata, OwnedUserId,
+    };
+    use serde_json::Value as JsonValue;
+
+    const METADATA: Metadata = metadata! {
+        method: GET,
+        rate_limited: false,
+        authentication: None,
+        history: {
+            unstable => "/_matrix/client/unstable/uk.tcpip.msc4133/profile/:user_id/:key_name",
+        }
+    };
+
+    /// Request type for the `get_profile_key` endpoint.
+    #[request(error = crate::Error)]
+    pub struct Request {
+        /// The user whose profile field will be retrieved.
+        #[ruma_api(path)]
+        pub user_id: OwnedUserId,
+
+        /// The name of the profile field to retrieve.
+        #[ruma_api(path)]
+        pub key_name: String,
+    }
+
+    /// Response type for the `get_profile_key` endpoint.
+    #[response(error = crate::Error)]
+    #[derive(Default)]
+    pub struct Response {
+        /// The requested profile field, as a map from its name to its value.
+        ///
+        /// This should contain a single entry, for the key name of the request.
+        #[ruma_api(body)]
+        pub fields: BTreeMap<String, JsonValue>,
+    }
+
+    impl Request {
+        /// Creates a new `Request` with the given user ID and key name.
+        pub fn new(user_id: OwnedUserId, key_name: String) -> Self {
+            Self { user_id, key_name }
+        }
+    }
+
+    impl Response {
+        /// Creates a new `Response` with the given key name and value.
+        pub fn new(key_name: String, value: JsonValue) -> Self {
+            Self { fields: BTreeMap::from([(key_name, value)]) }
+        }
+
+        /// Get the value of the profile field with the given key name, if it is present.
+        pub fn value(&self, key_name: &str) -> Option<&JsonValue> {
+            self.fields.get(key_name)
+        }
+    }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        use serde_json::json;
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::api::IncomingResponse;
+
+            let body = serde_json::to_vec(&json!({ "org.example.pronouns": "she/her" })).unwrap();
+
+            let res = super::Response::try_from_http_response(http::Response::new(body)).unwrap();
+            assert_eq!(res.value("org.example.pronouns"), Some(&json!("she/her")));
+            assert_eq!(res.value("displayname"), None);
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+                owned_user_id,
+            };
+
+            let req = super::Request::new(
+                owned_user_id!("@alice:example.org"),
+                "org.example.pronouns".to_owned(),
+            )
+            .try_into_http_request::<Vec<u8>>(
+                "https://matrix.example.org",
+                SendAccessToken::None,
+                &[MatrixVersion::V1_9],
+            )
+            .unwrap();
+
+            assert_eq!(req.method(), http::Method::GET);
+            assert_eq!(
+                req.uri(),
+                "https://matrix.example.org/_matrix/client/unstable/uk.tcpip.msc4133/profile/@alice:example.org/org.example.pronouns"
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn serialize_response() {
+            use ruma_common::api::OutgoingResponse;
+            use serde_json::{from_slice as from_json_slice, Value as JsonValue};
+
+            let res = super::Response::new("org.example.pronouns".to_owned(), json!("she/her"))
+                .try_into_http_response::<Vec<u8>>()
+                .unwrap();
+            assert_eq!(
+                from_json_slice::<JsonValue>(res.body()).unwrap(),
+                json!({ "org.example.pronouns": "she/her" })
+            );
+        }
+    }
+}
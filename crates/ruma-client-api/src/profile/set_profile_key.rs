@@ -0,0 +1,132 @@
+//! `PUT /_matrix/client/*/profile/{userId}/{keyName}`
+//!
+//! Set a single field of the profile of a user, according to [MSC4133].
+//!
+//! [MSC4133]: https://github.com/matrix-org/matrix-spec-proposals/pull/4133
+
+pub mod unstable {
+    //! `uk.tcpip.msc4133` ([MSC])
+    //!
+    //! [MSC]: https://github.com/matrix-org/matrix-spec-proposals/pull/4133
+
+    use std::collections::BTreeMap;
+
+    use ruma_common::{
+        api::{request, response, Metadata},
+        metadata, OwnedUserId,
+    };
+    use serde_json::Value as JsonValue;
+
+    const METADATA: Metadata = metadata! {
+        method: PUT,
+        rate_limited: true,
+        authentication: AccessToken,
+        history: {
+            unstable => "/_matrix/client/unstable/uk.tcpip.msc4133/profile/:user_id/:key_name",
+        }
+    };
+
+    /// Request type for the `set_profile_key` endpoint.
+    #[request(error = crate::Error)]
+    pub struct Request {
+        /// The user whose profile field will be set.
+        ///
+        /// The access token must be authorized to make requests for this user ID.
+        #[ruma_api(path)]
+        pub user_id: OwnedUserId,
+
+        /// The name of the profile field to set.
+        #[ruma_api(path)]
+        pub key_name: String,
+
+        /// The new profile field, as a map from its name to its value.
+        ///
+        /// This must contain a single entry, for the key name of the request.
+        #[ruma_api(body)]
+        pub fields: BTreeMap<String, JsonValue>,
+    }
+
+    /// Response type for the `set_profile_key` endpoint.
+    #[response(error = crate::Error)]
+    #[derive(Default)]
+    pub struct Response {}
+
+    impl Request {
+        /// Creates a new `Request` with the given user ID, key name and value.
+        pub fn new(user_id: OwnedUserId, key_name: String, value: JsonValue) -> Self {
+            let fields = BTreeMap::from([(key_name.clone(), value)]);
+            Self { user_id, key_name, fields }
+        }
+
+        /// Get the value of the profile field of this request, if it is present.
+        pub fn value(&self) -> Option<&JsonValue> {
+            self.fields.get(&self.key_name)
+        }
+    }
+
+    impl Response {
+        /// Creates an empty `Response`.
+        pub fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        use serde_json::json;
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+                owned_user_id,
+            };
+            use serde_json::{from_slice as from_json_slice, Value as JsonValue};
+
+            let req = super::Request::new(
+                owned_user_id!("@alice:example.org"),
+                "org.example.pronouns".to_owned(),
+                json!("she/her"),
+            )
+            .try_into_http_request::<Vec<u8>>(
+                "https://matrix.example.org",
+                SendAccessToken::IfRequired("tok"),
+                &[MatrixVersion::V1_9],
+            )
+            .unwrap();
+
+            assert_eq!(req.method(), http::Method::PUT);
+            assert_eq!(
+                req.uri(),
+                "https://matrix.example.org/_matrix/client/unstable/uk.tcpip.msc4133/profile/@alice:example.org/org.example.pronouns"
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({ "org.example.pronouns": "she/her" })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::api::IncomingRequest;
+
+            let body = serde_json::to_vec(&json!({ "org.example.pronouns": "she/her" })).unwrap();
+            let http_req = http::Request::builder()
+                .method(http::Method::PUT)
+                .uri("https://matrix.example.org/_matrix/client/unstable/uk.tcpip.msc4133/profile/@alice:example.org/org.example.pronouns")
+                .body(body)
+                .unwrap();
+
+            let req = super::Request::try_from_http_request(
+                http_req,
+                &["@alice:example.org", "org.example.pronouns"],
+            )
+            .unwrap();
+            assert_eq!(req.user_id, "@alice:example.org");
+            assert_eq!(req.key_name, "org.example.pronouns");
+            assert_eq!(req.value(), Some(&json!("she/her")));
+        }
+    }
+}
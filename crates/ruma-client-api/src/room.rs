@@ -1,10 +1,14 @@
 //! Endpoints for room management.
 
 pub mod aliases;
+#[cfg(feature = "unstable-msc2716")]
+pub mod batch_send;
 pub mod create_room;
 pub mod get_event_by_timestamp;
 pub mod get_room_event;
 pub mod report_content;
+#[cfg(feature = "unstable-msc4151")]
+pub mod report_room;
 pub mod upgrade_room;
 
 use ruma_common::serde::StringEnum;
@@ -0,0 +1,183 @@
+//! `POST /_matrix/client/*/rooms/{roomId}/batch_send`
+//!
+//! Send a batch of historical events to a room.
+
+pub mod unstable {
+    //! `msc2716` ([MSC])
+    //!
+    //! [MSC]: https://github.com/matrix-org/matrix-spec-proposals/pull/2716
+
+    use ruma_common::{
+        api::{request, response, Metadata},
+        metadata,
+        serde::Raw,
+        OwnedEventId, OwnedRoomId,
+    };
+    use ruma_events::{AnyStateEvent, AnyTimelineEvent};
+
+    const METADATA: Metadata = metadata! {
+        method: POST,
+        rate_limited: false,
+        authentication: AccessToken,
+        history: {
+            unstable => "/_matrix/client/unstable/org.matrix.msc2716/rooms/:room_id/batch_send",
+        }
+    };
+
+    /// Request type for the `batch_send` endpoint.
+    #[request(error = crate::Error)]
+    pub struct Request {
+        /// The room to send the batch of events to.
+        #[ruma_api(path)]
+        pub room_id: OwnedRoomId,
+
+        /// The ID of the event after which the batch is inserted.
+        #[ruma_api(query)]
+        pub prev_event_id: OwnedEventId,
+
+        /// The ID of the batch to connect to, from the `next_batch_id` of a previous response.
+        ///
+        /// If this is `None`, a new insertion point is created after `prev_event_id`.
+        #[ruma_api(query)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub batch_id: Option<String>,
+
+        /// The state events used to resolve the state at the start of the batch, like the
+        /// membership of the senders of the historical events.
+        ///
+        /// These events don't need an event ID or a room ID.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub state_events_at_start: Vec<Raw<AnyStateEvent>>,
+
+        /// The historical events to insert, in chronological order.
+        ///
+        /// These events don't need an event ID or a room ID.
+        pub events: Vec<Raw<AnyTimelineEvent>>,
+    }
+
+    /// Response type for the `batch_send` endpoint.
+    #[response(error = crate::Error)]
+    pub struct Response {
+        /// The IDs of the state events that were created from `state_events_at_start`.
+        #[serde(default)]
+        pub state_event_ids: Vec<OwnedEventId>,
+
+        /// The IDs of the historical events that were created from `events`.
+        pub event_ids: Vec<OwnedEventId>,
+
+        /// The batch ID to use to send the batch of events that precedes this one.
+        pub next_batch_id: String,
+
+        /// The ID of the insertion event at the start of this batch.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub insertion_event_id: Option<OwnedEventId>,
+
+        /// The ID of the batch event at the end of this batch.
+        pub batch_event_id: OwnedEventId,
+
+        /// The ID of the insertion event that was created after `prev_event_id`, if `batch_id`
+        /// was not set in the request.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub base_insertion_event_id: Option<OwnedEventId>,
+    }
+
+    impl Request {
+        /// Creates a new `Request` with the given room ID, previous event ID and events.
+        pub fn new(
+            room_id: OwnedRoomId,
+            prev_event_id: OwnedEventId,
+            events: Vec<Raw<AnyTimelineEvent>>,
+        ) -> Self {
+            Self {
+                room_id,
+                prev_event_id,
+                batch_id: None,
+                state_events_at_start: Vec::new(),
+                events,
+            }
+        }
+    }
+
+    impl Response {
+        /// Creates a new `Response` with the given event IDs, next batch ID and batch event ID.
+        pub fn new(
+            event_ids: Vec<OwnedEventId>,
+            next_batch_id: String,
+            batch_event_id: OwnedEventId,
+        ) -> Self {
+            Self {
+                state_event_ids: Vec::new(),
+                event_ids,
+                next_batch_id,
+                insertion_event_id: None,
+                batch_event_id,
+                base_insertion_event_id: None,
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+            owned_event_id, owned_room_id,
+            serde::Raw,
+        };
+        use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+        use super::Request;
+
+        #[test]
+        fn serialize_request() {
+            let event = Raw::new(&json!({
+                "content": {
+                    "body": "Hello",
+                    "msgtype": "m.text",
+                },
+                "origin_server_ts": 1,
+                "sender": "@alice:example.org",
+                "type": "m.room.message",
+            }))
+            .unwrap()
+            .cast();
+
+            let mut request = Request::new(
+                owned_room_id!("!room:example.org"),
+                owned_event_id!("$prev:example.org"),
+                vec![event],
+            );
+            request.batch_id = Some("batch".to_owned());
+
+            let http_request = request
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(
+                http_request.uri().path(),
+                "/_matrix/client/unstable/org.matrix.msc2716/rooms/!room:example.org/batch_send"
+            );
+            assert_eq!(
+                http_request.uri().query(),
+                Some("prev_event_id=%24prev%3Aexample.org&batch_id=batch")
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_request.body()).unwrap(),
+                json!({
+                    "events": [{
+                        "content": {
+                            "body": "Hello",
+                            "msgtype": "m.text",
+                        },
+                        "origin_server_ts": 1,
+                        "sender": "@alice:example.org",
+                        "type": "m.room.message",
+                    }],
+                })
+            );
+        }
+    }
+}
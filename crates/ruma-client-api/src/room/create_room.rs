@@ -13,11 +13,14 @@ pub mod v3 {
         metadata,
         room::RoomType,
         serde::{Raw, StringEnum},
-        OwnedRoomId, OwnedUserId, RoomVersionId,
+        OwnedRoomId, OwnedUserId, RoomVersionId, UserId,
     };
     use ruma_events::{
         room::{
             create::{PreviousRoom, RoomCreateEventContent},
+            guest_access::GuestAccess,
+            history_visibility::HistoryVisibility,
+            join_rules::JoinRule,
             power_levels::RoomPowerLevelsEventContent,
         },
         AnyInitialStateEvent,
@@ -201,4 +204,105 @@ pub mod v3 {
         #[doc(hidden)]
         _Custom(PrivOwnedStr),
     }
+
+    impl RoomPreset {
+        /// The join rule of a room created with this preset.
+        ///
+        /// Returns `None` for custom presets.
+        pub fn join_rule(&self) -> Option<JoinRule> {
+            match self {
+                Self::PrivateChat | Self::TrustedPrivateChat => Some(JoinRule::Invite),
+                Self::PublicChat => Some(JoinRule::Public),
+                Self::_Custom(_) => None,
+            }
+        }
+
+        /// The history visibility of a room created with this preset.
+        ///
+        /// Returns `None` for custom presets.
+        pub fn history_visibility(&self) -> Option<HistoryVisibility> {
+            match self {
+                Self::PrivateChat | Self::TrustedPrivateChat | Self::PublicChat => {
+                    Some(HistoryVisibility::Shared)
+                }
+                Self::_Custom(_) => None,
+            }
+        }
+
+        /// The guest access of a room created with this preset.
+        ///
+        /// Returns `None` for custom presets.
+        pub fn guest_access(&self) -> Option<GuestAccess> {
+            match self {
+                Self::PrivateChat | Self::TrustedPrivateChat => Some(GuestAccess::CanJoin),
+                Self::PublicChat => Some(GuestAccess::Forbidden),
+                Self::_Custom(_) => None,
+            }
+        }
+
+        /// The initial power levels of a room created with this preset.
+        ///
+        /// The creator gets the power level `100`. With `TrustedPrivateChat`, the given invitees
+        /// get the same power level as the creator.
+        pub fn power_levels(
+            &self,
+            creator: &UserId,
+            invitees: &[OwnedUserId],
+        ) -> RoomPowerLevelsEventContent {
+            let mut content = RoomPowerLevelsEventContent::with_creator(creator.to_owned());
+
+            if *self == Self::TrustedPrivateChat {
+                let creator_level = content.users[creator];
+                content
+                    .users
+                    .extend(invitees.iter().map(|user_id| (user_id.clone(), creator_level)));
+            }
+
+            content
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use js_int::int;
+        use ruma_common::{owned_user_id, user_id};
+        use ruma_events::room::{
+            guest_access::GuestAccess, history_visibility::HistoryVisibility, join_rules::JoinRule,
+        };
+
+        use super::RoomPreset;
+
+        #[test]
+        fn preset_state() {
+            let preset = RoomPreset::PrivateChat;
+            assert_eq!(preset.join_rule(), Some(JoinRule::Invite));
+            assert_eq!(preset.history_visibility(), Some(HistoryVisibility::Shared));
+            assert_eq!(preset.guest_access(), Some(GuestAccess::CanJoin));
+
+            let preset = RoomPreset::PublicChat;
+            assert_eq!(preset.join_rule(), Some(JoinRule::Public));
+            assert_eq!(preset.history_visibility(), Some(HistoryVisibility::Shared));
+            assert_eq!(preset.guest_access(), Some(GuestAccess::Forbidden));
+
+            let preset = RoomPreset::from("dev.ruma.custom");
+            assert_eq!(preset.join_rule(), None);
+            assert_eq!(preset.history_visibility(), None);
+            assert_eq!(preset.guest_access(), None);
+        }
+
+        #[test]
+        fn preset_power_levels() {
+            let creator = user_id!("@alice:localhost");
+            let invitees = [owned_user_id!("@bob:localhost")];
+
+            let power_levels = RoomPreset::PrivateChat.power_levels(creator, &invitees);
+            assert_eq!(power_levels.users.len(), 1);
+            assert_eq!(power_levels.users[creator], int!(100));
+
+            let power_levels = RoomPreset::TrustedPrivateChat.power_levels(creator, &invitees);
+            assert_eq!(power_levels.users.len(), 2);
+            assert_eq!(power_levels.users[creator], int!(100));
+            assert_eq!(power_levels.users[&invitees[0]], int!(100));
+        }
+    }
 }
@@ -77,4 +77,37 @@ pub mod v1 {
             Self { event_id, origin_server_ts }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use js_int::uint;
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+            owned_room_id, MilliSecondsSinceUnixEpoch,
+        };
+
+        use super::Request;
+
+        #[test]
+        fn serialize_request() {
+            let request = Request::until(
+                owned_room_id!("!room:example.org"),
+                MilliSecondsSinceUnixEpoch(uint!(1_000)),
+            );
+
+            let http_request = request
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_6],
+                )
+                .unwrap();
+
+            assert_eq!(
+                http_request.uri().path(),
+                "/_matrix/client/v1/rooms/!room:example.org/timestamp_to_event"
+            );
+            assert_eq!(http_request.uri().query(), Some("ts=1000&dir=b"));
+        }
+    }
 }
@@ -0,0 +1,98 @@
+//! `POST /_matrix/client/*/rooms/{roomId}/report`
+//!
+//! Report a room as inappropriate.
+//!
+//! The stable `/v3/` path of this endpoint was added in Matrix 1.13. It is not registered yet
+//! because stable paths must be associated with a [`MatrixVersion`], and the newest one known to
+//! Ruma is 1.9, so only the unstable path is available for now.
+//!
+//! [`MatrixVersion`]: ruma_common::api::MatrixVersion
+
+pub mod unstable {
+    //! `msc4151` ([MSC])
+    //!
+    //! [MSC]: https://github.com/matrix-org/matrix-spec-proposals/pull/4151
+
+    use ruma_common::{
+        api::{request, response, Metadata},
+        metadata, OwnedRoomId,
+    };
+
+    const METADATA: Metadata = metadata! {
+        method: POST,
+        rate_limited: true,
+        authentication: AccessToken,
+        history: {
+            unstable => "/_matrix/client/unstable/org.matrix.msc4151/rooms/:room_id/report",
+        }
+    };
+
+    /// Request type for the `report_room` endpoint.
+    #[request(error = crate::Error)]
+    pub struct Request {
+        /// The room to report.
+        #[ruma_api(path)]
+        pub room_id: OwnedRoomId,
+
+        /// The reason to report the room.
+        ///
+        /// May be blank.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+    }
+
+    /// Response type for the `report_room` endpoint.
+    #[response(error = crate::Error)]
+    #[derive(Default)]
+    pub struct Response {}
+
+    impl Request {
+        /// Creates a new `Request` with the given room ID and reason.
+        pub fn new(room_id: OwnedRoomId, reason: Option<String>) -> Self {
+            Self { room_id, reason }
+        }
+    }
+
+    impl Response {
+        /// Creates an empty `Response`.
+        pub fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+            owned_room_id,
+        };
+        use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+        use super::Request;
+
+        #[test]
+        fn serialize_request() {
+            let request = Request::new(
+                owned_room_id!("!room:example.org"),
+                Some("This room is full of spam".to_owned()),
+            );
+
+            let http_request = request
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(
+                http_request.uri().path(),
+                "/_matrix/client/unstable/org.matrix.msc4151/rooms/!room:example.org/report"
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_request.body()).unwrap(),
+                json!({ "reason": "This room is full of spam" })
+            );
+        }
+    }
+}
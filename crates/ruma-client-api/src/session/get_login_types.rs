@@ -172,6 +172,21 @@ pub mod v3 {
         /// The identity provider choices.
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
         pub identity_providers: Vec<IdentityProvider>,
+
+        /// Whether this SSO login flow is compatible with the OpenID Connect Provider the
+        /// homeserver delegates authentication to ([MSC3824]).
+        ///
+        /// If this is `true`, clients should prefer this flow to login with the `m.login.token`
+        /// that they receive at the end of the SSO flow.
+        ///
+        /// [MSC3824]: https://github.com/matrix-org/matrix-spec-proposals/pull/3824
+        #[cfg(feature = "unstable-msc3824")]
+        #[serde(
+            default,
+            rename = "org.matrix.msc3824.delegated_oidc_compatibility",
+            skip_serializing_if = "ruma_common::serde::is_default"
+        )]
+        pub delegated_oidc_compatibility: bool,
     }
 
     impl SsoLoginType {
@@ -387,7 +402,7 @@ pub mod v3 {
             assert_eq!(wrapper.flows.len(), 1);
             let flow = &wrapper.flows[0];
 
-            assert_matches!(flow, LoginType::Sso(SsoLoginType { identity_providers }));
+            assert_matches!(flow, LoginType::Sso(SsoLoginType { identity_providers, .. }));
             assert_eq!(identity_providers.len(), 2);
 
             let provider = &identity_providers[0];
@@ -415,6 +430,8 @@ pub mod v3 {
                             icon: Some("mxc://localhost/github-icon".into()),
                             brand: Some(IdentityProviderBrand::GitHub),
                         }],
+                        #[cfg(feature = "unstable-msc3824")]
+                        delegated_oidc_compatibility: false,
                     }),
                 ],
             })
@@ -442,5 +459,24 @@ pub mod v3 {
                 })
             );
         }
+
+        #[test]
+        #[cfg(feature = "unstable-msc3824")]
+        fn delegated_oidc_compatibility() {
+            let json = json!({
+                "flows": [
+                    {
+                        "type": "m.login.sso",
+                        "org.matrix.msc3824.delegated_oidc_compatibility": true,
+                    }
+                ],
+            });
+
+            let wrapper = from_json_value::<Wrapper>(json.clone()).unwrap();
+            assert_matches!(&wrapper.flows[0], LoginType::Sso(sso));
+            assert!(sso.delegated_oidc_compatibility);
+
+            assert_eq!(to_json_value(wrapper).unwrap(), json);
+        }
     }
 }
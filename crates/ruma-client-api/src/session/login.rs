@@ -7,7 +7,7 @@ pub mod v3 {
     //!
     //! [spec]: https://spec.matrix.org/latest/client-server-api/#post_matrixclientv3login
 
-    use std::{fmt, time::Duration};
+    use std::{borrow::Cow, fmt, time::Duration};
 
     use ruma_common::{
         api::{request, response, Metadata},
@@ -158,16 +158,16 @@ pub mod v3 {
     }
 
     impl LoginInfo {
-        /// Creates a new `IncomingLoginInfo` with the given `login_type` string, session and data.
+        /// Creates a new `LoginInfo` with the given `login_type` string and data.
         ///
-        /// Prefer to use the public variants of `IncomingLoginInfo` where possible; this
-        /// constructor is meant be used for unsupported authentication mechanisms only and
-        /// does not allow setting arbitrary data for supported ones.
+        /// Prefer to use the public variants of `LoginInfo` where possible; this constructor is
+        /// meant be used for unsupported authentication mechanisms only and does not allow setting
+        /// arbitrary data for supported ones.
         ///
         /// # Errors
         ///
         /// Returns an error if the `login_type` is known and serialization of `data` to the
-        /// corresponding `IncomingLoginInfo` variant fails.
+        /// corresponding `LoginInfo` variant fails.
         pub fn new(login_type: &str, data: JsonObject) -> serde_json::Result<Self> {
             Ok(match login_type {
                 "m.login.password" => {
@@ -180,6 +180,42 @@ pub mod v3 {
                 _ => Self::_Custom(CustomLoginInfo { login_type: login_type.into(), extra: data }),
             })
         }
+
+        /// Returns the value of the `type` field.
+        pub fn login_type(&self) -> &str {
+            match self {
+                Self::Password(_) => "m.login.password",
+                Self::Token(_) => "m.login.token",
+                Self::ApplicationService(_) => "m.login.application_service",
+                Self::_Custom(c) => &c.login_type,
+            }
+        }
+
+        /// Returns the associated data.
+        ///
+        /// The returned JSON object won't contain the `type` field, use
+        /// [`.login_type()`][Self::login_type] to access it.
+        ///
+        /// Prefer to use the public variants of `LoginInfo` where possible; this method is meant
+        /// to be used for custom login types only.
+        pub fn data(&self) -> Cow<'_, JsonObject> {
+            fn serialize<T: Serialize>(obj: &T) -> JsonObject {
+                match serde_json::to_value(obj).expect("login info serialization to succeed") {
+                    JsonValue::Object(mut obj) => {
+                        obj.remove("type");
+                        obj
+                    }
+                    _ => panic!("all login info variants must serialize to objects"),
+                }
+            }
+
+            match self {
+                Self::Password(x) => Cow::Owned(serialize(x)),
+                Self::Token(x) => Cow::Owned(serialize(x)),
+                Self::ApplicationService(x) => Cow::Owned(serialize(x)),
+                Self::_Custom(c) => Cow::Borrowed(&c.extra),
+            }
+        }
     }
 
     impl fmt::Debug for LoginInfo {
@@ -302,11 +338,15 @@ pub mod v3 {
     }
 
     /// An identifier to supply for Application Service authentication.
+    ///
+    /// The request must be authenticated with the `as_token` of the application service, by
+    /// sending it with [`SendAccessToken::Always`](ruma_common::api::SendAccessToken::Always).
     #[derive(Clone, Debug, Deserialize, Serialize)]
     #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
     #[serde(tag = "type", rename = "m.login.application_service")]
     pub struct ApplicationService {
         /// Identification information for the user.
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub identifier: Option<UserIdentifier>,
 
         /// Username for the user.
@@ -398,7 +438,9 @@ pub mod v3 {
     #[cfg(test)]
     mod tests {
         use assert_matches2::assert_matches;
-        use serde_json::{from_value as from_json_value, json};
+        use serde_json::{
+            from_value as from_json_value, json, to_value as to_json_value, Value as JsonValue,
+        };
 
         use super::{LoginInfo, Token};
         use crate::uiaa::UserIdentifier;
@@ -432,6 +474,45 @@ pub mod v3 {
             assert_eq!(token, "1234567890abcdef");
         }
 
+        #[test]
+        fn login_info_type_and_data() {
+            let login_info = from_json_value::<LoginInfo>(json!({
+                "type": "m.login.application_service",
+                "identifier": {
+                    "type": "m.id.user",
+                    "user": "_bridge_bot"
+                },
+            }))
+            .unwrap();
+            assert_eq!(login_info.login_type(), "m.login.application_service");
+            assert_eq!(
+                JsonValue::Object(login_info.data().into_owned()),
+                json!({
+                    "identifier": {
+                        "type": "m.id.user",
+                        "user": "_bridge_bot"
+                    },
+                })
+            );
+
+            let data = json!({ "secret": "hunter2" });
+            let JsonValue::Object(data) = data else { unreachable!() };
+            let login_info = LoginInfo::new("dev.ruma.custom", data.clone()).unwrap();
+            assert_eq!(login_info.login_type(), "dev.ruma.custom");
+            assert_eq!(*login_info.data(), data);
+            assert_eq!(
+                to_json_value(&login_info).unwrap(),
+                json!({ "type": "dev.ruma.custom", "secret": "hunter2" })
+            );
+
+            let login_info = from_json_value::<LoginInfo>(
+                json!({ "type": "dev.ruma.custom", "secret": "hunter2" }),
+            )
+            .unwrap();
+            assert_eq!(login_info.login_type(), "dev.ruma.custom");
+            assert_eq!(*login_info.data(), data);
+        }
+
         #[test]
         #[cfg(feature = "client")]
         fn serialize_login_request_body() {
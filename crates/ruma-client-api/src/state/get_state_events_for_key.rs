@@ -7,13 +7,17 @@ pub mod v3 {
     //!
     //! [spec]: https://spec.matrix.org/latest/client-server-api/#get_matrixclientv3roomsroomidstateeventtypestatekey
 
+    use std::borrow::Borrow;
+
     use ruma_common::{
         api::{response, Metadata},
         metadata,
         serde::Raw,
         OwnedRoomId,
     };
-    use ruma_events::{AnyStateEventContent, StateEventType};
+    use ruma_events::{
+        AnyStateEventContent, StateEventContent, StateEventType, StaticEventContent,
+    };
 
     const METADATA: Metadata = metadata! {
         method: GET,
@@ -44,6 +48,19 @@ pub mod v3 {
         pub fn new(room_id: OwnedRoomId, event_type: StateEventType, state_key: String) -> Self {
             Self { room_id, event_type, state_key }
         }
+
+        /// Creates a new `Request` for the state event with the content type `C` and the given
+        /// room ID and state key.
+        ///
+        /// The state key must match the type of state key of `C`.
+        pub fn new_static<C, K>(room_id: OwnedRoomId, state_key: &K) -> Self
+        where
+            C: StateEventContent + StaticEventContent,
+            C::StateKey: Borrow<K>,
+            K: AsRef<str> + ?Sized,
+        {
+            Self { room_id, event_type: C::TYPE.into(), state_key: state_key.as_ref().to_owned() }
+        }
     }
 
     /// Response type for the `get_state_events_for_key` endpoint.
@@ -142,4 +159,47 @@ pub mod v3 {
             Ok(Self { room_id, event_type, state_key })
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+            owned_room_id, user_id,
+        };
+        use ruma_events::{
+            room::{member::RoomMemberEventContent, name::RoomNameEventContent},
+            EmptyStateKey, StateEventType,
+        };
+
+        use super::Request;
+
+        #[test]
+        fn new_static() {
+            let request = Request::new_static::<RoomNameEventContent, _>(
+                owned_room_id!("!room:example.org"),
+                &EmptyStateKey,
+            );
+            assert_eq!(request.event_type, StateEventType::RoomName);
+            assert_eq!(request.state_key, "");
+
+            let request = Request::new_static::<RoomMemberEventContent, _>(
+                owned_room_id!("!room:example.org"),
+                user_id!("@alice:example.org"),
+            );
+            assert_eq!(request.event_type, StateEventType::RoomMember);
+
+            let http_request = request
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(
+                http_request.uri().path(),
+                "/_matrix/client/v3/rooms/!room:example.org/state/m.room.member/@alice:example.org"
+            );
+        }
+    }
 }
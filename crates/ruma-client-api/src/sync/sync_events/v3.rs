@@ -2,7 +2,7 @@
 //!
 //! [spec]: https://spec.matrix.org/latest/client-server-api/#get_matrixclientv3sync
 
-use std::{collections::BTreeMap, time::Duration};
+use std::{collections::BTreeMap, fmt, time::Duration};
 
 use js_int::UInt;
 use ruma_common::{
@@ -10,7 +10,8 @@ use ruma_common::{
     metadata,
     presence::PresenceState,
     serde::Raw,
-    DeviceKeyAlgorithm, OwnedEventId, OwnedRoomId,
+    DeviceKeyAlgorithm, OwnedEventId, OwnedRoomAliasId, OwnedRoomId, OwnedUserId, RoomAliasId,
+    UserId,
 };
 use ruma_events::{
     presence::PresenceEvent, AnyGlobalAccountDataEvent, AnyRoomAccountDataEvent,
@@ -104,11 +105,11 @@ pub struct Response {
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub device_one_time_keys_count: BTreeMap<DeviceKeyAlgorithm, UInt>,
 
-    /// For each key algorithm, the number of unclaimed one-time keys
-    /// currently held on the server for a device.
+    /// The unused fallback key algorithms.
     ///
     /// The presence of this field indicates that the server supports
     /// fallback keys.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub device_unused_fallback_key_types: Option<Vec<DeviceKeyAlgorithm>>,
 }
 
@@ -200,7 +201,10 @@ impl Rooms {
 
     /// Returns true if there is no update in any room.
     pub fn is_empty(&self) -> bool {
-        self.leave.is_empty() && self.join.is_empty() && self.invite.is_empty()
+        self.leave.is_empty()
+            && self.join.is_empty()
+            && self.invite.is_empty()
+            && self.knock.is_empty()
     }
 }
 
@@ -228,7 +232,7 @@ impl LeftRoom {
         Default::default()
     }
 
-    /// Returns true if there are updates in the room.
+    /// Returns true if there are no updates in the room.
     pub fn is_empty(&self) -> bool {
         self.timeline.is_empty() && self.state.is_empty() && self.account_data.is_empty()
     }
@@ -322,17 +326,55 @@ impl JoinedRoom {
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
 pub struct KnockedRoom {
     /// The knock state.
+    #[serde(default, skip_serializing_if = "KnockState::is_empty")]
     pub knock_state: KnockState,
 }
 
+impl KnockedRoom {
+    /// Creates an empty `KnockedRoom`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns true if there are no updates to this room.
+    pub fn is_empty(&self) -> bool {
+        self.knock_state.is_empty()
+    }
+}
+
+impl From<KnockState> for KnockedRoom {
+    fn from(knock_state: KnockState) -> Self {
+        KnockedRoom { knock_state, ..Default::default() }
+    }
+}
+
 /// A mapping from a key `events` to a list of `StrippedStateEvent`.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
 pub struct KnockState {
     /// The list of events.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub events: Vec<Raw<AnyStrippedStateEvent>>,
 }
 
+impl KnockState {
+    /// Creates an empty `KnockState`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns true if there are no state updates.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl From<Vec<Raw<AnyStrippedStateEvent>>> for KnockState {
+    fn from(events: Vec<Raw<AnyStrippedStateEvent>>) -> Self {
+        KnockState { events, ..Default::default() }
+    }
+}
+
 /// Events in the room.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
@@ -468,7 +510,7 @@ pub struct RoomSummary {
     ///
     /// Required if room name or canonical aliases are not set or empty.
     #[serde(rename = "m.heroes", default, skip_serializing_if = "Vec::is_empty")]
-    pub heroes: Vec<String>,
+    pub heroes: Vec<OwnedUserId>,
 
     /// Number of users whose membership status is `join`.
     /// Required if field has changed since last sync; otherwise, it may be
@@ -495,6 +537,123 @@ impl RoomSummary {
             && self.joined_member_count.is_none()
             && self.invited_member_count.is_none()
     }
+
+    /// Calculates the display name of the room, following the [algorithm from the spec].
+    ///
+    /// `name` and `canonical_alias` are the contents of the `m.room.name` and
+    /// `m.room.canonical_alias` state events of the room, if any. `hero_name` should return the
+    /// display name of the given hero, disambiguated from the other members of the room if
+    /// necessary. The user ID of the hero is used if it returns `None`.
+    ///
+    /// This summary should contain the latest known values of all its fields, since they are
+    /// omitted from sync responses when they didn't change. If both member counts are missing,
+    /// the heroes are assumed to be the only other members of the room.
+    ///
+    /// [algorithm from the spec]: https://spec.matrix.org/latest/client-server-api/#calculating-the-display-name-for-a-room
+    pub fn display_name(
+        &self,
+        name: Option<&str>,
+        canonical_alias: Option<&RoomAliasId>,
+        hero_name: impl Fn(&UserId) -> Option<String>,
+    ) -> RoomDisplayName {
+        if let Some(name) = name.filter(|name| !name.is_empty()) {
+            return RoomDisplayName::Named(name.to_owned());
+        }
+
+        if let Some(alias) = canonical_alias {
+            return RoomDisplayName::Aliased(alias.to_owned());
+        }
+
+        let heroes: Vec<_> = self
+            .heroes
+            .iter()
+            .map(|user_id| hero_name(user_id).unwrap_or_else(|| user_id.to_string()))
+            .collect();
+
+        let member_count = match (self.joined_member_count, self.invited_member_count) {
+            (None, None) => UInt::try_from(heroes.len() + 1).unwrap_or(UInt::MAX),
+            (joined, invited) => {
+                joined.unwrap_or_default().saturating_add(invited.unwrap_or_default())
+            }
+        };
+
+        if member_count <= UInt::from(1_u32) {
+            return RoomDisplayName::Empty { heroes };
+        }
+
+        let others = (member_count - UInt::from(1_u32))
+            .saturating_sub(UInt::try_from(heroes.len()).unwrap_or(UInt::MAX));
+
+        RoomDisplayName::Calculated { heroes, others }
+    }
+}
+
+/// The display name of a room, as calculated by [`RoomSummary::display_name()`].
+///
+/// The `Display` implementation renders the name in English, like in the examples of the spec.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub enum RoomDisplayName {
+    /// The room has a name.
+    Named(String),
+
+    /// The room has no name but a canonical alias.
+    Aliased(OwnedRoomAliasId),
+
+    /// The name is calculated from the other members of the room.
+    Calculated {
+        /// The names of the heroes of the room.
+        heroes: Vec<String>,
+
+        /// The number of other members of the room that are not heroes.
+        others: UInt,
+    },
+
+    /// The user is alone in the room.
+    Empty {
+        /// The names of the heroes of the room, that used to be members of the room.
+        heroes: Vec<String>,
+    },
+}
+
+impl fmt::Display for RoomDisplayName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Named(name) => f.write_str(name),
+            Self::Aliased(alias) => f.write_str(alias.as_str()),
+            Self::Calculated { heroes, others } => fmt_member_names(f, heroes, *others),
+            Self::Empty { heroes } if heroes.is_empty() => f.write_str("Empty Room"),
+            Self::Empty { heroes } => {
+                f.write_str("Empty Room (was ")?;
+                fmt_member_names(f, heroes, UInt::MIN)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// Writes a list of member names like "Alice, Bob, and 2 others".
+fn fmt_member_names(f: &mut fmt::Formatter<'_>, names: &[String], others: UInt) -> fmt::Result {
+    let others = (others > UInt::MIN).then(|| {
+        if others == UInt::from(1_u32) {
+            "1 other".to_owned()
+        } else {
+            format!("{others} others")
+        }
+    });
+    let items: Vec<&str> = names.iter().map(String::as_str).chain(others.as_deref()).collect();
+
+    match items.as_slice() {
+        [] => Ok(()),
+        [item] => f.write_str(item),
+        [first, second] => write!(f, "{first} and {second}"),
+        [init @ .., last] => {
+            for item in init {
+                write!(f, "{item}, ")?;
+            }
+            write!(f, "and {last}")
+        }
+    }
 }
 
 /// Updates to the rooms that the user has been invited to.
@@ -596,9 +755,153 @@ impl ToDevice {
 #[cfg(test)]
 mod tests {
     use assign::assign;
+    use js_int::uint;
+    use ruma_common::{
+        event_id, owned_room_alias_id, owned_room_id, owned_user_id, room_alias_id, UserId,
+    };
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::Timeline;
+    use super::{JoinedRoom, KnockedRoom, RoomDisplayName, RoomSummary, Rooms, Timeline};
+
+    #[test]
+    fn room_summary_serde() {
+        let summary = assign!(RoomSummary::new(), {
+            heroes: vec![owned_user_id!("@alice:example.org"), owned_user_id!("@bob:example.org")],
+            joined_member_count: Some(uint!(2)),
+            invited_member_count: Some(uint!(0)),
+        });
+        let summary_serialized = json!({
+            "m.heroes": ["@alice:example.org", "@bob:example.org"],
+            "m.joined_member_count": 2,
+            "m.invited_member_count": 0,
+        });
+        assert_eq!(to_json_value(&summary).unwrap(), summary_serialized);
+
+        let summary_deserialized = from_json_value::<RoomSummary>(summary_serialized).unwrap();
+        assert_eq!(summary_deserialized.heroes, summary.heroes);
+        assert_eq!(summary_deserialized.joined_member_count, Some(uint!(2)));
+        assert_eq!(summary_deserialized.invited_member_count, Some(uint!(0)));
+
+        assert!(RoomSummary::new().is_empty());
+        assert_eq!(to_json_value(RoomSummary::new()).unwrap(), json!({}));
+
+        from_json_value::<RoomSummary>(json!({ "m.heroes": ["alice"] })).unwrap_err();
+    }
+
+    #[test]
+    fn room_display_name() {
+        let summary = assign!(RoomSummary::new(), {
+            heroes: vec![owned_user_id!("@alice:example.org"), owned_user_id!("@bob:example.org")],
+            joined_member_count: Some(uint!(3)),
+            invited_member_count: Some(uint!(0)),
+        });
+        let hero_name =
+            |user_id: &UserId| (user_id == "@alice:example.org").then(|| "Alice".to_owned());
+
+        assert_eq!(
+            summary.display_name(
+                Some("The Room"),
+                Some(room_alias_id!("#room:example.org")),
+                hero_name
+            ),
+            RoomDisplayName::Named("The Room".to_owned())
+        );
+
+        let name =
+            summary.display_name(Some(""), Some(room_alias_id!("#room:example.org")), hero_name);
+        assert_eq!(name, RoomDisplayName::Aliased(owned_room_alias_id!("#room:example.org")));
+        assert_eq!(name.to_string(), "#room:example.org");
+
+        let name = summary.display_name(None, None, hero_name);
+        assert_eq!(
+            name,
+            RoomDisplayName::Calculated {
+                heroes: vec!["Alice".to_owned(), "@bob:example.org".to_owned()],
+                others: uint!(0),
+            }
+        );
+        assert_eq!(name.to_string(), "Alice and @bob:example.org");
+    }
+
+    #[test]
+    fn room_display_name_with_others() {
+        let summary = assign!(RoomSummary::new(), {
+            heroes: vec![owned_user_id!("@alice:example.org"), owned_user_id!("@bob:example.org")],
+            joined_member_count: Some(uint!(10)),
+            invited_member_count: Some(uint!(2)),
+        });
+
+        let name = summary.display_name(None, None, |user_id| Some(user_id.localpart().to_owned()));
+        assert_eq!(
+            name,
+            RoomDisplayName::Calculated {
+                heroes: vec!["alice".to_owned(), "bob".to_owned()],
+                others: uint!(9),
+            }
+        );
+        assert_eq!(name.to_string(), "alice, bob, and 9 others");
+
+        let summary = assign!(RoomSummary::new(), {
+            heroes: vec![owned_user_id!("@alice:example.org")],
+        });
+        let name = summary.display_name(None, None, |_| None);
+        assert_eq!(name.to_string(), "@alice:example.org");
+
+        let summary = assign!(RoomSummary::new(), {
+            heroes: vec![owned_user_id!("@alice:example.org")],
+            joined_member_count: Some(uint!(3)),
+        });
+        let name = summary.display_name(None, None, |_| Some("Alice".to_owned()));
+        assert_eq!(name.to_string(), "Alice and 1 other");
+    }
+
+    #[test]
+    fn empty_room_display_name() {
+        let summary = assign!(RoomSummary::new(), {
+            joined_member_count: Some(uint!(1)),
+            invited_member_count: Some(uint!(0)),
+        });
+        let name = summary.display_name(None, None, |_| None);
+        assert_eq!(name, RoomDisplayName::Empty { heroes: vec![] });
+        assert_eq!(name.to_string(), "Empty Room");
+
+        let summary = assign!(RoomSummary::new(), {
+            heroes: vec![
+                owned_user_id!("@alice:example.org"),
+                owned_user_id!("@bob:example.org"),
+                owned_user_id!("@carl:example.org"),
+            ],
+            joined_member_count: Some(uint!(1)),
+            invited_member_count: Some(uint!(0)),
+        });
+        let name = summary.display_name(None, None, |user_id| Some(user_id.localpart().to_owned()));
+        assert_eq!(name.to_string(), "Empty Room (was alice, bob, and carl)");
+    }
+
+    #[test]
+    fn joined_room_notification_counts() {
+        let joined_room = from_json_value::<JoinedRoom>(json!({
+            "unread_notifications": {
+                "highlight_count": 1,
+                "notification_count": 5,
+            },
+            "unread_thread_notifications": {
+                "$thread:example.org": {
+                    "highlight_count": 0,
+                    "notification_count": 2,
+                },
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(joined_room.unread_notifications.highlight_count, Some(uint!(1)));
+        assert_eq!(joined_room.unread_notifications.notification_count, Some(uint!(5)));
+        let thread_notifications =
+            &joined_room.unread_thread_notifications[event_id!("$thread:example.org")];
+        assert_eq!(thread_notifications.highlight_count, Some(uint!(0)));
+        assert_eq!(thread_notifications.notification_count, Some(uint!(2)));
+        assert!(!joined_room.is_empty());
+    }
 
     #[test]
     fn timeline_serde() {
@@ -615,15 +918,54 @@ mod tests {
         let timeline_default_deserialized = from_json_value::<Timeline>(json!({})).unwrap();
         assert!(!timeline_default_deserialized.limited);
     }
+
+    #[test]
+    fn knocked_room_serde() {
+        let knocked_room = from_json_value::<KnockedRoom>(json!({
+            "knock_state": {
+                "events": [
+                    {
+                        "content": { "name": "My Room Name" },
+                        "sender": "@bob:example.com",
+                        "state_key": "",
+                        "type": "m.room.name",
+                    },
+                ],
+            },
+        }))
+        .unwrap();
+        assert!(!knocked_room.is_empty());
+        assert_eq!(
+            knocked_room.knock_state.events[0].get_field::<String>("type").unwrap().as_deref(),
+            Some("m.room.name")
+        );
+
+        let knocked_room = from_json_value::<KnockedRoom>(json!({})).unwrap();
+        assert!(knocked_room.is_empty());
+        assert_eq!(to_json_value(knocked_room).unwrap(), json!({}));
+    }
+
+    #[test]
+    fn rooms_with_knock_not_empty() {
+        let mut rooms = Rooms::new();
+        assert!(rooms.is_empty());
+
+        rooms.knock.insert(owned_room_id!("!room:example.com"), KnockedRoom::new());
+        assert!(!rooms.is_empty());
+    }
 }
 
 #[cfg(all(test, feature = "client"))]
 mod client_tests {
     use std::time::Duration;
 
-    use ruma_common::api::{MatrixVersion, OutgoingRequest as _, SendAccessToken};
+    use ruma_common::{
+        api::{IncomingResponse as _, MatrixVersion, OutgoingRequest as _, SendAccessToken},
+        room_id, DeviceKeyAlgorithm,
+    };
+    use serde_json::{json, to_vec as to_json_vec};
 
-    use super::{Filter, PresenceState, Request};
+    use super::{Filter, PresenceState, Request, Response};
 
     #[test]
     fn serialize_all_params() {
@@ -651,6 +993,89 @@ mod client_tests {
         assert!(query.contains("set_presence=offline"));
         assert!(query.contains("timeout=30000"));
     }
+
+    #[test]
+    fn deserialize_e2ee_sections() {
+        let body = json!({
+            "next_batch": "s72595_4483_1934",
+            "to_device": {
+                "events": [
+                    {
+                        "type": "m.dummy",
+                        "sender": "@alice:example.org",
+                        "content": {},
+                    },
+                ],
+            },
+            "device_lists": {
+                "changed": ["@bob:example.org"],
+                "left": ["@carl:example.org"],
+            },
+            "device_one_time_keys_count": {
+                "signed_curve25519": 20,
+            },
+            "device_unused_fallback_key_types": ["signed_curve25519"],
+        });
+        let http_response = http::Response::new(to_json_vec(&body).unwrap());
+
+        let response = Response::try_from_http_response(http_response).unwrap();
+
+        assert_eq!(response.next_batch, "s72595_4483_1934");
+        assert_eq!(response.to_device.events.len(), 1);
+        assert_eq!(response.device_lists.changed, ["@bob:example.org"]);
+        assert_eq!(response.device_lists.left, ["@carl:example.org"]);
+        assert_eq!(
+            response.device_one_time_keys_count.get(&DeviceKeyAlgorithm::SignedCurve25519),
+            Some(&20_u32.into())
+        );
+        assert_eq!(
+            response.device_unused_fallback_key_types.as_deref(),
+            Some(&[DeviceKeyAlgorithm::SignedCurve25519][..])
+        );
+    }
+
+    #[test]
+    fn deserialize_timeline_with_malformed_event() {
+        let body = json!({
+            "next_batch": "s72595_4483_1934",
+            "rooms": {
+                "join": {
+                    "!room:example.org": {
+                        "timeline": {
+                            "events": [
+                                {
+                                    "type": "m.room.message",
+                                    "event_id": "$valid:example.org",
+                                    "sender": "@alice:example.org",
+                                    "origin_server_ts": 1_432_735_824_653_u64,
+                                    "content": {
+                                        "msgtype": "m.text",
+                                        "body": "Hello",
+                                    },
+                                },
+                                {
+                                    "type": "m.room.message",
+                                    "event_id": "$malformed:example.org",
+                                    "sender": "not a user ID",
+                                    "content": {},
+                                },
+                            ],
+                        },
+                    },
+                },
+            },
+        });
+        let http_response = http::Response::new(to_json_vec(&body).unwrap());
+
+        // A malformed event doesn't prevent the response from being deserialized.
+        let response = Response::try_from_http_response(http_response).unwrap();
+
+        let room = response.rooms.join.get(room_id!("!room:example.org")).unwrap();
+        let events = &room.timeline.events;
+        assert_eq!(events.len(), 2);
+        events[0].deserialize().unwrap();
+        events[1].deserialize().unwrap_err();
+    }
 }
 
 #[cfg(all(test, feature = "server"))]
@@ -12,7 +12,8 @@ use ruma_common::{
     api::{request, response, Metadata},
     metadata,
     serde::{deserialize_cow_str, duration::opt_ms, Raw},
-    DeviceKeyAlgorithm, MilliSecondsSinceUnixEpoch, OwnedMxcUri, OwnedRoomId, RoomId,
+    DeviceKeyAlgorithm, MilliSecondsSinceUnixEpoch, OwnedMxcUri, OwnedRoomId, OwnedTransactionId,
+    RoomId,
 };
 use ruma_events::{
     receipt::SyncReceiptEvent, typing::SyncTypingEvent, AnyGlobalAccountDataEvent,
@@ -78,7 +79,7 @@ pub struct Request {
     /// Allows clients to know what request params reached the server,
     /// functionally similar to txn IDs on /send for events.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub txn_id: Option<String>,
+    pub txn_id: Option<OwnedTransactionId>,
 
     /// The maximum time to poll before responding to this request.
     #[serde(with = "opt_ms", default, skip_serializing_if = "Option::is_none")]
@@ -113,7 +114,7 @@ pub struct Response {
 
     /// Matches the `txn_id` sent by the request. Please see [`Request::txn_id`].
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub txn_id: Option<String>,
+    pub txn_id: Option<OwnedTransactionId>,
 
     /// The token to supply in the `pos` param of the next `/sync` request.
     pub pos: String,
@@ -660,8 +661,7 @@ pub struct E2EE {
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub device_one_time_keys_count: BTreeMap<DeviceKeyAlgorithm, UInt>,
 
-    /// For each key algorithm, the number of unclaimed one-time keys
-    /// currently held on the server for a device.
+    /// The unused fallback key algorithms.
     ///
     /// The presence of this field indicates that the server supports
     /// fallback keys.
@@ -6,25 +6,30 @@ use std::{
 use assign::assign;
 use async_stream::try_stream;
 use futures_core::stream::Stream;
+use js_int::UInt;
 use ruma_client_api::{
     account::register::{self, RegistrationKind},
+    media::{create_content, get_content, get_content_thumbnail},
     session::login::{self, v3::LoginInfo},
+    space::{get_hierarchy, SpaceHierarchyRoomsChunk},
     sync::sync_events,
     uiaa::UserIdentifier,
 };
 use ruma_common::{
     api::{MatrixVersion, OutgoingRequest, SendAccessToken},
     presence::PresenceState,
-    DeviceId, UserId,
+    DeviceId, MxcUri, OwnedMxcUri, UserId,
 };
 
 use crate::{
-    add_user_id_to_query, send_customized_request, Error, HttpClient, ResponseError, ResponseResult,
+    add_user_id_to_query, send_customized_request_with_rate_limit, Error, HttpClient,
+    ResponseError, ResponseResult,
 };
 
 mod builder;
+mod request_config;
 
-pub use self::builder::ClientBuilder;
+pub use self::{builder::ClientBuilder, request_config::RequestConfig};
 
 /// A client for the Matrix client-server API.
 #[derive(Clone, Debug)]
@@ -42,8 +47,14 @@ struct ClientData<C> {
     /// The access token, if logged in.
     access_token: Mutex<Option<String>>,
 
+    /// Whether the access token is the `as_token` of an application service.
+    is_appservice: bool,
+
     /// The (known) Matrix versions the homeserver supports.
     supported_matrix_versions: Vec<MatrixVersion>,
+
+    /// The configuration of the requests.
+    request_config: RequestConfig,
 }
 
 impl Client<()> {
@@ -69,36 +80,57 @@ impl<C: HttpClient> Client<C> {
     }
 
     /// Makes a request to a Matrix API endpoint including additional URL parameters.
+    ///
+    /// If the request is rate-limited, it is retried according to the [`RequestConfig`] of the
+    /// client, in which case `customize` is called again for every attempt.
     pub async fn send_customized_request<R, F>(
         &self,
         request: R,
-        customize: F,
+        mut customize: F,
     ) -> ResponseResult<C, R>
     where
         R: OutgoingRequest,
-        F: FnOnce(&mut http::Request<C::RequestBody>) -> Result<(), ResponseError<C, R>>,
+        F: FnMut(&mut http::Request<C::RequestBody>) -> Result<(), ResponseError<C, R>>,
     {
         let access_token = self.access_token();
         let send_access_token = match access_token.as_deref() {
+            Some(at) if self.0.is_appservice => SendAccessToken::Appservice(at),
             Some(at) => SendAccessToken::IfRequired(at),
             None => SendAccessToken::None,
         };
 
-        send_customized_request(
-            &self.0.http_client,
-            &self.0.homeserver_url,
-            send_access_token,
-            &self.0.supported_matrix_versions,
-            request,
-            customize,
-        )
-        .await
+        let mut attempt = 0;
+        loop {
+            let result = send_customized_request_with_rate_limit(
+                &self.0.http_client,
+                &self.0.homeserver_url,
+                send_access_token,
+                &self.0.supported_matrix_versions,
+                request.clone(),
+                &mut customize,
+            )
+            .await;
+
+            match result {
+                Ok(response) => return Ok(response),
+                Err((error, Some(rate_limited))) => {
+                    match self.0.request_config.retry_delay(attempt, rate_limited.retry_after) {
+                        Some(delay) => delay.await,
+                        None => return Err(error),
+                    }
+                }
+                Err((error, None)) => return Err(error),
+            }
+
+            attempt += 1;
+        }
     }
 
     /// Makes a request to a Matrix API endpoint as a virtual user.
     ///
     /// This method is meant to be used by application services when interacting with the
-    /// client-server API.
+    /// client-server API. The client should have been built with
+    /// [`ClientBuilder::appservice_token`].
     pub async fn send_request_as<R: OutgoingRequest>(
         &self,
         user_id: &UserId,
@@ -174,8 +206,57 @@ impl<C: HttpClient> Client<C> {
         Ok(response)
     }
 
+    /// Upload a file to the content repository of the homeserver.
+    ///
+    /// Returns the `mxc://` URI of the uploaded file.
+    pub async fn upload(
+        &self,
+        content_type: &str,
+        file: Vec<u8>,
+    ) -> Result<OwnedMxcUri, Error<C::Error, ruma_client_api::Error>> {
+        let response = self
+            .send_request(assign!(create_content::v3::Request::new(file), {
+                content_type: Some(content_type.to_owned()),
+            }))
+            .await?;
+
+        Ok(response.content_uri)
+    }
+
+    /// Download a file from the content repository.
+    ///
+    /// If `allow_remote` is `false`, the homeserver will not try to fetch the file from the
+    /// server that hosts it if it is remote.
+    pub async fn download(
+        &self,
+        mxc: &MxcUri,
+        allow_remote: bool,
+    ) -> Result<get_content::v3::Response, Error<C::Error, ruma_client_api::Error>> {
+        let request = get_content::v3::Request::from_url(mxc).map_err(Error::InvalidMxcUri)?;
+        self.send_request(assign!(request, { allow_remote })).await
+    }
+
+    /// Download a thumbnail of a file from the content repository.
+    ///
+    /// The homeserver will return a thumbnail with a size close to the given `width` and
+    /// `height`.
+    pub async fn download_thumbnail(
+        &self,
+        mxc: &MxcUri,
+        width: UInt,
+        height: UInt,
+        allow_remote: bool,
+    ) -> Result<get_content_thumbnail::v3::Response, Error<C::Error, ruma_client_api::Error>> {
+        let request = get_content_thumbnail::v3::Request::from_url(mxc, width, height)
+            .map_err(Error::InvalidMxcUri)?;
+        self.send_request(assign!(request, { allow_remote })).await
+    }
+
     /// Convenience method that represents repeated calls to the sync_events endpoint as a stream.
     ///
+    /// If `since` is `None`, the first request is an initial sync. After that, the `next_batch`
+    /// token of each response is used as the `since` token of the following request.
+    ///
     /// # Example:
     ///
     /// ```no_run
@@ -189,7 +270,7 @@ impl<C: HttpClient> Client<C> {
     /// #     .homeserver_url(homeserver_url)
     /// #     .build::<ruma_client::http_client::Dummy>()
     /// #     .await?;
-    /// # let next_batch_token = String::new();
+    /// # let next_batch_token = None;
     /// let mut sync_stream = Box::pin(client.sync(
     ///     None,
     ///     next_batch_token,
@@ -205,7 +286,7 @@ impl<C: HttpClient> Client<C> {
     pub fn sync(
         &self,
         filter: Option<sync_events::v3::Filter>,
-        mut since: String,
+        mut since: Option<String>,
         set_presence: PresenceState,
         timeout: Option<Duration>,
     ) -> impl Stream<Item = Result<sync_events::v3::Response, Error<C::Error, ruma_client_api::Error>>>
@@ -215,15 +296,68 @@ impl<C: HttpClient> Client<C> {
                 let response = self
                     .send_request(assign!(sync_events::v3::Request::new(), {
                         filter: filter.clone(),
-                        since: Some(since.clone()),
+                        since: since.clone(),
                         set_presence: set_presence.clone(),
                         timeout,
                     }))
                     .await?;
 
-                since = response.next_batch.clone();
+                since = Some(response.next_batch.clone());
                 yield response;
             }
         }
     }
+
+    /// Convenience method that represents repeated calls to the space hierarchy endpoint as a
+    /// stream of rooms.
+    ///
+    /// The `from` token of the given request is used for the first request. After that, the
+    /// `next_batch` token of each response is used as the `from` token of the following request,
+    /// while the other fields of the request, like `max_depth` and `suggested_only`, are kept.
+    /// The stream ends when the server doesn't return a `next_batch` token anymore.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// use js_int::uint;
+    /// use ruma_client_api::space::get_hierarchy;
+    ///
+    /// # use ruma_common::owned_room_id;
+    /// # use tokio_stream::{StreamExt as _};
+    /// # let homeserver_url = "https://example.com".to_owned();
+    /// # async {
+    /// # let client = ruma_client::Client::builder()
+    /// #     .homeserver_url(homeserver_url)
+    /// #     .build::<ruma_client::http_client::Dummy>()
+    /// #     .await?;
+    /// let mut request = get_hierarchy::v1::Request::new(owned_room_id!("!space:example.com"));
+    /// request.max_depth = Some(uint!(2));
+    ///
+    /// let mut rooms = Box::pin(client.space_hierarchy(request));
+    /// while let Some(room) = rooms.try_next().await? {
+    ///     // Do something with the room...
+    /// }
+    /// # Result::<(), ruma_client::Error<_, _>>::Ok(())
+    /// # };
+    /// ```
+    pub fn space_hierarchy(
+        &self,
+        mut request: get_hierarchy::v1::Request,
+    ) -> impl Stream<Item = Result<SpaceHierarchyRoomsChunk, Error<C::Error, ruma_client_api::Error>>> + '_
+    {
+        try_stream! {
+            loop {
+                let response = self.send_request(request.clone()).await?;
+
+                for room in response.rooms {
+                    yield room;
+                }
+
+                match response.next_batch {
+                    Some(next_batch) => request.from = Some(next_batch),
+                    None => break,
+                }
+            }
+        }
+    }
 }
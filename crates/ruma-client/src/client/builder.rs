@@ -1,10 +1,15 @@
 use std::sync::{Arc, Mutex};
 
-use ruma_client_api::discovery::get_supported_versions;
-use ruma_common::api::{MatrixVersion, SendAccessToken};
+use ruma_client_api::discovery::{discover_homeserver, get_supported_versions};
+use ruma_common::{
+    api::{error::FromHttpResponseError, EndpointError, MatrixVersion, SendAccessToken},
+    ServerName,
+};
 
-use super::{Client, ClientData};
-use crate::{DefaultConstructibleHttpClient, Error, HttpClient, HttpClientExt};
+use super::{Client, ClientData, RequestConfig};
+use crate::{
+    http_client::ConnectionConfig, DefaultConstructibleHttpClient, Error, HttpClient, HttpClientExt,
+};
 
 /// A [`Client`] builder.
 ///
@@ -12,12 +17,22 @@ use crate::{DefaultConstructibleHttpClient, Error, HttpClient, HttpClientExt};
 pub struct ClientBuilder {
     homeserver_url: Option<String>,
     access_token: Option<String>,
+    is_appservice: bool,
     supported_matrix_versions: Option<Vec<MatrixVersion>>,
+    request_config: RequestConfig,
+    connection_config: ConnectionConfig,
 }
 
 impl ClientBuilder {
     pub(super) fn new() -> Self {
-        Self { homeserver_url: None, access_token: None, supported_matrix_versions: None }
+        Self {
+            homeserver_url: None,
+            access_token: None,
+            is_appservice: false,
+            supported_matrix_versions: None,
+            request_config: RequestConfig::new(),
+            connection_config: ConnectionConfig::new(),
+        }
     }
 
     /// Set the homeserver URL.
@@ -30,7 +45,16 @@ impl ClientBuilder {
 
     /// Set the access token.
     pub fn access_token(self, access_token: Option<String>) -> Self {
-        Self { access_token, ..self }
+        Self { access_token, is_appservice: false, ..self }
+    }
+
+    /// Set the `as_token` of an application service as the access token.
+    ///
+    /// The token will also be sent to endpoints that only require authentication for application
+    /// services. Combined with [`Client::send_request_as`], this allows to make requests as the
+    /// virtual users of the application service.
+    pub fn appservice_token(self, as_token: String) -> Self {
+        Self { access_token: Some(as_token), is_appservice: true, ..self }
     }
 
     /// Set the supported Matrix versions.
@@ -42,17 +66,136 @@ impl ClientBuilder {
         Self { supported_matrix_versions: Some(versions), ..self }
     }
 
+    /// Set the configuration of the requests sent by the client.
+    ///
+    /// Defaults to [`RequestConfig::new()`], which doesn't retry rate-limited requests.
+    pub fn request_config(self, request_config: RequestConfig) -> Self {
+        Self { request_config, ..self }
+    }
+
+    /// Set the URL of a proxy to send all the requests through.
+    ///
+    /// This is only supported by the `Reqwest` HTTP client, [`build()`][Self::build] returns an
+    /// error with the other HTTP clients. This is only used by [`build()`][Self::build], an HTTP
+    /// client passed to [`http_client()`][Self::http_client] has to be configured directly.
+    pub fn proxy(mut self, url: String) -> Self {
+        self.connection_config.proxy = Some(url);
+        self
+    }
+
+    /// Add a root certificate to trust, in PEM format.
+    ///
+    /// This allows to connect to a homeserver with a self-signed certificate. This is only
+    /// supported by the `Reqwest` and `HyperNativeTls` HTTP clients, [`build()`][Self::build]
+    /// returns an error with the other HTTP clients. This is only used by
+    /// [`build()`][Self::build], an HTTP client passed to [`http_client()`][Self::http_client] has
+    /// to be configured directly.
+    pub fn add_root_certificate(mut self, pem: Vec<u8>) -> Self {
+        self.connection_config.root_certificates.push(pem);
+        self
+    }
+
+    /// Discover the homeserver URL of the given server name with [server discovery].
+    ///
+    /// This looks up the `/.well-known/matrix/client` file of the server. If the server doesn't
+    /// have one, the server name is used as the hostname of the homeserver. The homeserver is then
+    /// validated by requesting its supported Matrix versions, which are reused by
+    /// [`build()`][Self::build] and [`http_client()`][Self::http_client]. If the file contains an
+    /// identity server, it is also validated by requesting its `/_matrix/identity/v2` endpoint.
+    ///
+    /// On success, the homeserver URL and the supported Matrix versions of the builder are set. If
+    /// the homeserver or the identity server can't be validated, an error is returned, as the
+    /// specification says that clients should not continue in that case.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ruma_common::server_name;
+    /// # async {
+    /// let http_client = ruma_client::http_client::Dummy;
+    /// let client = ruma_client::Client::builder()
+    ///     .discover_homeserver(server_name!("example.com"), &http_client)
+    ///     .await?
+    ///     .http_client(http_client)
+    ///     .await?;
+    /// # Result::<(), ruma_client::Error<_, _>>::Ok(())
+    /// # };
+    /// ```
+    ///
+    /// [server discovery]: https://spec.matrix.org/latest/client-server-api/#server-discovery
+    pub async fn discover_homeserver<C>(
+        self,
+        server_name: &ServerName,
+        http_client: &C,
+    ) -> Result<Self, Error<C::Error, ruma_client_api::Error>>
+    where
+        C: HttpClient,
+    {
+        let server_url = format!("https://{server_name}");
+
+        let (homeserver_url, identity_server_url) = match http_client
+            .send_matrix_request(
+                &server_url,
+                SendAccessToken::None,
+                &[MatrixVersion::V1_0],
+                discover_homeserver::Request::new(),
+            )
+            .await
+        {
+            Ok(response) => (
+                response.homeserver.base_url.trim_end_matches('/').to_owned(),
+                response.identity_server.map(|identity_server| {
+                    identity_server.base_url.trim_end_matches('/').to_owned()
+                }),
+            ),
+            Err(Error::FromHttpResponse(FromHttpResponseError::Server(error)))
+                if error.status_code == http::StatusCode::NOT_FOUND =>
+            {
+                (server_url, None)
+            }
+            Err(error) => return Err(error),
+        };
+
+        // Make sure that the URL is valid before querying it.
+        homeserver_url.parse::<http::Uri>()?;
+
+        let supported_matrix_versions = http_client
+            .send_matrix_request(
+                &homeserver_url,
+                SendAccessToken::None,
+                &[MatrixVersion::V1_0],
+                get_supported_versions::Request::new(),
+            )
+            .await?
+            .known_versions()
+            .collect();
+
+        if let Some(identity_server_url) = identity_server_url {
+            validate_identity_server(&identity_server_url, http_client).await?;
+        }
+
+        Ok(Self {
+            homeserver_url: Some(homeserver_url),
+            supported_matrix_versions: Some(supported_matrix_versions),
+            ..self
+        })
+    }
+
     /// Finish building the [`Client`].
     ///
-    /// Uses [`DefaultConstructibleHttpClient::default()`] to create an HTTP client instance.
-    /// Unless the supported Matrix versions were manually set via
+    /// Uses [`DefaultConstructibleHttpClient::with_connection_config()`] to create an HTTP client
+    /// instance, with the [`proxy`][Self::proxy] and
+    /// [root certificates][Self::add_root_certificate] that were set. Unless the supported Matrix
+    /// versions were manually set via
     /// [`supported_matrix_versions`][Self::supported_matrix_versions], this will do a
     /// [`get_supported_versions`] request to find out about the supported versions.
     pub async fn build<C>(self) -> Result<Client<C>, Error<C::Error, ruma_client_api::Error>>
     where
         C: DefaultConstructibleHttpClient,
     {
-        self.http_client(C::default()).await
+        let http_client =
+            C::with_connection_config(&self.connection_config).map_err(Error::ConnectionConfig)?;
+        self.http_client(http_client).await
     }
 
     /// Set the HTTP client to finish building the [`Client`].
@@ -89,7 +232,31 @@ impl ClientBuilder {
             homeserver_url,
             http_client,
             access_token: Mutex::new(self.access_token),
+            is_appservice: self.is_appservice,
             supported_matrix_versions,
+            request_config: self.request_config,
         })))
     }
 }
+
+/// Validate the identity server at the given URL by requesting its `/_matrix/identity/v2` endpoint.
+async fn validate_identity_server<C>(
+    identity_server_url: &str,
+    http_client: &C,
+) -> Result<(), Error<C::Error, ruma_client_api::Error>>
+where
+    C: HttpClient,
+{
+    let request = http::Request::get(format!("{identity_server_url}/_matrix/identity/v2"))
+        .body(C::RequestBody::default())
+        .map_err(Error::Url)?;
+    let response = http_client.send_http_request(request).await.map_err(Error::Response)?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(Error::FromHttpResponse(FromHttpResponseError::Server(
+            ruma_client_api::Error::from_http_response(response),
+        )))
+    }
+}
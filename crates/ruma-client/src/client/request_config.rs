@@ -0,0 +1,136 @@
+use std::{
+    collections::hash_map::RandomState,
+    fmt,
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::http_client::MaybeSend;
+
+#[cfg(not(target_arch = "wasm32"))]
+type SleepFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+#[cfg(target_arch = "wasm32")]
+type SleepFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+type SleepFn = dyn Fn(Duration) -> SleepFuture + Send + Sync;
+
+/// Configuration for the requests sent by a [`Client`][super::Client].
+///
+/// By default, requests that are rate-limited by the homeserver are not retried. To retry them,
+/// set a [`sleep`][Self::sleep] function to wait between attempts. Since this crate doesn't
+/// depend on an async runtime, this function has to be provided by the application, for example
+/// with `tokio`:
+///
+/// ```ignore
+/// # // HACK: "ignore" the doctest here because it needs tokio's time feature.
+/// let config = ruma_client::RequestConfig::new().max_retries(5).sleep(tokio::time::sleep);
+/// ```
+///
+/// A request is retried when the homeserver responds with a `429 Too Many Requests` status, which
+/// is the case for `M_LIMIT_EXCEEDED` errors. If the response contains a `retry_after_ms` field or
+/// a `Retry-After` header, the client waits for that duration. Otherwise, the delay grows
+/// exponentially with the number of attempts, with some random jitter.
+#[derive(Clone)]
+pub struct RequestConfig {
+    max_retries: u32,
+    max_delay: Duration,
+    sleep: Option<Arc<SleepFn>>,
+}
+
+impl RequestConfig {
+    /// The delay before the first retry, if the homeserver didn't specify one.
+    const INITIAL_DELAY: Duration = Duration::from_millis(500);
+
+    /// Creates a new `RequestConfig` with the default values.
+    ///
+    /// Requests are retried 3 times at most, with a delay of at most 60 seconds between attempts,
+    /// once a [`sleep`][Self::sleep] function is set.
+    pub fn new() -> Self {
+        Self { max_retries: 3, max_delay: Duration::from_secs(60), sleep: None }
+    }
+
+    /// Set the maximum number of times a rate-limited request is retried.
+    pub fn max_retries(self, max_retries: u32) -> Self {
+        Self { max_retries, ..self }
+    }
+
+    /// Set the maximum delay to wait before retrying a request.
+    ///
+    /// If the homeserver asks to wait for a longer duration, the request is not retried and the
+    /// rate-limiting error is returned.
+    pub fn max_delay(self, max_delay: Duration) -> Self {
+        Self { max_delay, ..self }
+    }
+
+    /// Set the function used to wait between two attempts of a request.
+    ///
+    /// The function receives the duration to wait for, and must return a future that resolves
+    /// once that duration has elapsed.
+    pub fn sleep<F, Fut>(self, sleep: F) -> Self
+    where
+        F: Fn(Duration) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + MaybeSend + 'static,
+    {
+        Self { sleep: Some(Arc::new(move |duration| Box::pin(sleep(duration)))), ..self }
+    }
+
+    /// Get the future to wait for before sending the request again, if it should be retried.
+    ///
+    /// `attempt` is the number of times the request has already been retried and `retry_after` is
+    /// the delay asked by the homeserver, if any.
+    pub(crate) fn retry_delay(
+        &self,
+        attempt: u32,
+        retry_after: Option<Duration>,
+    ) -> Option<SleepFuture> {
+        let sleep = self.sleep.as_ref()?;
+
+        if attempt >= self.max_retries {
+            return None;
+        }
+
+        let delay = match retry_after {
+            Some(delay) if delay > self.max_delay => return None,
+            Some(delay) => delay,
+            None => {
+                let delay = Self::INITIAL_DELAY
+                    .checked_mul(2_u32.saturating_pow(attempt))
+                    .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+
+                // Wait between half and the full delay, so clients that were rate-limited at the
+                // same time don't all retry at the same time.
+                delay / 2 + delay.mul_f64(jitter() / 2.0)
+            }
+        };
+
+        Some(sleep(delay))
+    }
+}
+
+impl Default for RequestConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for RequestConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestConfig")
+            .field("max_retries", &self.max_retries)
+            .field("max_delay", &self.max_delay)
+            .field("sleep", &self.sleep.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// A random number between `0.0` and `1.0`.
+///
+/// Uses the random keys of the standard library's hasher, to avoid depending on a random number
+/// generator.
+fn jitter() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1_u64 << 53) as f64
+}
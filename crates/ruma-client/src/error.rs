@@ -2,7 +2,10 @@
 
 use std::fmt::{self, Debug, Display, Formatter};
 
-use ruma_common::api::error::{FromHttpResponseError, IntoHttpError};
+use ruma_common::{
+    api::error::{DeserializationError, FromHttpResponseError, IntoHttpError},
+    IdParseError,
+};
 
 /// An error that can occur during client operations.
 #[derive(Debug)]
@@ -17,11 +20,24 @@ pub enum Error<E, F> {
     /// The request's URL is invalid (this should never happen).
     Url(http::Error),
 
+    /// The given `mxc://` URI is invalid.
+    InvalidMxcUri(IdParseError),
+
     /// Couldn't obtain an HTTP response (e.g. due to network or DNS issues).
     Response(E),
 
     /// Converting the HTTP response to one of ruma's types failed.
+    ///
+    /// Deserialization errors of responses with a success status are returned as
+    /// [`Error::InvalidResponse`] instead.
     FromHttpResponse(FromHttpResponseError<F>),
+
+    /// The server returned a success status, but the body of the response couldn't be
+    /// deserialized.
+    InvalidResponse(InvalidResponseError),
+
+    /// The HTTP client couldn't be created with the given connection configuration.
+    ConnectionConfig(ConnectionConfigError),
 }
 
 #[cfg(feature = "client-api")]
@@ -34,6 +50,19 @@ impl<E> Error<E, ruma_client_api::Error> {
 
         as_variant!(self, Self::FromHttpResponse)?.error_kind()
     }
+
+    /// If `self` is a server error with the `M_LIMIT_EXCEEDED` error kind, returns the time the
+    /// client should wait before retrying the request, if the server provided one.
+    ///
+    /// Rate-limited requests are retried automatically if the client was configured with a
+    /// [`RequestConfig::sleep`] function, up to [`RequestConfig::max_retries`] times. This method
+    /// can be used to handle the error once the retries are exhausted.
+    ///
+    /// [`RequestConfig::sleep`]: crate::RequestConfig::sleep
+    /// [`RequestConfig::max_retries`]: crate::RequestConfig::max_retries
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        self.error_kind()?.retry_after()
+    }
 }
 
 impl<E: Display, F: Display> Display for Error<E, F> {
@@ -44,8 +73,11 @@ impl<E: Display, F: Display> Display for Error<E, F> {
             }
             Self::IntoHttp(err) => write!(f, "HTTP request construction failed: {err}"),
             Self::Url(err) => write!(f, "Invalid URL: {err}"),
+            Self::InvalidMxcUri(err) => write!(f, "Invalid MXC URI: {err}"),
             Self::Response(err) => write!(f, "Couldn't obtain a response: {err}"),
             Self::FromHttpResponse(err) => write!(f, "HTTP response conversion failed: {err}"),
+            Self::InvalidResponse(err) => write!(f, "HTTP response conversion failed: {err}"),
+            Self::ConnectionConfig(err) => write!(f, "HTTP client creation failed: {err}"),
         }
     }
 }
@@ -77,3 +109,77 @@ impl<E, F> From<FromHttpResponseError<F>> for Error<E, F> {
 }
 
 impl<E: Debug + Display, F: Debug + Display> std::error::Error for Error<E, F> {}
+
+/// A response with a success status that couldn't be deserialized.
+///
+/// This keeps the status code and the raw body of the response, to help with debugging
+/// homeservers that don't send what the specification says they should.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct InvalidResponseError {
+    /// The HTTP status code of the response.
+    pub status: http::StatusCode,
+
+    /// The raw body of the response.
+    pub body: Vec<u8>,
+
+    /// The error that occurred during deserialization.
+    pub error: DeserializationError,
+}
+
+impl Display for InvalidResponseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "deserialization of response with status {} failed: {}", self.status, self.error)
+    }
+}
+
+impl std::error::Error for InvalidResponseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An error when creating an HTTP client with a [`ConnectionConfig`].
+///
+/// [`ConnectionConfig`]: crate::http_client::ConnectionConfig
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConnectionConfigError {
+    /// The HTTP client doesn't support one of the options of the configuration.
+    Unsupported {
+        /// The type name of the HTTP client.
+        http_client: &'static str,
+
+        /// The name of the unsupported option.
+        option: &'static str,
+    },
+
+    /// One of the options of the configuration is invalid.
+    Invalid(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl ConnectionConfigError {
+    pub(crate) fn unsupported<C>(option: &'static str) -> Self {
+        Self::Unsupported { http_client: std::any::type_name::<C>(), option }
+    }
+}
+
+impl Display for ConnectionConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported { http_client, option } => {
+                write!(f, "{http_client} doesn't support the `{option}` option")
+            }
+            Self::Invalid(err) => write!(f, "invalid connection configuration: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ConnectionConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Unsupported { .. } => None,
+            Self::Invalid(err) => Some(&**err),
+        }
+    }
+}
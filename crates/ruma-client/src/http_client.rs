@@ -1,7 +1,7 @@
 //! This module contains an abstraction for HTTP clients as well as friendly-named re-exports of
 //! client types that implement this trait.
 
-use std::{future::Future, pin::Pin};
+use std::{future::Future, pin::Pin, sync::Arc};
 
 use bytes::BufMut;
 use ruma_common::{
@@ -9,7 +9,7 @@ use ruma_common::{
     UserId,
 };
 
-use crate::{add_user_id_to_query, ResponseError, ResponseResult};
+use crate::{add_user_id_to_query, ConnectionConfigError, ResponseError, ResponseResult};
 
 #[cfg(feature = "hyper")]
 mod hyper;
@@ -29,28 +29,138 @@ pub use self::isahc::Isahc;
 #[cfg(feature = "reqwest")]
 pub use self::reqwest::Reqwest;
 
+/// A marker trait for types that are `Send`, except on the `wasm32` target.
+///
+/// Futures and types of the browser APIs are generally not `Send`, so this bound is removed on
+/// `wasm32` to allow using `fetch`-based HTTP clients.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + ?Sized> MaybeSend for T {}
+
+/// A marker trait for types that are `Send`, except on the `wasm32` target.
+///
+/// Futures and types of the browser APIs are generally not `Send`, so this bound is removed on
+/// `wasm32` to allow using `fetch`-based HTTP clients.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// A marker trait for types that are `Sync`, except on the `wasm32` target.
+///
+/// See [`MaybeSend`] for the reasoning.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSync: Sync {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Sync + ?Sized> MaybeSync for T {}
+
+/// A marker trait for types that are `Sync`, except on the `wasm32` target.
+///
+/// See [`MaybeSend`] for the reasoning.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSync {}
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSync for T {}
+
+/// The future returned by the methods of [`HttpClientExt`].
+#[cfg(not(target_arch = "wasm32"))]
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+#[cfg(target_arch = "wasm32")]
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
 /// An HTTP client that can be used to send requests to a Matrix homeserver.
-pub trait HttpClient: Sync {
+///
+/// On the `wasm32` target, the `Send` and `Sync` bounds of this trait are removed, see
+/// [`MaybeSend`].
+pub trait HttpClient: MaybeSync {
     /// The type to use for `try_into_http_request`.
-    type RequestBody: Default + BufMut + Send;
+    type RequestBody: Default + BufMut + MaybeSend;
 
     /// The type to use for `try_from_http_response`.
     type ResponseBody: AsRef<[u8]>;
 
     /// The error type for the `send_request` function.
-    type Error: Send + Unpin;
+    type Error: MaybeSend + Unpin;
 
     /// Send an `http::Request` to get back an `http::Response`.
     fn send_http_request(
         &self,
         req: http::Request<Self::RequestBody>,
-    ) -> impl Future<Output = Result<http::Response<Self::ResponseBody>, Self::Error>> + Send;
+    ) -> impl Future<Output = Result<http::Response<Self::ResponseBody>, Self::Error>> + MaybeSend;
+}
+
+/// Forwards requests to the inner HTTP client.
+///
+/// This allows to share a single HTTP client, and its connection pool, between several Matrix
+/// clients.
+impl<C: HttpClient + MaybeSend> HttpClient for Arc<C> {
+    type RequestBody = C::RequestBody;
+    type ResponseBody = C::ResponseBody;
+    type Error = C::Error;
+
+    fn send_http_request(
+        &self,
+        req: http::Request<Self::RequestBody>,
+    ) -> impl Future<Output = Result<http::Response<Self::ResponseBody>, Self::Error>> + MaybeSend
+    {
+        (**self).send_http_request(req)
+    }
 }
 
 /// An HTTP client that has a default configuration.
 pub trait DefaultConstructibleHttpClient: HttpClient {
     /// Creates a new HTTP client with default configuration.
     fn default() -> Self;
+
+    /// Creates a new HTTP client with the given connection configuration.
+    ///
+    /// The default implementation returns an error if any option of the configuration is set.
+    fn with_connection_config(config: &ConnectionConfig) -> Result<Self, ConnectionConfigError>
+    where
+        Self: Sized,
+    {
+        if config.proxy.is_some() {
+            return Err(ConnectionConfigError::unsupported::<Self>("proxy"));
+        }
+        if !config.root_certificates.is_empty() {
+            return Err(ConnectionConfigError::unsupported::<Self>("root_certificates"));
+        }
+
+        Ok(Self::default())
+    }
+}
+
+/// The configuration of the connections of an HTTP client.
+///
+/// Used by [`ClientBuilder::build`] to create the HTTP client. Not all HTTP clients support all
+/// the options, see [`DefaultConstructibleHttpClient::with_connection_config`]: the `Reqwest`
+/// client supports all of them, the `HyperNativeTls` client only supports root certificates and
+/// the `Hyper` client supports none of them.
+///
+/// [`ClientBuilder::build`]: crate::ClientBuilder::build
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ConnectionConfig {
+    /// The URL of a proxy to send all the requests through.
+    ///
+    /// Only supported by the `Reqwest` client, with the `http`, `https` and, if the `socks`
+    /// feature of `reqwest` is enabled, `socks5` schemes.
+    pub proxy: Option<String>,
+
+    /// Additional root certificates to trust, in PEM format.
+    ///
+    /// This allows to connect to homeservers with self-signed certificates. Only supported by the
+    /// `Reqwest` client, with one of the `reqwest-native-tls*` or `reqwest-rustls-*` features, and
+    /// by the `HyperNativeTls` client.
+    pub root_certificates: Vec<Vec<u8>>,
+}
+
+impl ConnectionConfig {
+    /// Creates an empty `ConnectionConfig`.
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
 /// Convenience functionality on top of `HttpClient`.
@@ -66,7 +176,7 @@ pub trait HttpClientExt: HttpClient {
         access_token: SendAccessToken<'_>,
         for_versions: &[MatrixVersion],
         request: R,
-    ) -> Pin<Box<dyn Future<Output = ResponseResult<Self, R>> + 'a + Send>> {
+    ) -> BoxFuture<'a, ResponseResult<Self, R>> {
         self.send_customized_matrix_request(
             homeserver_url,
             access_token,
@@ -86,7 +196,7 @@ pub trait HttpClientExt: HttpClient {
         for_versions: &[MatrixVersion],
         request: R,
         customize: F,
-    ) -> Pin<Box<dyn Future<Output = ResponseResult<Self, R>> + 'a + Send>>
+    ) -> BoxFuture<'a, ResponseResult<Self, R>>
     where
         R: OutgoingRequest + 'a,
         F: FnOnce(&mut http::Request<Self::RequestBody>) -> Result<(), ResponseError<Self, R>> + 'a,
@@ -113,7 +223,7 @@ pub trait HttpClientExt: HttpClient {
         for_versions: &[MatrixVersion],
         user_id: &'a UserId,
         request: R,
-    ) -> Pin<Box<dyn Future<Output = ResponseResult<Self, R>> + 'a>> {
+    ) -> BoxFuture<'a, ResponseResult<Self, R>> {
         self.send_customized_matrix_request(
             homeserver_url,
             access_token,
@@ -15,7 +15,8 @@ pub type HyperNativeTls = hyper::Client<hyper_tls::HttpsConnector<HttpConnector>
 /// A hyper HTTP client using rustls for TLS support.
 ///
 /// This client does not implement `DefaultConstructibleHttpClient`. To use it, you need to manually
-/// construct
+/// construct it with a `hyper_rustls::HttpsConnector` configured with the root certificates of
+/// your choice, and pass it to `ClientBuilder::http_client()`.
 #[cfg(feature = "hyper-rustls")]
 pub type HyperRustls = hyper::Client<hyper_rustls::HttpsConnector<HttpConnector>>;
 
@@ -55,4 +56,32 @@ impl DefaultConstructibleHttpClient for HyperNativeTls {
     fn default() -> Self {
         hyper::Client::builder().build(hyper_tls::HttpsConnector::new())
     }
+
+    /// Creates a new hyper HTTP client with the given connection configuration.
+    ///
+    /// Proxies are not supported.
+    fn with_connection_config(
+        config: &super::ConnectionConfig,
+    ) -> Result<Self, crate::ConnectionConfigError> {
+        use tokio_native_tls::native_tls;
+
+        use crate::ConnectionConfigError;
+
+        if config.proxy.is_some() {
+            return Err(ConnectionConfigError::unsupported::<Self>("proxy"));
+        }
+
+        let mut tls = native_tls::TlsConnector::builder();
+        for certificate in &config.root_certificates {
+            let certificate = native_tls::Certificate::from_pem(certificate)
+                .map_err(|err| ConnectionConfigError::Invalid(err.into()))?;
+            tls.add_root_certificate(certificate);
+        }
+        let tls = tls.build().map_err(|err| ConnectionConfigError::Invalid(err.into()))?;
+
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+
+        Ok(hyper::Client::builder().build((http, tls.into()).into()))
+    }
 }
@@ -2,7 +2,8 @@ use std::mem;
 
 use bytes::{Bytes, BytesMut};
 
-use super::{DefaultConstructibleHttpClient, HttpClient};
+use super::{ConnectionConfig, DefaultConstructibleHttpClient, HttpClient};
+use crate::ConnectionConfigError;
 
 /// The `reqwest` crate's `Client`.
 pub type Reqwest = reqwest::Client;
@@ -34,4 +35,61 @@ impl DefaultConstructibleHttpClient for Reqwest {
     fn default() -> Self {
         reqwest::Client::new()
     }
+
+    /// Creates a new `reqwest::Client` with the given connection configuration.
+    ///
+    /// The `socks5` scheme for the proxy requires the `socks` feature of `reqwest`. Root
+    /// certificates require one of the `reqwest-native-tls*` or `reqwest-rustls-*` features.
+    ///
+    /// On the `wasm32` target, neither option is supported, since the browser takes care of them.
+    fn with_connection_config(config: &ConnectionConfig) -> Result<Self, ConnectionConfigError> {
+        #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+        let mut builder = reqwest::Client::builder();
+
+        #[cfg(target_arch = "wasm32")]
+        if config.proxy.is_some() {
+            return Err(ConnectionConfigError::unsupported::<Self>("proxy"));
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(proxy) = &config.proxy {
+            let proxy = reqwest::Proxy::all(proxy.as_str())
+                .map_err(|err| ConnectionConfigError::Invalid(err.into()))?;
+            builder = builder.proxy(proxy);
+        }
+
+        #[cfg(all(
+            not(target_arch = "wasm32"),
+            any(
+                feature = "reqwest-native-tls",
+                feature = "reqwest-native-tls-alpn",
+                feature = "reqwest-native-tls-vendored",
+                feature = "reqwest-rustls-manual-roots",
+                feature = "reqwest-rustls-webpki-roots",
+                feature = "reqwest-rustls-native-roots",
+            )
+        ))]
+        for certificate in &config.root_certificates {
+            let certificate = reqwest::Certificate::from_pem(certificate)
+                .map_err(|err| ConnectionConfigError::Invalid(err.into()))?;
+            builder = builder.add_root_certificate(certificate);
+        }
+
+        #[cfg(not(all(
+            not(target_arch = "wasm32"),
+            any(
+                feature = "reqwest-native-tls",
+                feature = "reqwest-native-tls-alpn",
+                feature = "reqwest-native-tls-vendored",
+                feature = "reqwest-rustls-manual-roots",
+                feature = "reqwest-rustls-webpki-roots",
+                feature = "reqwest-rustls-native-roots",
+            )
+        )))]
+        if !config.root_certificates.is_empty() {
+            return Err(ConnectionConfigError::unsupported::<Self>("root_certificates"));
+        }
+
+        builder.build().map_err(|err| ConnectionConfigError::Invalid(err.into()))
+    }
 }
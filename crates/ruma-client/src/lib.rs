@@ -30,8 +30,7 @@
 //! ```
 //!
 //! You can also pass an existing access token to the `Client` constructor to restore a previous
-//! session rather than calling `log_in`. This can also be used to create a session for an
-//! application service that does not need to log in, but uses the access_token directly:
+//! session rather than calling `log_in`:
 //!
 //! ```no_run
 //! # type HttpClient = ruma_client::http_client::Dummy;
@@ -40,7 +39,7 @@
 //! let homeserver_url = "https://example.com".to_owned();
 //! let client = ruma_client::Client::builder()
 //!     .homeserver_url(homeserver_url)
-//!     .access_token(Some("as_access_token".into()))
+//!     .access_token(Some("access_token".into()))
 //!     .build::<HttpClient>()
 //!     .await?;
 //!
@@ -49,6 +48,30 @@
 //! # };
 //! ```
 //!
+//! An application service that does not need to log in can use its `as_token` directly, and make
+//! requests as one of its virtual users with `send_request_as`:
+//!
+//! ```no_run
+//! # type HttpClient = ruma_client::http_client::Dummy;
+//! #
+//! # async {
+//! use ruma_client_api::profile::get_display_name;
+//! use ruma_common::user_id;
+//!
+//! let homeserver_url = "https://example.com".to_owned();
+//! let client = ruma_client::Client::builder()
+//!     .homeserver_url(homeserver_url)
+//!     .appservice_token("as_token".into())
+//!     .build::<HttpClient>()
+//!     .await?;
+//!
+//! let user_id = user_id!("@_bridge_alice:example.com");
+//! let request = get_display_name::v3::Request::new(user_id.to_owned());
+//! let response = client.send_request_as(user_id, request).await?;
+//! # Result::<(), ruma_client::Error<_, _>>::Ok(())
+//! # };
+//! ```
+//!
 //! The `Client` type also provides methods for registering a new account if you don't already have
 //! one with the given homeserver.
 //!
@@ -95,27 +118,85 @@
 //!   * `reqwest-rustls-manual-roots`
 //!   * `reqwest-rustls-webpki-roots`
 //!   * `reqwest-rustls-native-roots`
+//!
+//! # Custom HTTP client configuration
+//!
+//! `ClientBuilder::build` can configure a proxy and additional root certificates for the HTTP
+//! client, for the HTTP clients that support it:
+//!
+//! * `Reqwest` supports both, root certificates requiring one of the `reqwest-native-tls*` or
+//!   `reqwest-rustls-*` features.
+//! * `HyperNativeTls` only supports root certificates.
+//! * `Hyper` supports neither. `HyperRustls` and `Isahc` can't be created by `ClientBuilder::build`
+//!   at all.
+//!
+//! Using an option that is not supported returns a `ConnectionConfigError::Unsupported` error.
+//!
+//! ```ignore
+//! # // HACK: "ignore" the doctest here because it needs the reqwest-rustls-* features.
+//! # async {
+//! let client = ruma_client::Client::builder()
+//!     .homeserver_url("https://example.com".to_owned())
+//!     .proxy("http://127.0.0.1:3128".to_owned())
+//!     .add_root_certificate(std::fs::read("my-ca.pem")?)
+//!     .build::<ruma_client::http_client::Reqwest>()
+//!     .await?;
+//! # Result::<(), Box<dyn std::error::Error>>::Ok(())
+//! # };
+//! ```
+//!
+//! For other settings, configure the HTTP client yourself and pass it to
+//! `ClientBuilder::http_client`. For example with `reqwest`:
+//!
+//! ```ignore
+//! # // HACK: "ignore" the doctest here because it needs the reqwest-rustls-* features.
+//! # async {
+//! let http_client = reqwest::Client::builder()
+//!     .proxy(reqwest::Proxy::all("socks5://127.0.0.1:1080")?)
+//!     .connect_timeout(std::time::Duration::from_secs(10))
+//!     .build()?;
+//!
+//! let client = ruma_client::Client::builder()
+//!     .homeserver_url("https://example.com".to_owned())
+//!     .http_client(http_client)
+//!     .await?;
+//! # Result::<(), Box<dyn std::error::Error>>::Ok(())
+//! # };
+//! ```
+//!
+//! # WebAssembly
+//!
+//! This crate doesn't depend on an async runtime, and can be used on the `wasm32-unknown-unknown`
+//! target with the `reqwest` feature, which uses the `fetch` API of the browser. On this target,
+//! the futures returned by [`HttpClient::send_http_request`] and the types of the HTTP client
+//! don't need to be `Send` or `Sync`, see [`http_client::MaybeSend`]. To retry rate-limited
+//! requests, a `RequestConfig` can use a timer like the one of the `gloo-timers` crate as its
+//! sleep function.
 
 #![warn(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
-use std::{any::type_name, future::Future};
+use std::{any::type_name, future::Future, time::Duration};
 
+use http::{header::RETRY_AFTER, HeaderMap, StatusCode};
 use ruma_common::{
-    api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+    api::{error::FromHttpResponseError, MatrixVersion, OutgoingRequest, SendAccessToken},
     UserId,
 };
+use serde::Deserialize;
 use tracing::{info_span, Instrument};
 
+use self::http_client::MaybeSend;
+
 #[cfg(feature = "client-api")]
 mod client;
 mod error;
 pub mod http_client;
 
 #[cfg(feature = "client-api")]
-pub use self::client::{Client, ClientBuilder};
+pub use self::client::{Client, ClientBuilder, RequestConfig};
 pub use self::{
-    error::Error,
+    error::{ConnectionConfigError, Error, InvalidResponseError},
     http_client::{DefaultConstructibleHttpClient, HttpClient, HttpClientExt},
 };
 
@@ -134,7 +215,36 @@ fn send_customized_request<'a, C, R, F>(
     for_versions: &[MatrixVersion],
     request: R,
     customize: F,
-) -> impl Future<Output = ResponseResult<C, R>> + Send + 'a
+) -> impl Future<Output = ResponseResult<C, R>> + MaybeSend + 'a
+where
+    C: HttpClient + ?Sized,
+    R: OutgoingRequest,
+    F: FnOnce(&mut http::Request<C::RequestBody>) -> Result<(), ResponseError<C, R>>,
+{
+    let fut = send_customized_request_with_rate_limit(
+        http_client,
+        homeserver_url,
+        send_access_token,
+        for_versions,
+        request,
+        customize,
+    );
+
+    async move { fut.await.map_err(|(error, _)| error) }
+}
+
+/// Like [`send_customized_request`], but if the request was rate-limited, the error is returned
+/// alongside a [`RateLimited`].
+fn send_customized_request_with_rate_limit<'a, C, R, F>(
+    http_client: &'a C,
+    homeserver_url: &str,
+    send_access_token: SendAccessToken<'_>,
+    for_versions: &[MatrixVersion],
+    request: R,
+    customize: F,
+) -> impl Future<Output = Result<R::IncomingResponse, (ResponseError<C, R>, Option<RateLimited>)>>
+       + MaybeSend
+       + 'a
 where
     C: HttpClient + ?Sized,
     R: OutgoingRequest,
@@ -160,24 +270,83 @@ where
 
     async move {
         let http_res = http_client
-            .send_http_request(http_req?)
+            .send_http_request(http_req.map_err(|error| (error, None))?)
             .instrument(send_span)
             .await
-            .map_err(Error::Response)?;
+            .map_err(|error| (Error::Response(error), None))?;
+
+        // Keep the body around, so it is still available if deserialization fails.
+        let (parts, body) = http_res.into_parts();
+        let status = parts.status;
+        let rate_limited = (status == StatusCode::TOO_MANY_REQUESTS)
+            .then(|| RateLimited::new(&parts.headers, body.as_ref()));
 
         let res =
             info_span!("deserialize_response", response_type = type_name::<R::IncomingResponse>())
-                .in_scope(move || {
+                .in_scope(|| {
+                    let mut http_res = http::Response::new(body.as_ref());
+                    *http_res.status_mut() = parts.status;
+                    *http_res.version_mut() = parts.version;
+                    *http_res.headers_mut() = parts.headers;
+                    *http_res.extensions_mut() = parts.extensions;
+
                     ruma_common::api::IncomingResponse::try_from_http_response(http_res)
-                })?;
+                });
+
+        let error = match res {
+            Ok(res) => return Ok(res),
+            Err(FromHttpResponseError::Deserialization(error)) => {
+                Error::InvalidResponse(InvalidResponseError {
+                    status,
+                    body: body.as_ref().to_owned(),
+                    error,
+                })
+            }
+            Err(err) => err.into(),
+        };
+
+        Err((error, rate_limited))
+    }
+}
+
+/// Information about a request that was rate-limited by the homeserver.
+struct RateLimited {
+    /// The delay to wait for before retrying the request, if the homeserver specified one.
+    #[cfg_attr(not(feature = "client-api"), allow(dead_code))]
+    retry_after: Option<Duration>,
+}
+
+impl RateLimited {
+    /// Get the delay from the `retry_after_ms` field of the response body, with a fallback to the
+    /// `Retry-After` header.
+    fn new(headers: &HeaderMap, body: &[u8]) -> Self {
+        #[derive(Deserialize)]
+        struct RateLimitedBody {
+            #[serde(default, with = "ruma_common::serde::duration::opt_ms")]
+            retry_after_ms: Option<Duration>,
+        }
+
+        let retry_after = serde_json::from_slice::<RateLimitedBody>(body)
+            .ok()
+            .and_then(|body| body.retry_after_ms)
+            .or_else(|| {
+                headers
+                    .get(RETRY_AFTER)?
+                    .to_str()
+                    .ok()?
+                    .trim()
+                    .parse()
+                    .ok()
+                    .map(Duration::from_secs)
+            });
 
-        Ok(res)
+        Self { retry_after }
     }
 }
 
 fn add_user_id_to_query<C: HttpClient + ?Sized, R: OutgoingRequest>(
     user_id: &UserId,
-) -> impl FnOnce(&mut http::Request<C::RequestBody>) -> Result<(), ResponseError<C, R>> + '_ {
+) -> impl FnMut(&mut http::Request<C::RequestBody>) -> Result<(), ResponseError<C, R>> + '_ {
     use assign::assign;
     use http::uri::Uri;
 
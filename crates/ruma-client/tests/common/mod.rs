@@ -0,0 +1,22 @@
+use ruma_client::HttpClient;
+
+/// An HTTP client that always returns the same response.
+pub(crate) struct StaticResponse {
+    pub(crate) status: http::StatusCode,
+    pub(crate) body: &'static [u8],
+}
+
+impl HttpClient for StaticResponse {
+    type RequestBody = Vec<u8>;
+    type ResponseBody = &'static [u8];
+    type Error = ();
+
+    async fn send_http_request(
+        &self,
+        _req: http::Request<Self::RequestBody>,
+    ) -> Result<http::Response<Self::ResponseBody>, Self::Error> {
+        let mut res = http::Response::new(self.body);
+        *res.status_mut() = self.status;
+        Ok(res)
+    }
+}
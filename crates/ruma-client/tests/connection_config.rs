@@ -0,0 +1,74 @@
+#![cfg(feature = "client-api")]
+
+use ruma_client::{http_client::Dummy, Client, ConnectionConfigError, Error};
+use ruma_common::api::MatrixVersion;
+
+#[tokio::test]
+async fn unsupported_proxy() {
+    let err = Client::builder()
+        .homeserver_url("https://example.org".to_owned())
+        .supported_matrix_versions(vec![MatrixVersion::V1_0])
+        .proxy("http://127.0.0.1:3128".to_owned())
+        .build::<Dummy>()
+        .await
+        .unwrap_err();
+
+    let Error::ConnectionConfig(ConnectionConfigError::Unsupported { option, .. }) = err else {
+        panic!("expected an unsupported option error, got {err:?}");
+    };
+    assert_eq!(option, "proxy");
+}
+
+#[tokio::test]
+async fn unsupported_root_certificate() {
+    let err = Client::builder()
+        .homeserver_url("https://example.org".to_owned())
+        .supported_matrix_versions(vec![MatrixVersion::V1_0])
+        .add_root_certificate(b"certificate".to_vec())
+        .build::<Dummy>()
+        .await
+        .unwrap_err();
+
+    let Error::ConnectionConfig(ConnectionConfigError::Unsupported { option, .. }) = err else {
+        panic!("expected an unsupported option error, got {err:?}");
+    };
+    assert_eq!(option, "root_certificates");
+}
+
+#[cfg(feature = "reqwest")]
+#[tokio::test]
+async fn reqwest_proxy() {
+    use ruma_client::http_client::Reqwest;
+
+    Client::builder()
+        .homeserver_url("https://example.org".to_owned())
+        .supported_matrix_versions(vec![MatrixVersion::V1_0])
+        .proxy("http://127.0.0.1:3128".to_owned())
+        .build::<Reqwest>()
+        .await
+        .unwrap();
+
+    let err = Client::builder()
+        .homeserver_url("https://example.org".to_owned())
+        .supported_matrix_versions(vec![MatrixVersion::V1_0])
+        .proxy("not a url".to_owned())
+        .build::<Reqwest>()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::ConnectionConfig(ConnectionConfigError::Invalid(_))), "{err:?}");
+}
+
+#[cfg(feature = "reqwest-rustls-manual-roots")]
+#[tokio::test]
+async fn reqwest_invalid_root_certificate() {
+    use ruma_client::http_client::Reqwest;
+
+    let err = Client::builder()
+        .homeserver_url("https://example.org".to_owned())
+        .supported_matrix_versions(vec![MatrixVersion::V1_0])
+        .add_root_certificate(b"not a certificate".to_vec())
+        .build::<Reqwest>()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::ConnectionConfig(ConnectionConfigError::Invalid(_))), "{err:?}");
+}
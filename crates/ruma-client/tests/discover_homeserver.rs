@@ -0,0 +1,211 @@
+#![cfg(feature = "client-api")]
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use assert_matches2::assert_matches;
+use ruma_client::{Client, Error, HttpClient};
+use ruma_client_api::discovery::get_supported_versions;
+use ruma_common::{api::error::FromHttpResponseError, server_name};
+
+mod common;
+
+use self::common::StaticResponse;
+
+const WELL_KNOWN_URL: &str = "https://example.org/.well-known/matrix/client";
+const VERSIONS: StaticResponse =
+    StaticResponse { status: http::StatusCode::OK, body: br#"{"versions":["v1.1"]}"# };
+const NOT_FOUND: StaticResponse = StaticResponse {
+    status: http::StatusCode::NOT_FOUND,
+    body: br#"{"errcode":"M_NOT_FOUND","error":"Not found"}"#,
+};
+
+/// An HTTP client that returns a static response for each URL, or a `404 Not Found` response
+/// for unknown URLs, and records the URLs of the requests.
+struct StaticRoutes {
+    routes: BTreeMap<&'static str, StaticResponse>,
+    requests: Mutex<Vec<String>>,
+}
+
+impl StaticRoutes {
+    fn new(routes: impl IntoIterator<Item = (&'static str, StaticResponse)>) -> Arc<Self> {
+        Arc::new(Self { routes: routes.into_iter().collect(), requests: Mutex::default() })
+    }
+
+    fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl HttpClient for StaticRoutes {
+    type RequestBody = Vec<u8>;
+    type ResponseBody = &'static [u8];
+    type Error = ();
+
+    async fn send_http_request(
+        &self,
+        req: http::Request<Self::RequestBody>,
+    ) -> Result<http::Response<Self::ResponseBody>, Self::Error> {
+        let url = req.uri().to_string();
+        let response = self.routes.get(url.as_str()).unwrap_or(&NOT_FOUND);
+        self.requests.lock().unwrap().push(url);
+
+        response.send_http_request(req).await
+    }
+}
+
+#[tokio::test]
+async fn well_known_not_found_falls_back_to_server_name() {
+    let http_client =
+        StaticRoutes::new([("https://example.org/_matrix/client/versions", VERSIONS)]);
+
+    let client = Client::builder()
+        .discover_homeserver(server_name!("example.org"), &http_client)
+        .await
+        .unwrap()
+        .http_client(http_client.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        http_client.requests(),
+        [WELL_KNOWN_URL, "https://example.org/_matrix/client/versions"]
+    );
+
+    // The homeserver URL is used for the following requests.
+    client.send_request(get_supported_versions::Request::new()).await.unwrap();
+    assert_eq!(
+        http_client.requests().last().map(String::as_str),
+        Some("https://example.org/_matrix/client/versions")
+    );
+}
+
+#[tokio::test]
+async fn base_url_trailing_slash_is_trimmed() {
+    let http_client = StaticRoutes::new([
+        (
+            WELL_KNOWN_URL,
+            StaticResponse {
+                status: http::StatusCode::OK,
+                body: br#"{"m.homeserver":{"base_url":"https://matrix.example.org/"}}"#,
+            },
+        ),
+        ("https://matrix.example.org/_matrix/client/versions", VERSIONS),
+    ]);
+
+    let client = Client::builder()
+        .discover_homeserver(server_name!("example.org"), &http_client)
+        .await
+        .unwrap()
+        .http_client(http_client.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        http_client.requests(),
+        [WELL_KNOWN_URL, "https://matrix.example.org/_matrix/client/versions"]
+    );
+
+    client.send_request(get_supported_versions::Request::new()).await.unwrap();
+    assert_eq!(
+        http_client.requests().last().map(String::as_str),
+        Some("https://matrix.example.org/_matrix/client/versions")
+    );
+}
+
+#[tokio::test]
+async fn invalid_base_url() {
+    let http_client = StaticRoutes::new([(
+        WELL_KNOWN_URL,
+        StaticResponse {
+            status: http::StatusCode::OK,
+            body: br#"{"m.homeserver":{"base_url":"not a valid url"}}"#,
+        },
+    )]);
+
+    let result =
+        Client::builder().discover_homeserver(server_name!("example.org"), &http_client).await;
+    let result = result.map(|_| ());
+    assert_matches!(result, Err(Error::Url(_)));
+    assert_eq!(http_client.requests(), [WELL_KNOWN_URL]);
+}
+
+#[tokio::test]
+async fn failing_versions() {
+    let http_client = StaticRoutes::new([
+        (
+            WELL_KNOWN_URL,
+            StaticResponse {
+                status: http::StatusCode::OK,
+                body: br#"{"m.homeserver":{"base_url":"https://matrix.example.org"}}"#,
+            },
+        ),
+        (
+            "https://matrix.example.org/_matrix/client/versions",
+            StaticResponse {
+                status: http::StatusCode::INTERNAL_SERVER_ERROR,
+                body: br#"{"errcode":"M_UNKNOWN","error":"Internal error"}"#,
+            },
+        ),
+    ]);
+
+    let result =
+        Client::builder().discover_homeserver(server_name!("example.org"), &http_client).await;
+    let result = result.map(|_| ());
+    assert_matches!(result, Err(Error::FromHttpResponse(FromHttpResponseError::Server(error))));
+    assert_eq!(error.status_code, http::StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn valid_identity_server() {
+    let http_client = StaticRoutes::new([
+        (
+            WELL_KNOWN_URL,
+            StaticResponse {
+                status: http::StatusCode::OK,
+                body: br#"{
+                    "m.homeserver": { "base_url": "https://matrix.example.org" },
+                    "m.identity_server": { "base_url": "https://identity.example.org/" }
+                }"#,
+            },
+        ),
+        ("https://matrix.example.org/_matrix/client/versions", VERSIONS),
+        (
+            "https://identity.example.org/_matrix/identity/v2",
+            StaticResponse { status: http::StatusCode::OK, body: b"{}" },
+        ),
+    ]);
+
+    Client::builder().discover_homeserver(server_name!("example.org"), &http_client).await.unwrap();
+    assert_eq!(
+        http_client.requests(),
+        [
+            WELL_KNOWN_URL,
+            "https://matrix.example.org/_matrix/client/versions",
+            "https://identity.example.org/_matrix/identity/v2",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn failing_identity_server() {
+    let http_client = StaticRoutes::new([
+        (
+            WELL_KNOWN_URL,
+            StaticResponse {
+                status: http::StatusCode::OK,
+                body: br#"{
+                    "m.homeserver": { "base_url": "https://matrix.example.org" },
+                    "m.identity_server": { "base_url": "https://identity.example.org" }
+                }"#,
+            },
+        ),
+        ("https://matrix.example.org/_matrix/client/versions", VERSIONS),
+    ]);
+
+    let result =
+        Client::builder().discover_homeserver(server_name!("example.org"), &http_client).await;
+    let result = result.map(|_| ());
+    assert_matches!(result, Err(Error::FromHttpResponse(FromHttpResponseError::Server(error))));
+    assert_eq!(error.status_code, http::StatusCode::NOT_FOUND);
+}
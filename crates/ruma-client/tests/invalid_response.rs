@@ -0,0 +1,66 @@
+#![cfg(feature = "client-api")]
+
+use ruma_client::{http_client::HttpClientExt, Error};
+use ruma_client_api::discovery::get_supported_versions;
+use ruma_common::api::{MatrixVersion, SendAccessToken};
+
+mod common;
+
+use self::common::StaticResponse;
+
+#[tokio::test]
+async fn invalid_success_response() {
+    let http_client = StaticResponse { status: http::StatusCode::OK, body: br#"{"versions":42}"# };
+
+    let err = http_client
+        .send_matrix_request(
+            "https://example.org",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_0],
+            get_supported_versions::Request::new(),
+        )
+        .await
+        .unwrap_err();
+
+    let Error::InvalidResponse(err) = err else {
+        panic!("expected an invalid response error, got {err:?}");
+    };
+    assert_eq!(err.status, http::StatusCode::OK);
+    assert_eq!(err.body, br#"{"versions":42}"#);
+}
+
+#[tokio::test]
+async fn valid_success_response() {
+    let http_client =
+        StaticResponse { status: http::StatusCode::OK, body: br#"{"versions":["v1.1"]}"# };
+
+    let res = http_client
+        .send_matrix_request(
+            "https://example.org",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_0],
+            get_supported_versions::Request::new(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(res.versions, ["v1.1"]);
+}
+
+#[tokio::test]
+async fn error_response() {
+    let http_client = StaticResponse {
+        status: http::StatusCode::NOT_FOUND,
+        body: br#"{"errcode":"M_NOT_FOUND","error":"Not found"}"#,
+    };
+
+    let err = http_client
+        .send_matrix_request(
+            "https://example.org",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_0],
+            get_supported_versions::Request::new(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_kind(), Some(&ruma_client_api::error::ErrorKind::NotFound));
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "client-api")]
+
+use std::{
+    collections::VecDeque,
+    future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use ruma_client::{Client, HttpClient, RequestConfig};
+use ruma_client_api::{discovery::get_supported_versions, error::ErrorKind};
+use ruma_common::api::MatrixVersion;
+
+const RATE_LIMITED: (http::StatusCode, &[u8]) = (
+    http::StatusCode::TOO_MANY_REQUESTS,
+    br#"{"errcode":"M_LIMIT_EXCEEDED","error":"Too many requests","retry_after_ms":2000}"#,
+);
+const SUCCESS: (http::StatusCode, &[u8]) = (http::StatusCode::OK, br#"{"versions":["v1.1"]}"#);
+
+/// An HTTP client that returns the given responses in order.
+struct QueuedResponses(Mutex<VecDeque<(http::StatusCode, &'static [u8])>>);
+
+impl QueuedResponses {
+    fn new(responses: impl IntoIterator<Item = (http::StatusCode, &'static [u8])>) -> Self {
+        Self(Mutex::new(responses.into_iter().collect()))
+    }
+
+    fn remaining(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+}
+
+impl HttpClient for QueuedResponses {
+    type RequestBody = Vec<u8>;
+    type ResponseBody = &'static [u8];
+    type Error = ();
+
+    async fn send_http_request(
+        &self,
+        _req: http::Request<Self::RequestBody>,
+    ) -> Result<http::Response<Self::ResponseBody>, Self::Error> {
+        let (status, body) = self.0.lock().unwrap().pop_front().ok_or(())?;
+        let mut res = http::Response::new(body);
+        *res.status_mut() = status;
+        Ok(res)
+    }
+}
+
+/// Build a client with the given responses and a `RequestConfig` that records the delays it
+/// waits for.
+async fn client(
+    responses: impl IntoIterator<Item = (http::StatusCode, &'static [u8])>,
+    config: RequestConfig,
+) -> (Client<Arc<QueuedResponses>>, Arc<QueuedResponses>, Arc<Mutex<Vec<Duration>>>) {
+    let http_client = Arc::new(QueuedResponses::new(responses));
+    let delays = Arc::new(Mutex::new(Vec::new()));
+
+    let recorded_delays = delays.clone();
+    let config = config.sleep(move |delay| {
+        recorded_delays.lock().unwrap().push(delay);
+        future::ready(())
+    });
+
+    let client = Client::builder()
+        .homeserver_url("https://example.org".to_owned())
+        .supported_matrix_versions(vec![MatrixVersion::V1_0])
+        .request_config(config)
+        .http_client(http_client.clone())
+        .await
+        .unwrap();
+
+    (client, http_client, delays)
+}
+
+#[tokio::test]
+async fn retry_rate_limited_request() {
+    let (client, http_client, delays) =
+        client([RATE_LIMITED, RATE_LIMITED, SUCCESS], RequestConfig::new()).await;
+
+    let res = client.send_request(get_supported_versions::Request::new()).await.unwrap();
+    assert_eq!(res.versions, ["v1.1"]);
+    assert_eq!(http_client.remaining(), 0);
+    assert_eq!(*delays.lock().unwrap(), [Duration::from_secs(2), Duration::from_secs(2)]);
+}
+
+#[tokio::test]
+async fn exponential_backoff_without_retry_after() {
+    let rate_limited = (
+        http::StatusCode::TOO_MANY_REQUESTS,
+        br#"{"errcode":"M_LIMIT_EXCEEDED","error":"Too many requests"}"#.as_slice(),
+    );
+    let (client, _, delays) =
+        client([rate_limited, rate_limited, rate_limited, SUCCESS], RequestConfig::new()).await;
+
+    client.send_request(get_supported_versions::Request::new()).await.unwrap();
+
+    let delays = delays.lock().unwrap();
+    assert_eq!(delays.len(), 3);
+    for (attempt, delay) in delays.iter().enumerate() {
+        let max = Duration::from_millis(500) * 2_u32.pow(attempt as u32);
+        assert!(*delay >= max / 2 && *delay <= max, "unexpected delay {delay:?} for {attempt}");
+    }
+}
+
+#[tokio::test]
+async fn give_up_after_max_retries() {
+    let (client, http_client, delays) =
+        client([RATE_LIMITED, RATE_LIMITED, SUCCESS], RequestConfig::new().max_retries(1)).await;
+
+    let err = client.send_request(get_supported_versions::Request::new()).await.unwrap_err();
+    assert_eq!(
+        err.error_kind(),
+        Some(&ErrorKind::LimitExceeded { retry_after_ms: Some(Duration::from_secs(2)) })
+    );
+    assert_eq!(http_client.remaining(), 1);
+    assert_eq!(delays.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn give_up_when_retry_after_exceeds_max_delay() {
+    let (client, http_client, delays) =
+        client([RATE_LIMITED, SUCCESS], RequestConfig::new().max_delay(Duration::from_secs(1)))
+            .await;
+
+    client.send_request(get_supported_versions::Request::new()).await.unwrap_err();
+    assert_eq!(http_client.remaining(), 1);
+    assert!(delays.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn no_retry_without_sleep() {
+    let http_client = Arc::new(QueuedResponses::new([RATE_LIMITED, SUCCESS]));
+    let client = Client::builder()
+        .homeserver_url("https://example.org".to_owned())
+        .supported_matrix_versions(vec![MatrixVersion::V1_0])
+        .http_client(http_client.clone())
+        .await
+        .unwrap();
+
+    client.send_request(get_supported_versions::Request::new()).await.unwrap_err();
+    assert_eq!(http_client.remaining(), 1);
+}
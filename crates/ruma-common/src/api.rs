@@ -113,18 +113,19 @@ macro_rules! metadata {
 /// To declare which part of the request a field belongs to:
 ///
 /// * `#[ruma_api(header = HEADER_NAME)]`: Fields with this attribute will be treated as HTTP
-///   headers on the request. The value must implement `Display`. Generally this is a `String`.
-///   The attribute value shown above as `HEADER_NAME` must be a `const` expression of the type
+///   headers on the request. The field must be a `String`, or an `Option<String>` if the
+///   header is optional. A missing required header is a deserialization error. The attribute
+///   value shown above as `HEADER_NAME` must be a `const` expression of the type
 ///   `http::header::HeaderName`, like one of the constants from `http::header`, e.g.
 ///   `CONTENT_TYPE`.
 /// * `#[ruma_api(path)]`: Fields with this attribute will be inserted into the matching path
 ///   component of the request URL. If there are multiple of these fields, the order in which
 ///   they are declared must match the order in which they occur in the request path.
-/// * `#[ruma_api(query)]`: Fields with this attribute will be inserting into the URL's query
-///   string.
+/// * `#[ruma_api(query)]`: Fields with this attribute will be inserted into the URL's query
+///   string. Sequences like `Vec<T>` are serialized as a repeated key (`key=a&key=b`).
 /// * `#[ruma_api(query_map)]`: Instead of individual query fields, one query_map field, of any
-///   type that implements `IntoIterator<Item = (String, String)>` (e.g. `HashMap<String,
-///   String>`, can be used for cases where an endpoint supports arbitrary query parameters.
+///   type that implements `IntoIterator<Item = (String, String)>` (e.g. `Vec<(String,
+///   String)>`), can be used for cases where an endpoint supports arbitrary query parameters.
 /// * No attribute: Fields without an attribute are part of the body. They can use `#[serde]`
 ///   attributes to customize (de)serialization.
 /// * `#[ruma_api(body)]`: Use this if multiple endpoints should share a request body type, or
@@ -133,7 +134,8 @@ macro_rules! metadata {
 ///   object).
 /// * `#[ruma_api(raw_body)]`: Like `body` in that the field annotated with it represents the
 ///   entire request body, but this attribute is for endpoints where the body can be anything,
-///   not just JSON. The field type must be `Vec<u8>`.
+///   not just JSON. The field type must be `Vec<u8>`. The `Content-Type` header defaults to
+///   `application/octet-stream`, a `CONTENT_TYPE` header field can be used to override it.
 ///
 /// ## Examples
 ///
@@ -222,9 +224,10 @@ pub use ruma_macros::request;
 /// To declare which part of the request a field belongs to:
 ///
 /// * `#[ruma_api(header = HEADER_NAME)]`: Fields with this attribute will be treated as HTTP
-///   headers on the response. The value must implement `Display`. Generally this is a
-///   `String`. The attribute value shown above as `HEADER_NAME` must be a header name constant
-///   from `http::header`, e.g. `CONTENT_TYPE`.
+///   headers on the response. The field must be a `String`, or an `Option<String>` if the
+///   header is optional. A missing required header is a deserialization error. The attribute
+///   value shown above as `HEADER_NAME` must be a header name constant from `http::header`,
+///   e.g. `CONTENT_TYPE`.
 /// * No attribute: Fields without an attribute are part of the body. They can use `#[serde]`
 ///   attributes to customize (de)serialization.
 /// * `#[ruma_api(body)]`: Use this if multiple endpoints should share a response body type, or
@@ -233,7 +236,8 @@ pub use ruma_macros::request;
 ///   object).
 /// * `#[ruma_api(raw_body)]`: Like `body` in that the field annotated with it represents the
 ///   entire response body, but this attribute is for endpoints where the body can be anything,
-///   not just JSON. The field type must be `Vec<u8>`.
+///   not just JSON. The field type must be `Vec<u8>`. The `Content-Type` header defaults to
+///   `application/octet-stream`, a `CONTENT_TYPE` header field can be used to override it.
 ///
 /// ## Examples
 ///
@@ -318,6 +322,10 @@ pub enum SendAccessToken<'a> {
     /// Always add the access token.
     Always(&'a str),
 
+    /// Add the given appservice token to the request only if the `METADATA` on the request
+    /// requires it.
+    Appservice(&'a str),
+
     /// Don't add an access token.
     ///
     /// This will lead to an error if the request endpoint requires authentication
@@ -329,7 +337,7 @@ impl<'a> SendAccessToken<'a> {
     ///
     /// Returns `Some(_)` if `self` contains an access token.
     pub fn get_required_for_endpoint(self) -> Option<&'a str> {
-        as_variant!(self, Self::IfRequired | Self::Always)
+        as_variant!(self, Self::IfRequired | Self::Appservice | Self::Always)
     }
 
     /// Get the access token for an endpoint that should not require one.
@@ -338,6 +346,14 @@ impl<'a> SendAccessToken<'a> {
     pub fn get_not_required_for_endpoint(self) -> Option<&'a str> {
         as_variant!(self, Self::Always)
     }
+
+    /// Gets the access token for an endpoint that requires one for appservices.
+    ///
+    /// Returns `Some(_)` if `self` is either `SendAccessToken::Appservice(_)`
+    /// or `SendAccessToken::Always(_)`
+    pub fn get_required_for_appservice(self) -> Option<&'a str> {
+        as_variant!(self, Self::Appservice | Self::Always)
+    }
 }
 
 /// A request type for a Matrix API endpoint, used for sending requests.
@@ -388,6 +404,54 @@ pub trait IncomingResponse: Sized {
     ) -> Result<Self, FromHttpResponseError<Self::EndpointError>>;
 }
 
+/// A request type for a Matrix API endpoint with a raw body, that can be sent with a body of any
+/// type.
+///
+/// This allows to stream the body of large requests, like media uploads, instead of buffering it
+/// in memory. This is implemented by the [`request`] macro for requests with a
+/// `#[ruma_api(raw_body)]` field.
+pub trait OutgoingRawBodyRequest: OutgoingRequest {
+    /// Tries to convert this request into an `http::Request` with the given body.
+    ///
+    /// The raw body field of this request is ignored, so it should be left empty. See
+    /// [`OutgoingRequest::try_into_http_request`] for the possible errors.
+    fn try_into_http_request_with_body<B>(
+        self,
+        base_url: &str,
+        access_token: SendAccessToken<'_>,
+        considering_versions: &'_ [MatrixVersion],
+        body: B,
+    ) -> Result<http::Request<B>, IntoHttpError> {
+        let http_request =
+            self.try_into_http_request::<Vec<u8>>(base_url, access_token, considering_versions)?;
+        Ok(http_request.map(|_| body))
+    }
+}
+
+/// A response type for a Matrix API endpoint with a raw body, that can be received with a body of
+/// any type.
+///
+/// This allows to stream the body of large responses, like media downloads, instead of buffering
+/// it in memory. This is implemented by the [`response`] macro for responses with a
+/// `#[ruma_api(raw_body)]` field.
+pub trait IncomingRawBodyResponse: IncomingResponse {
+    /// Tries to convert the given `http::Response` into this response type, without reading its
+    /// body.
+    ///
+    /// Returns the response with an empty raw body field, and the body of the `http::Response`.
+    ///
+    /// The body is not read for responses with an error status either, so the error won't contain
+    /// the details sent by the server. To get them, the body must be collected and passed to
+    /// [`IncomingResponse::try_from_http_response`].
+    fn try_from_http_response_with_body<B>(
+        response: http::Response<B>,
+    ) -> Result<(Self, B), FromHttpResponseError<Self::EndpointError>> {
+        let (parts, body) = response.into_parts();
+        let response = Self::try_from_http_response(http::Response::from_parts(parts, &[]))?;
+        Ok((response, body))
+    }
+}
+
 /// An extension to [`OutgoingRequest`] which provides Appservice specific methods.
 pub trait OutgoingRequestAppserviceExt: OutgoingRequest {
     /// Tries to convert this request into an `http::Request` and appends a virtual `user_id` to
@@ -451,17 +515,63 @@ pub trait IncomingRequest: Sized {
         S: AsRef<str>;
 }
 
-/// A request type for a Matrix API endpoint, used for sending responses.
+/// A response type for a Matrix API endpoint, used for sending responses.
 pub trait OutgoingResponse {
     /// Tries to convert this response into an `http::Response`.
     ///
-    /// This method should only fail when when invalid header values are specified. It may also
+    /// This method should only fail when invalid header values are specified. It may also
     /// fail with a serialization error in case of bugs in Ruma though.
     fn try_into_http_response<T: Default + BufMut>(
         self,
     ) -> Result<http::Response<T>, IntoHttpError>;
 }
 
+/// A request type for a Matrix API endpoint with a raw body, that can be received with a body of
+/// any type.
+///
+/// This allows to stream the body of large requests, like media uploads, instead of buffering it
+/// in memory. This is implemented by the [`request`] macro for requests with a
+/// `#[ruma_api(raw_body)]` field.
+pub trait IncomingRawBodyRequest: IncomingRequest {
+    /// Tries to turn the given `http::Request` into this request type, together with the
+    /// corresponding path arguments, without reading its body.
+    ///
+    /// Returns the request with an empty raw body field, and the body of the `http::Request`.
+    ///
+    /// Note: The strings in path_args need to be percent-decoded.
+    fn try_from_http_request_with_body<B, S>(
+        req: http::Request<B>,
+        path_args: &[S],
+    ) -> Result<(Self, B), FromHttpRequestError>
+    where
+        S: AsRef<str>,
+    {
+        let (parts, body) = req.into_parts();
+        let req = Self::try_from_http_request(http::Request::from_parts(parts, &[]), path_args)?;
+        Ok((req, body))
+    }
+}
+
+/// A response type for a Matrix API endpoint with a raw body, that can be sent with a body of any
+/// type.
+///
+/// This allows to stream the body of large responses, like media downloads, instead of buffering
+/// it in memory. This is implemented by the [`response`] macro for responses with a
+/// `#[ruma_api(raw_body)]` field.
+pub trait OutgoingRawBodyResponse: OutgoingResponse + Sized {
+    /// Tries to convert this response into an `http::Response` with the given body.
+    ///
+    /// The raw body field of this response is ignored, so it should be left empty. See
+    /// [`OutgoingResponse::try_into_http_response`] for the possible errors.
+    fn try_into_http_response_with_body<B>(
+        self,
+        body: B,
+    ) -> Result<http::Response<B>, IntoHttpError> {
+        let http_response = self.try_into_http_response::<Vec<u8>>()?;
+        Ok(http_response.map(|_| body))
+    }
+}
+
 /// Gives users the ability to define their own serializable / deserializable errors.
 pub trait EndpointError: OutgoingResponse + StdError + Sized + Send + 'static {
     /// Tries to construct `Self` from an `http::Response`.
@@ -490,6 +600,13 @@ pub enum AuthScheme {
     /// It is recommended to use the header over the query parameter.
     AccessTokenOptional,
 
+    /// Authentication is required, and can only be performed for appservices, by including an
+    /// appservice access token in the `Authentication` http header, or `access_token` query
+    /// parameter.
+    ///
+    /// It is recommended to use the header over the query parameter.
+    AppserviceToken,
+
     /// Authentication is performed by including X-Matrix signatures in the request headers,
     /// as defined in the federation API.
     ServerSignatures,
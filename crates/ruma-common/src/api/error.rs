@@ -9,6 +9,7 @@ use serde_json::{from_slice as from_json_slice, Value as JsonValue};
 use thiserror::Error;
 
 use super::{EndpointError, MatrixVersion, OutgoingResponse};
+use crate::http_headers::ContentDispositionParseError;
 
 /// A general-purpose Matrix error type consisting of an HTTP status code and a JSON body.
 ///
@@ -251,6 +252,12 @@ impl From<http::header::ToStrError> for DeserializationError {
     }
 }
 
+impl From<ContentDispositionParseError> for DeserializationError {
+    fn from(err: ContentDispositionParseError) -> Self {
+        Self::Header(err.into())
+    }
+}
+
 /// An error with the http headers.
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -262,6 +269,10 @@ pub enum HeaderDeserializationError {
     /// The given required header is missing.
     #[error("missing header `{0}`")]
     MissingHeader(String),
+
+    /// The value of a `Content-Disposition` header is invalid.
+    #[error("invalid `Content-Disposition` header: {0}")]
+    InvalidContentDisposition(#[from] ContentDispositionParseError),
 }
 
 /// An error that happens when Ruma cannot understand a Matrix version.
@@ -79,6 +79,14 @@ impl Metadata {
                 None => None,
             },
 
+            AuthScheme::AppserviceToken => {
+                let token = access_token
+                    .get_required_for_appservice()
+                    .ok_or(IntoHttpError::NeedsAuthentication)?;
+
+                Some((header::AUTHORIZATION, format!("Bearer {token}").try_into()?))
+            }
+
             AuthScheme::ServerSignatures => None,
         })
     }
@@ -305,8 +313,16 @@ impl VersionHistory {
         VersionHistory { unstable_paths, stable_paths, deprecated, removed }
     }
 
-    // This function helps picks the right path (or an error) from a set of Matrix versions.
-    fn select_path(&self, versions: &[MatrixVersion]) -> Result<&'static str, IntoHttpError> {
+    /// Picks the right path to query the endpoint, given the Matrix versions supported by the
+    /// homeserver.
+    ///
+    /// This is the path used by [`Metadata::make_endpoint_url`]. It is the latest stable path that
+    /// any of the versions accepts, or the unstable path if none of them supports the endpoint in
+    /// a stable fashion.
+    ///
+    /// Returns an error if the endpoint was removed in all the given versions, or if no stable
+    /// version supports it and it has no unstable path.
+    pub fn select_path(&self, versions: &[MatrixVersion]) -> Result<&'static str, IntoHttpError> {
         match self.versioning_decision_for(versions) {
             VersioningDecision::Removed => Err(IntoHttpError::EndpointRemoved(
                 self.removed.expect("VersioningDecision::Removed implies metadata.removed"),
@@ -398,6 +414,15 @@ impl VersionHistory {
         self.removed
     }
 
+    /// Whether this endpoint is available in a stable fashion in the given Matrix version.
+    ///
+    /// This is `true` if the endpoint was added in or before the given version and was not
+    /// removed yet. Deprecated endpoints are still available.
+    pub fn is_supported_in(&self, version: MatrixVersion) -> bool {
+        self.added_in().is_some_and(|added| version.is_superset_of(added))
+            && !self.removed.is_some_and(|removed| version.is_superset_of(removed))
+    }
+
     /// Picks the last unstable path, if it exists.
     pub fn unstable(&self) -> Option<&'static str> {
         self.unstable_paths.last().copied()
@@ -714,6 +739,18 @@ impl MatrixVersion {
     }
 }
 
+impl PartialOrd for MatrixVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MatrixVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.const_ord(other)
+    }
+}
+
 impl Display for MatrixVersion {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (major, minor) = self.into_parts();
@@ -728,10 +765,10 @@ mod tests {
 
     use super::{
         AuthScheme,
-        MatrixVersion::{self, V1_0, V1_1, V1_2, V1_3},
+        MatrixVersion::{self, V1_0, V1_1, V1_2, V1_3, V1_4},
         Metadata, VersionHistory,
     };
-    use crate::api::error::IntoHttpError;
+    use crate::api::{error::IntoHttpError, SendAccessToken};
 
     fn stable_only_metadata(stable_paths: &'static [(MatrixVersion, &'static str)]) -> Metadata {
         Metadata {
@@ -792,6 +829,40 @@ mod tests {
         _ = meta.make_endpoint_url(&[V1_0], "https://example.org", &[], "");
     }
 
+    #[test]
+    fn appservice_token_authorization_header() {
+        let meta = Metadata {
+            authentication: AuthScheme::AppserviceToken,
+            ..stable_only_metadata(&[(V1_0, "/s")])
+        };
+
+        let (name, value) =
+            meta.authorization_header(SendAccessToken::Appservice("as_token")).unwrap().unwrap();
+        assert_eq!(name, http::header::AUTHORIZATION);
+        assert_eq!(value, "Bearer as_token");
+
+        assert_matches!(
+            meta.authorization_header(SendAccessToken::IfRequired("user_token")),
+            Err(IntoHttpError::NeedsAuthentication)
+        );
+        assert_matches!(
+            meta.authorization_header(SendAccessToken::None),
+            Err(IntoHttpError::NeedsAuthentication)
+        );
+    }
+
+    #[test]
+    fn appservice_token_for_access_token_endpoint() {
+        let meta = Metadata {
+            authentication: AuthScheme::AccessToken,
+            ..stable_only_metadata(&[(V1_0, "/s")])
+        };
+
+        let (_, value) =
+            meta.authorization_header(SendAccessToken::Appservice("as_token")).unwrap().unwrap();
+        assert_eq!(value, "Bearer as_token");
+    }
+
     const EMPTY: VersionHistory =
         VersionHistory { unstable_paths: &[], stable_paths: &[], deprecated: None, removed: None };
 
@@ -841,6 +912,55 @@ mod tests {
         assert_matches!(hist.select_path(&[V1_0]), Err(IntoHttpError::NoUnstablePath));
     }
 
+    #[test]
+    fn select_latest_stable_of_migrated_path() {
+        let hist = VersionHistory {
+            stable_paths: &[(V1_0, "/r0/s"), (V1_1, "/v3/s")],
+            unstable_paths: &["/unstable/s"],
+            ..EMPTY
+        };
+        assert_matches!(hist.select_path(&[V1_0]), Ok("/r0/s"));
+        assert_matches!(hist.select_path(&[V1_1]), Ok("/v3/s"));
+        assert_matches!(hist.select_path(&[V1_0, V1_1]), Ok("/v3/s"));
+        assert_matches!(hist.select_path(&[V1_3]), Ok("/v3/s"));
+    }
+
+    #[test]
+    fn select_unstable_before_stabilization() {
+        let hist = VersionHistory {
+            stable_paths: &[(V1_2, "/v3/s")],
+            unstable_paths: &["/unstable/old", "/unstable/s"],
+            ..EMPTY
+        };
+        assert_matches!(hist.select_path(&[V1_0, V1_1]), Ok("/unstable/s"));
+        assert_matches!(hist.select_path(&[V1_1, V1_2]), Ok("/v3/s"));
+    }
+
+    #[test]
+    fn supported_in() {
+        let hist = VersionHistory {
+            stable_paths: &[(V1_1, "/v3/s")],
+            unstable_paths: &["/unstable/s"],
+            deprecated: Some(V1_2),
+            removed: Some(V1_3),
+        };
+        assert!(!hist.is_supported_in(V1_0));
+        assert!(hist.is_supported_in(V1_1));
+        assert!(hist.is_supported_in(V1_2));
+        assert!(!hist.is_supported_in(V1_3));
+        assert!(!hist.is_supported_in(V1_4));
+
+        let hist = VersionHistory { unstable_paths: &["/unstable/s"], ..EMPTY };
+        assert!(!hist.is_supported_in(V1_4));
+    }
+
+    #[test]
+    fn version_ordering() {
+        assert!(V1_0 < V1_1);
+        assert!(V1_3 > V1_2);
+        assert_eq!([V1_2, V1_0, V1_3, V1_1].iter().max(), Some(&V1_3));
+    }
+
     #[test]
     fn version_literal() {
         const LIT: MatrixVersion = MatrixVersion::from_lit("1.0");
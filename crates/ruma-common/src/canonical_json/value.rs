@@ -110,6 +110,11 @@ impl CanonicalJsonValue {
         as_variant!(self, Self::Object)
     }
 
+    /// Returns `true` if the `CanonicalJsonValue` is `Null`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
     /// Returns `true` if the `CanonicalJsonValue` is a `Bool`.
     pub fn is_bool(&self) -> bool {
         matches!(self, Self::Bool(_))
@@ -136,7 +136,7 @@ impl Filter {
 
     /// Returns `true` if the filter is empty.
     pub fn is_empty(&self) -> bool {
-        self.generic_search_term.is_none()
+        self.generic_search_term.is_none() && self.room_types.is_empty()
     }
 }
 
@@ -226,6 +226,7 @@ where
 #[cfg(test)]
 mod tests {
     use assert_matches2::assert_matches;
+    use assign::assign;
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
     use super::{Filter, RoomNetwork, RoomTypeFilter};
@@ -319,6 +320,17 @@ mod tests {
         assert_eq!(to_json_value(filter).unwrap(), json);
     }
 
+    #[test]
+    fn filter_is_empty() {
+        assert!(Filter::new().is_empty());
+
+        let filter = assign!(Filter::new(), { generic_search_term: Some("ruma".to_owned()) });
+        assert!(!filter.is_empty());
+
+        let filter = assign!(Filter::new(), { room_types: vec![RoomTypeFilter::Space] });
+        assert!(!filter.is_empty());
+    }
+
     #[test]
     fn deserialize_filter_room_types() {
         let json = json!({ "room_types": [null, "m.space", "custom_type"] });
@@ -6,6 +6,8 @@ use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "canonical-json")]
+use crate::{canonical_json::to_canonical_value, CanonicalJsonError, CanonicalJsonValue};
 use crate::{
     serde::{Base64, StringEnum},
     EventEncryptionAlgorithm, OwnedDeviceId, OwnedDeviceKeyId, OwnedUserId, PrivOwnedStr,
@@ -38,6 +40,15 @@ pub struct DeviceKeys {
     /// not covered by the signatures.
     #[serde(default, skip_serializing_if = "UnsignedDeviceInfo::is_empty")]
     pub unsigned: UnsignedDeviceInfo,
+
+    /// Whether this is a [dehydrated device].
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [dehydrated device]: https://github.com/matrix-org/matrix-spec-proposals/pull/3814
+    #[cfg(feature = "unstable-msc3814")]
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub dehydrated: bool,
 }
 
 impl DeviceKeys {
@@ -50,7 +61,24 @@ impl DeviceKeys {
         keys: BTreeMap<OwnedDeviceKeyId, String>,
         signatures: BTreeMap<OwnedUserId, BTreeMap<OwnedDeviceKeyId, String>>,
     ) -> Self {
-        Self { user_id, device_id, algorithms, keys, signatures, unsigned: Default::default() }
+        Self {
+            user_id,
+            device_id,
+            algorithms,
+            keys,
+            signatures,
+            unsigned: Default::default(),
+            #[cfg(feature = "unstable-msc3814")]
+            dehydrated: false,
+        }
+    }
+
+    /// Returns the canonical JSON of these device keys, as it must be signed or verified.
+    ///
+    /// The `signatures` and `unsigned` fields are not part of the signed data.
+    #[cfg(feature = "canonical-json")]
+    pub fn signable_json(&self) -> Result<String, CanonicalJsonError> {
+        signable_json(self)
     }
 }
 
@@ -103,6 +131,14 @@ impl SignedKey {
     pub fn new_fallback(key: Base64, signatures: SignedKeySignatures) -> Self {
         Self { key, signatures, fallback: true }
     }
+
+    /// Returns the canonical JSON of this key, as it must be signed or verified.
+    ///
+    /// The `signatures` field is not part of the signed data, but the `fallback` marker is.
+    #[cfg(feature = "canonical-json")]
+    pub fn signable_json(&self) -> Result<String, CanonicalJsonError> {
+        signable_json(self)
+    }
 }
 
 /// A one-time public key for "pre-key" messages.
@@ -152,6 +188,14 @@ impl CrossSigningKey {
     ) -> Self {
         Self { user_id, usage, keys, signatures }
     }
+
+    /// Returns the canonical JSON of this key, as it must be signed or verified.
+    ///
+    /// The `signatures` field is not part of the signed data.
+    #[cfg(feature = "canonical-json")]
+    pub fn signable_json(&self) -> Result<String, CanonicalJsonError> {
+        signable_json(self)
+    }
 }
 
 /// The usage of a cross signing key.
@@ -172,3 +216,68 @@ pub enum KeyUsage {
     #[doc(hidden)]
     _Custom(PrivOwnedStr),
 }
+
+/// Serializes the given value to canonical JSON, without its `signatures` and `unsigned` fields.
+#[cfg(feature = "canonical-json")]
+fn signable_json(value: &impl Serialize) -> Result<String, CanonicalJsonError> {
+    let mut value = to_canonical_value(value)?;
+    if let CanonicalJsonValue::Object(object) = &mut value {
+        object.remove("signatures");
+        object.remove("unsigned");
+    }
+
+    Ok(value.to_string())
+}
+
+#[cfg(all(test, feature = "canonical-json"))]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{DeviceKeys, SignedKey, UnsignedDeviceInfo};
+    use crate::{
+        owned_device_id, owned_device_key_id, owned_user_id, serde::Base64,
+        EventEncryptionAlgorithm,
+    };
+
+    #[test]
+    fn device_keys_signable_json() {
+        let user_id = owned_user_id!("@alice:example.org");
+        let mut device_keys = DeviceKeys::new(
+            user_id.clone(),
+            owned_device_id!("JLAFKJWSCS"),
+            vec![EventEncryptionAlgorithm::MegolmV1AesSha2],
+            BTreeMap::from([(
+                owned_device_key_id!("ed25519:JLAFKJWSCS"),
+                "lEuiRJBit0IG6nUf5pUzWTUEsRVVe/HJkoKuEww9ULI".to_owned(),
+            )]),
+            BTreeMap::from([(
+                user_id,
+                BTreeMap::from([(
+                    owned_device_key_id!("ed25519:JLAFKJWSCS"),
+                    "signature".to_owned(),
+                )]),
+            )]),
+        );
+        device_keys.unsigned =
+            UnsignedDeviceInfo { device_display_name: Some("Alice's mobile phone".to_owned()) };
+
+        assert_eq!(
+            device_keys.signable_json().unwrap(),
+            r#"{"algorithms":["m.megolm.v1.aes-sha2"],"device_id":"JLAFKJWSCS","keys":{"ed25519:JLAFKJWSCS":"lEuiRJBit0IG6nUf5pUzWTUEsRVVe/HJkoKuEww9ULI"},"user_id":"@alice:example.org"}"#
+        );
+    }
+
+    #[test]
+    fn signed_key_signable_json() {
+        let key = Base64::new(b"key".to_vec());
+
+        assert_eq!(
+            SignedKey::new(key.clone(), BTreeMap::new()).signable_json().unwrap(),
+            r#"{"key":"a2V5"}"#
+        );
+        assert_eq!(
+            SignedKey::new_fallback(key, BTreeMap::new()).signable_json().unwrap(),
+            r#"{"fallback":true,"key":"a2V5"}"#
+        );
+    }
+}
@@ -0,0 +1,342 @@
+//! Helpers for HTTP headers used by the Matrix APIs.
+
+use std::{fmt, str::FromStr};
+
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+use crate::{serde::StringEnum, PrivOwnedStr};
+
+/// The characters that are not percent-encoded in the `value-chars` of an extended parameter
+/// value, as defined in [RFC 8187].
+///
+/// [RFC 8187]: https://datatracker.ietf.org/doc/html/rfc8187#section-3.2.1
+const ATTR_CHAR_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'!')
+    .remove(b'#')
+    .remove(b'$')
+    .remove(b'&')
+    .remove(b'+')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'^')
+    .remove(b'_')
+    .remove(b'`')
+    .remove(b'|')
+    .remove(b'~');
+
+/// The value of a `Content-Disposition` HTTP header, as defined in [RFC 6266].
+///
+/// The only parameter that is kept is the name of the file. When parsing a header, the extended
+/// `filename*` parameter is preferred to the `filename` parameter if both are present.
+///
+/// [RFC 6266]: https://datatracker.ietf.org/doc/html/rfc6266
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct ContentDisposition {
+    /// The disposition type.
+    pub disposition_type: ContentDispositionType,
+
+    /// The name of the file, if any.
+    pub filename: Option<String>,
+}
+
+impl ContentDisposition {
+    /// Creates a new `ContentDisposition` with the given disposition type and no filename.
+    pub fn new(disposition_type: ContentDispositionType) -> Self {
+        Self { disposition_type, filename: None }
+    }
+
+    /// Builder method to set the name of the file.
+    pub fn with_filename(mut self, filename: Option<String>) -> Self {
+        self.filename = filename;
+        self
+    }
+}
+
+impl fmt::Display for ContentDisposition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.disposition_type)?;
+
+        if let Some(filename) = &self.filename {
+            if filename.chars().all(|c| c.is_ascii() && !c.is_ascii_control()) {
+                // Use a quoted string, escaping the backslashes and double quotes.
+                f.write_str("; filename=\"")?;
+                for c in filename.chars() {
+                    if matches!(c, '\\' | '"') {
+                        f.write_str("\\")?;
+                    }
+                    write!(f, "{c}")?;
+                }
+                f.write_str("\"")?;
+            } else {
+                // Use the extended parameter syntax, that supports any UTF-8 string.
+                write!(
+                    f,
+                    "; filename*=utf-8''{}",
+                    utf8_percent_encode(filename, ATTR_CHAR_ENCODE_SET)
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ContentDisposition {
+    type Err = ContentDispositionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (disposition_type, mut params) = s.split_once(';').unwrap_or((s, ""));
+
+        let disposition_type = disposition_type.trim();
+        if disposition_type.is_empty() || !disposition_type.chars().all(is_token_char) {
+            return Err(ContentDispositionParseError::InvalidDispositionType);
+        }
+
+        let mut filename = None;
+        let mut ext_filename = None;
+
+        loop {
+            params = params.trim_start();
+            if params.is_empty() {
+                break;
+            }
+
+            let (name, rest) =
+                params.split_once('=').ok_or(ContentDispositionParseError::InvalidParameter)?;
+            let name = name.trim_end();
+            if name.is_empty() || !name.chars().all(is_token_char) {
+                return Err(ContentDispositionParseError::InvalidParameter);
+            }
+
+            let (value, rest) = parse_parameter_value(rest.trim_start())?;
+
+            if name.eq_ignore_ascii_case("filename") {
+                filename = Some(value);
+            } else if name.eq_ignore_ascii_case("filename*") {
+                // Ignore the values with an unsupported charset.
+                ext_filename = decode_ext_value(&value);
+            }
+
+            let rest = rest.trim_start();
+            params = match rest.strip_prefix(';') {
+                Some(rest) => rest,
+                None if rest.is_empty() => rest,
+                None => return Err(ContentDispositionParseError::InvalidParameter),
+            };
+        }
+
+        Ok(Self {
+            disposition_type: disposition_type.to_ascii_lowercase().into(),
+            filename: ext_filename.or(filename),
+        })
+    }
+}
+
+/// Parses the value of a parameter, which is either a token or a quoted string.
+///
+/// Returns the unescaped value and the rest of the string.
+fn parse_parameter_value(s: &str) -> Result<(String, &str), ContentDispositionParseError> {
+    let Some(quoted) = s.strip_prefix('"') else {
+        let end = s.find(|c| !is_token_char(c)).unwrap_or(s.len());
+        if end == 0 {
+            return Err(ContentDispositionParseError::InvalidParameter);
+        }
+
+        return Ok((s[..end].to_owned(), &s[end..]));
+    };
+
+    let mut value = String::new();
+    let mut chars = quoted.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((value, &quoted[i + 1..])),
+            '\\' => {
+                let (_, escaped) =
+                    chars.next().ok_or(ContentDispositionParseError::InvalidParameter)?;
+                value.push(escaped);
+            }
+            c => value.push(c),
+        }
+    }
+
+    // The closing quote is missing.
+    Err(ContentDispositionParseError::InvalidParameter)
+}
+
+/// Decodes an extended parameter value, as defined in [RFC 8187].
+///
+/// Returns `None` if the value is invalid or uses a charset other than UTF-8 or ISO-8859-1.
+///
+/// [RFC 8187]: https://datatracker.ietf.org/doc/html/rfc8187#section-3.2
+fn decode_ext_value(s: &str) -> Option<String> {
+    let (charset, rest) = s.split_once('\'')?;
+    // The language is not used.
+    let (_, value) = rest.split_once('\'')?;
+
+    let bytes = percent_decode_str(value);
+
+    if charset.eq_ignore_ascii_case("utf-8") {
+        bytes.decode_utf8().ok().map(Into::into)
+    } else if charset.eq_ignore_ascii_case("iso-8859-1") {
+        // ISO-8859-1 maps each byte to the Unicode code point with the same value.
+        Some(bytes.map(char::from).collect())
+    } else {
+        None
+    }
+}
+
+/// Whether the given char is a `tchar`, as defined in [RFC 9110].
+///
+/// [RFC 9110]: https://datatracker.ietf.org/doc/html/rfc9110#section-5.6.2
+fn is_token_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c)
+}
+
+/// The disposition type of a `Content-Disposition` HTTP header.
+///
+/// Disposition types are case-insensitive, they are converted to lowercase when a
+/// [`ContentDisposition`] is parsed.
+#[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/doc/string_enum.md"))]
+#[derive(Clone, Default, PartialEq, Eq, StringEnum)]
+#[ruma_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ContentDispositionType {
+    /// The content can be displayed inline.
+    ///
+    /// This is the default.
+    #[default]
+    Inline,
+
+    /// The content should be downloaded and saved locally.
+    Attachment,
+
+    #[doc(hidden)]
+    _Custom(PrivOwnedStr),
+}
+
+/// An error encountered when trying to parse an invalid [`ContentDisposition`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ContentDispositionParseError {
+    /// The disposition type is missing or contains invalid characters.
+    #[error("missing or invalid disposition type")]
+    InvalidDispositionType,
+
+    /// A parameter is malformed.
+    #[error("invalid parameter syntax")]
+    InvalidParameter,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContentDisposition, ContentDispositionParseError, ContentDispositionType};
+
+    #[test]
+    fn parse_disposition_type_only() {
+        let content_disposition = "inline".parse::<ContentDisposition>().unwrap();
+        assert_eq!(content_disposition.disposition_type, ContentDispositionType::Inline);
+        assert_eq!(content_disposition.filename, None);
+
+        let content_disposition = " Attachment ".parse::<ContentDisposition>().unwrap();
+        assert_eq!(content_disposition.disposition_type, ContentDispositionType::Attachment);
+        assert_eq!(content_disposition.filename, None);
+
+        let content_disposition = "x-custom".parse::<ContentDisposition>().unwrap();
+        assert_eq!(content_disposition.disposition_type.as_str(), "x-custom");
+    }
+
+    #[test]
+    fn parse_filename() {
+        let content_disposition =
+            "attachment; filename=image.png".parse::<ContentDisposition>().unwrap();
+        assert_eq!(content_disposition.disposition_type, ContentDispositionType::Attachment);
+        assert_eq!(content_disposition.filename.as_deref(), Some("image.png"));
+
+        let content_disposition =
+            r#"inline;filename="my \"cat\".png"; size=1024"#.parse::<ContentDisposition>().unwrap();
+        assert_eq!(content_disposition.disposition_type, ContentDispositionType::Inline);
+        assert_eq!(content_disposition.filename.as_deref(), Some(r#"my "cat".png"#));
+    }
+
+    #[test]
+    fn parse_ext_filename() {
+        let content_disposition = "attachment; filename*=UTF-8''%E2%82%AC%20rates.txt"
+            .parse::<ContentDisposition>()
+            .unwrap();
+        assert_eq!(content_disposition.filename.as_deref(), Some("€ rates.txt"));
+
+        let content_disposition = "attachment; filename*=iso-8859-1'en'%A3%20rates.txt"
+            .parse::<ContentDisposition>()
+            .unwrap();
+        assert_eq!(content_disposition.filename.as_deref(), Some("£ rates.txt"));
+
+        // The extended parameter is preferred, regardless of the order.
+        let content_disposition =
+            r#"attachment; filename*=utf-8''%E2%82%AC%20rates.txt; filename="EUR rates.txt""#
+                .parse::<ContentDisposition>()
+                .unwrap();
+        assert_eq!(content_disposition.filename.as_deref(), Some("€ rates.txt"));
+
+        // Unsupported charsets are ignored.
+        let content_disposition =
+            r#"attachment; filename="rates.txt"; filename*=x-unknown''%A3%20rates.txt"#
+                .parse::<ContentDisposition>()
+                .unwrap();
+        assert_eq!(content_disposition.filename.as_deref(), Some("rates.txt"));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            "".parse::<ContentDisposition>(),
+            Err(ContentDispositionParseError::InvalidDispositionType)
+        );
+        assert_eq!(
+            "; filename=image.png".parse::<ContentDisposition>(),
+            Err(ContentDispositionParseError::InvalidDispositionType)
+        );
+        assert_eq!(
+            "attachment; filename".parse::<ContentDisposition>(),
+            Err(ContentDispositionParseError::InvalidParameter)
+        );
+        assert_eq!(
+            r#"attachment; filename="image.png"#.parse::<ContentDisposition>(),
+            Err(ContentDispositionParseError::InvalidParameter)
+        );
+        assert_eq!(
+            r#"attachment; filename="image.png" size=1024"#.parse::<ContentDisposition>(),
+            Err(ContentDispositionParseError::InvalidParameter)
+        );
+    }
+
+    #[test]
+    fn display() {
+        let content_disposition = ContentDisposition::new(ContentDispositionType::Inline);
+        assert_eq!(content_disposition.to_string(), "inline");
+
+        let content_disposition = ContentDisposition::new(ContentDispositionType::Attachment)
+            .with_filename(Some(r#"my "cat".png"#.to_owned()));
+        assert_eq!(content_disposition.to_string(), r#"attachment; filename="my \"cat\".png""#);
+
+        let content_disposition = ContentDisposition::new(ContentDispositionType::Attachment)
+            .with_filename(Some("€ rates.txt".to_owned()));
+        assert_eq!(
+            content_disposition.to_string(),
+            "attachment; filename*=utf-8''%E2%82%AC%20rates.txt"
+        );
+    }
+
+    #[test]
+    fn display_parse_roundtrip() {
+        for filename in ["image.png", r#"a\b"c.txt"#, "€ rates.txt", "日本語.pdf"] {
+            let content_disposition = ContentDisposition::new(ContentDispositionType::Attachment)
+                .with_filename(Some(filename.to_owned()));
+            assert_eq!(
+                content_disposition.to_string().parse::<ContentDisposition>().unwrap(),
+                content_disposition
+            );
+        }
+    }
+}
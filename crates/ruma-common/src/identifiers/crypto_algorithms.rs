@@ -36,6 +36,12 @@ pub enum SigningKeyAlgorithm {
     _Custom(PrivOwnedStr),
 }
 
+impl From<SigningKeyAlgorithm> for DeviceKeyAlgorithm {
+    fn from(algorithm: SigningKeyAlgorithm) -> Self {
+        algorithm.as_str().into()
+    }
+}
+
 /// An encryption algorithm to be used to encrypt messages sent to a room.
 #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/doc/string_enum.md"))]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, StringEnum)]
@@ -85,6 +91,18 @@ mod tests {
         assert_eq!(SigningKeyAlgorithm::from("ed25519"), SigningKeyAlgorithm::Ed25519);
     }
 
+    #[test]
+    fn signing_key_algorithm_into_device_key_algorithm() {
+        assert_eq!(
+            DeviceKeyAlgorithm::from(SigningKeyAlgorithm::Ed25519),
+            DeviceKeyAlgorithm::Ed25519
+        );
+        assert_eq!(
+            DeviceKeyAlgorithm::from(SigningKeyAlgorithm::from("io.ruma.test")).as_str(),
+            "io.ruma.test"
+        );
+    }
+
     #[test]
     fn event_encryption_algorithm_serde() {
         use serde_json::json;
@@ -2,7 +2,7 @@
 
 use ruma_macros::IdZst;
 
-use super::ServerName;
+use super::{IdParseError, ServerName};
 
 /// A Matrix [event ID].
 ///
@@ -52,6 +52,26 @@ impl EventId {
         Self::from_borrowed(&format!("${}:{server_name}", super::generate_localpart(18))).to_owned()
     }
 
+    /// Creates an `EventId` from the given reference hash of an event.
+    ///
+    /// This should only be used for events in the format used by Matrix room versions 3 and later,
+    /// where the event ID is derived from the reference hash of the event, as returned by
+    /// `ruma_signatures::reference_hash`.
+    ///
+    /// Returns an error if the hash is empty or is not encoded as unpadded base64, with the
+    /// standard alphabet of room version 3 or the URL-safe alphabet of later room versions.
+    pub fn from_reference_hash(reference_hash: &str) -> Result<OwnedEventId, IdParseError> {
+        if reference_hash.is_empty() {
+            return Err(IdParseError::Empty);
+        }
+
+        if !reference_hash.bytes().all(|b| b.is_ascii_alphanumeric() || b"+/-_".contains(&b)) {
+            return Err(IdParseError::InvalidCharacters);
+        }
+
+        Self::parse(format!("${reference_hash}"))
+    }
+
     /// Returns the event's unique ID.
     ///
     /// For the original event format as used by Matrix room versions 1 and 2, this is the
@@ -105,6 +125,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn event_id_from_reference_hash() {
+        let event_id =
+            EventId::from_reference_hash("Rqnc-F-dvnEYJTyHq_iKxU2bZ1CI92-kuZq3a5lr5Zg").unwrap();
+        assert_eq!(event_id, "$Rqnc-F-dvnEYJTyHq_iKxU2bZ1CI92-kuZq3a5lr5Zg");
+        assert_eq!(event_id.localpart(), "Rqnc-F-dvnEYJTyHq_iKxU2bZ1CI92-kuZq3a5lr5Zg");
+        assert_eq!(event_id.server_name(), None);
+
+        let event_id =
+            EventId::from_reference_hash("acR1l0raoZnm60CBwAVgqbZqoO/mYU81xysh1u7XcJk").unwrap();
+        assert_eq!(event_id, "$acR1l0raoZnm60CBwAVgqbZqoO/mYU81xysh1u7XcJk");
+    }
+
+    #[test]
+    fn invalid_reference_hash() {
+        assert_eq!(EventId::from_reference_hash("").unwrap_err(), IdParseError::Empty);
+        assert_eq!(
+            EventId::from_reference_hash("abc:example.com").unwrap_err(),
+            IdParseError::InvalidCharacters
+        );
+        assert_eq!(
+            EventId::from_reference_hash("$abcdef").unwrap_err(),
+            IdParseError::InvalidCharacters
+        );
+        assert_eq!(
+            EventId::from_reference_hash("abcdef==").unwrap_err(),
+            IdParseError::InvalidCharacters
+        );
+    }
+
     #[cfg(feature = "rand")]
     #[test]
     fn generate_random_valid_event_id() {
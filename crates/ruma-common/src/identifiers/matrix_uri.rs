@@ -259,7 +259,7 @@ impl From<(&RoomAliasId, &EventId)> for MatrixId {
 /// in a formatting macro or via `.to_string()`).
 ///
 /// [`matrix.to` URI]: https://spec.matrix.org/latest/appendices/#matrixto-navigation
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MatrixToUri {
     id: MatrixId,
     via: Vec<OwnedServerName>,
@@ -443,7 +443,7 @@ impl From<Box<str>> for UriAction {
 /// in a formatting macro or via `.to_string()`).
 ///
 /// [`matrix:` URI]: https://spec.matrix.org/latest/appendices/#matrix-uri-scheme
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MatrixUri {
     id: MatrixId,
     via: Vec<OwnedServerName>,
@@ -538,6 +538,15 @@ impl FromStr for MatrixUri {
     }
 }
 
+/// Converts a `matrix:` URI into the equivalent `matrix.to` URI.
+///
+/// The action of the `matrix:` URI is dropped because `matrix.to` URIs can't represent it.
+impl From<MatrixUri> for MatrixToUri {
+    fn from(uri: MatrixUri) -> Self {
+        Self { id: uri.id, via: uri.via }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use assert_matches2::assert_matches;
@@ -1088,6 +1097,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn matrixuri_into_matrixtouri() {
+        let uri =
+            MatrixUri::parse("matrix:roomid/ruma:notareal.hs?via=notareal.hs&action=join").unwrap();
+        assert_eq!(
+            MatrixToUri::from(uri).to_string(),
+            "https://matrix.to/#/!ruma:notareal.hs?via=notareal.hs"
+        );
+
+        let uri = MatrixUri::parse("matrix:r/ruma:notareal.hs/e/event:notareal.hs").unwrap();
+        assert_eq!(
+            MatrixToUri::from(uri).to_string(),
+            "https://matrix.to/#/%23ruma:notareal.hs/$event:notareal.hs"
+        );
+
+        let uri = MatrixUri::parse("matrix:u/jplatte:notareal.hs?action=chat").unwrap();
+        assert_eq!(MatrixToUri::from(uri).to_string(), "https://matrix.to/#/@jplatte:notareal.hs");
+    }
+
     #[test]
     fn parse_matrixuri_wrong_identifier() {
         assert_matches!(
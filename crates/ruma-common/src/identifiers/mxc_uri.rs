@@ -19,6 +19,15 @@ type Result<T, E = MxcUriError> = std::result::Result<T, E>;
 pub struct MxcUri(str);
 
 impl MxcUri {
+    /// Constructs an MXC URI from the given server name and media ID.
+    ///
+    /// Returns an error if the media ID contains invalid characters.
+    pub fn from_parts(server_name: &ServerName, media_id: &str) -> Result<OwnedMxcUri> {
+        let uri = format!("mxc://{server_name}/{media_id}");
+        validate(&uri)?;
+        Ok(uri.into())
+    }
+
     /// If this is a valid MXC URI, returns the media ID.
     pub fn media_id(&self) -> Result<&str> {
         self.parts().map(|(_, s)| s)
@@ -62,7 +71,7 @@ impl MxcUri {
 mod tests {
     use ruma_identifiers_validation::error::MxcUriError;
 
-    use super::{MxcUri, OwnedMxcUri};
+    use super::{MxcUri, OwnedMxcUri, ServerName};
 
     #[test]
     fn parse_mxc_uri() {
@@ -88,6 +97,20 @@ mod tests {
         assert!(!Box::<MxcUri>::from("127.0.0.1/asd32asdfasdsd").is_valid());
     }
 
+    #[test]
+    fn mxc_uri_from_parts() {
+        let server_name = <&ServerName>::try_from("example.org").unwrap();
+
+        let mxc = MxcUri::from_parts(server_name, "asd32asdfasdsd").unwrap();
+        assert_eq!(mxc, "mxc://example.org/asd32asdfasdsd");
+        assert_eq!(mxc.parts(), Ok((server_name, "asd32asdfasdsd")));
+
+        assert_eq!(
+            MxcUri::from_parts(server_name, "asd/asd").unwrap_err(),
+            MxcUriError::MediaIdMalformed
+        );
+    }
+
     #[test]
     fn serialize_mxc_uri() {
         assert_eq!(
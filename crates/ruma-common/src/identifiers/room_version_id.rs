@@ -6,6 +6,7 @@ use ruma_macros::DisplayAsRefStr;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::IdParseError;
+use crate::room_version_rules::RoomVersionRules;
 
 /// A Matrix [room version] ID.
 ///
@@ -88,6 +89,38 @@ impl RoomVersionId {
     pub fn as_bytes(&self) -> &[u8] {
         self.as_str().as_bytes()
     }
+
+    /// Whether this is a custom room version, i.e. one that is not known to this version of Ruma.
+    ///
+    /// This is the case for experimental room versions and for room versions that were introduced
+    /// into the specification after this code was written.
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::_Custom(_))
+    }
+
+    /// Get the rules of this room version.
+    ///
+    /// Returns `None` if the rules of this room version are not known to this version of Ruma.
+    pub fn rules(&self) -> Option<RoomVersionRules> {
+        Some(match self {
+            Self::V1 => RoomVersionRules::V1,
+            Self::V2 => RoomVersionRules::V2,
+            Self::V3 => RoomVersionRules::V3,
+            Self::V4 => RoomVersionRules::V4,
+            Self::V5 => RoomVersionRules::V5,
+            Self::V6 => RoomVersionRules::V6,
+            Self::V7 => RoomVersionRules::V7,
+            Self::V8 => RoomVersionRules::V8,
+            Self::V9 => RoomVersionRules::V9,
+            Self::V10 => RoomVersionRules::V10,
+            Self::V11 => RoomVersionRules::V11,
+            #[cfg(feature = "unstable-msc2870")]
+            Self::_Custom(version) if version.as_str() == "org.matrix.msc2870" => {
+                RoomVersionRules::MSC2870
+            }
+            Self::_Custom(_) => return None,
+        })
+    }
 }
 
 impl From<RoomVersionId> for String {
@@ -317,10 +350,11 @@ mod tests {
 
     #[test]
     fn valid_custom_room_version_id() {
-        assert_eq!(
-            RoomVersionId::try_from("io.ruma.1").expect("Failed to create RoomVersionId.").as_str(),
-            "io.ruma.1"
-        );
+        let room_version_id =
+            RoomVersionId::try_from("io.ruma.1").expect("Failed to create RoomVersionId.");
+        assert_eq!(room_version_id.as_str(), "io.ruma.1");
+        assert!(room_version_id.is_custom());
+        assert!(!RoomVersionId::V11.is_custom());
     }
 
     #[test]
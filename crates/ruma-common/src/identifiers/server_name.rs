@@ -1,6 +1,6 @@
 //! Matrix-spec compliant server names.
 
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr};
 
 use ruma_macros::IdZst;
 
@@ -49,10 +49,21 @@ impl ServerName {
     pub fn is_ip_literal(&self) -> bool {
         self.host().parse::<Ipv4Addr>().is_ok() || self.0.starts_with('[')
     }
+
+    /// Returns the IP address of the server name, if it is an IPv4 or IPv6 literal.
+    ///
+    /// For IPv6 addresses, the surrounding brackets are removed before parsing.
+    pub fn ip_addr(&self) -> Option<IpAddr> {
+        let host = self.host();
+        let host = host.strip_prefix('[').and_then(|h| h.strip_suffix(']')).unwrap_or(host);
+        host.parse().ok()
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
     use super::ServerName;
 
     #[test]
@@ -123,6 +134,7 @@ mod tests {
         let server_name = <&ServerName>::try_from("1.1.1.1:12000").unwrap();
         assert!(server_name.is_ip_literal());
         assert_eq!(server_name.host(), "1.1.1.1");
+        assert_eq!(server_name.ip_addr(), Some(IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1))));
     }
 
     #[test]
@@ -130,6 +142,7 @@ mod tests {
         let server_name = <&ServerName>::try_from("[::1]").unwrap();
         assert!(server_name.is_ip_literal());
         assert_eq!(server_name.host(), "[::1]");
+        assert_eq!(server_name.ip_addr(), Some(IpAddr::V6(Ipv6Addr::LOCALHOST)));
     }
 
     #[test]
@@ -151,5 +164,6 @@ mod tests {
         let server_name = <&ServerName>::try_from("ruma.io:8080").unwrap();
         assert!(!server_name.is_ip_literal());
         assert_eq!(server_name.host(), "ruma.io");
+        assert_eq!(server_name.ip_addr(), None);
     }
 }
@@ -8,12 +8,27 @@ use super::IdParseError;
 ///
 /// Session IDs in Matrix are opaque character sequences of `[0-9a-zA-Z.=_-]`. Their length must
 /// must not exceed 255 characters.
+///
+/// You can create one from a string (using `SessionId::parse()`) or generate a random one with
+/// `SessionId::new()`. If that function is not available for you, you need to activate this
+/// crate's `rand` Cargo feature.
 #[repr(transparent)]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, IdZst)]
 #[ruma_id(validate = validate_session_id)]
 pub struct SessionId(str);
 
 impl SessionId {
+    /// Creates a random session ID.
+    ///
+    /// This will currently be a UUID without hyphens, but no guarantees are made about the
+    /// structure of session IDs generated from this function.
+    #[cfg(feature = "rand")]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> OwnedSessionId {
+        let id = uuid::Uuid::new_v4();
+        Self::from_borrowed(&id.simple().to_string()).to_owned()
+    }
+
     #[doc(hidden)]
     pub const fn _priv_const_new(s: &str) -> Result<&Self, &'static str> {
         match validate_session_id(s) {
@@ -58,3 +73,16 @@ const fn contains_invalid_byte(mut bytes: &[u8]) -> bool {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_session_id() {
+        use super::SessionId;
+
+        let session_id = SessionId::new();
+        assert_eq!(session_id.as_str().len(), 32);
+        assert!(SessionId::parse(session_id.as_str()).is_ok());
+    }
+}
@@ -24,3 +24,26 @@ impl TransactionId {
         Self::from_borrowed(&id.simple().to_string()).to_owned()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{OwnedTransactionId, TransactionId};
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_transaction_id() {
+        let txn_id = TransactionId::new();
+        assert_eq!(txn_id.as_str().len(), 32);
+        assert!(txn_id.as_str().chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(txn_id, TransactionId::new());
+    }
+
+    #[test]
+    fn create_transaction_id_from_str() {
+        let ref_id: &TransactionId = "m1234.5".into();
+        assert_eq!(ref_id.as_str(), "m1234.5");
+
+        let owned_id: OwnedTransactionId = "m1234.5".into();
+        assert_eq!(owned_id, ref_id);
+    }
+}
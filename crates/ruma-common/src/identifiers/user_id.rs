@@ -107,6 +107,19 @@ impl UserId {
         !localpart_is_fully_conforming(self.localpart()).unwrap()
     }
 
+    /// Validates this user ID against the strict grammar of the latest specification.
+    ///
+    /// Returns an error if this is a [historical](Self::is_historical) user ID. This should be
+    /// used by homeservers when registering new users, since historical user IDs are only accepted
+    /// for compatibility with existing users.
+    pub fn validate_strict(&self) -> Result<(), IdParseError> {
+        if localpart_is_fully_conforming(self.localpart())? {
+            Ok(())
+        } else {
+            Err(IdParseError::InvalidCharacters)
+        }
+    }
+
     /// Create a `matrix.to` URI for this user ID.
     ///
     /// # Example
@@ -188,6 +201,23 @@ mod tests {
         assert!(!user_id.is_historical());
     }
 
+    #[test]
+    fn validate_strict_user_id() {
+        let user_id = <&UserId>::try_from("@carl:example.com").expect("Failed to create UserId.");
+        assert_eq!(user_id.validate_strict(), Ok(()));
+
+        let user_id = <&UserId>::try_from("@Carl:example.com").expect("Failed to create UserId.");
+        assert_eq!(user_id.validate_strict(), Err(IdParseError::InvalidCharacters));
+    }
+
+    #[test]
+    fn owned_user_id_rc_roundtrip() {
+        let user_id = UserId::parse_rc("@carl:example.com").expect("Failed to create UserId.");
+        let owned = OwnedUserId::from(user_id.clone());
+        assert_eq!(owned, "@carl:example.com");
+        assert_eq!(std::rc::Rc::<UserId>::from(owned), user_id);
+    }
+
     #[cfg(not(feature = "compat-user-id"))]
     #[test]
     fn invalid_user_id() {
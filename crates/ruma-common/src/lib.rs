@@ -24,12 +24,14 @@ pub mod authentication;
 pub mod canonical_json;
 pub mod directory;
 pub mod encryption;
+pub mod http_headers;
 mod identifiers;
 mod percent_encode;
 pub mod power_levels;
 pub mod presence;
 pub mod push;
 pub mod room;
+pub mod room_version_rules;
 pub mod serde;
 pub mod space;
 pub mod thirdparty;
@@ -1,5 +1,8 @@
 //! Common types for the [presence module][presence].
 //!
+//! They are shared by the `m.presence` event, the presence endpoints of the client-server API and
+//! the presence EDU of the server-server API.
+//!
 //! [presence]: https://spec.matrix.org/latest/client-server-api/#presence
 
 use crate::{serde::StringEnum, PrivOwnedStr};
@@ -29,3 +32,35 @@ impl Default for &'_ PresenceState {
         &PresenceState::Online
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::PresenceState;
+
+    #[test]
+    fn presence_state_serde() {
+        for (state, s) in [
+            (PresenceState::Offline, "offline"),
+            (PresenceState::Online, "online"),
+            (PresenceState::Unavailable, "unavailable"),
+        ] {
+            assert_eq!(to_json_value(&state).unwrap(), json!(s));
+            assert_eq!(from_json_value::<PresenceState>(json!(s)).unwrap(), state);
+        }
+    }
+
+    #[test]
+    fn custom_presence_state() {
+        let state = from_json_value::<PresenceState>(json!("org.example.busy")).unwrap();
+        assert_eq!(state.as_str(), "org.example.busy");
+        assert_eq!(to_json_value(&state).unwrap(), json!("org.example.busy"));
+    }
+
+    #[test]
+    fn default_presence_state() {
+        assert_eq!(PresenceState::default(), PresenceState::Online);
+        assert_eq!(<&PresenceState>::default(), &PresenceState::Online);
+    }
+}
@@ -55,8 +55,12 @@ impl RoomVersionFeature {
             | RoomVersionId::V8
             | RoomVersionId::V9
             | RoomVersionId::V10
-            | RoomVersionId::V11
-            | RoomVersionId::_Custom(_) => vec![],
+            | RoomVersionId::V11 => vec![],
+            #[cfg(feature = "unstable-msc3932")]
+            RoomVersionId::_Custom(_) if version.as_str() == "org.matrix.msc1767.10" => {
+                vec![Self::ExtensibleEvents]
+            }
+            RoomVersionId::_Custom(_) => vec![],
         }
     }
 }
@@ -165,6 +169,11 @@ impl PushCondition {
         match self {
             Self::EventMatch { key, pattern } => check_event_match(event, key, pattern, context),
             Self::ContainsDisplayName => {
+                // An empty display name would match an empty body.
+                if context.user_display_name.is_empty() {
+                    return false;
+                }
+
                 let value = match event.get_str("content.body") {
                     Some(v) => v,
                     None => return false,
@@ -195,12 +204,7 @@ impl PushCondition {
                 }
             }
             #[cfg(feature = "unstable-msc3931")]
-            Self::RoomVersionSupports { feature } => match feature {
-                RoomVersionFeature::ExtensibleEvents => {
-                    context.supported_features.contains(&RoomVersionFeature::ExtensibleEvents)
-                }
-                RoomVersionFeature::_Custom(_) => false,
-            },
+            Self::RoomVersionSupports { feature } => context.supported_features.contains(feature),
             Self::EventPropertyIs { key, value } => event.get(key).is_some_and(|v| v == value),
             Self::EventPropertyContains { key, value } => event
                 .get(key)
@@ -773,6 +777,29 @@ mod tests {
         assert!(!contains_display_name.applies(&second_event, &context));
     }
 
+    #[test]
+    fn contains_display_name_empty() {
+        let mut context = push_context();
+        context.user_display_name = String::new();
+
+        let raw = serde_json::from_str::<Raw<JsonValue>>(
+            r#"{
+                "sender": "@worthy_whale:server.name",
+                "content": {
+                    "msgtype": "m.text",
+                    "body": ""
+                }
+            }"#,
+        )
+        .unwrap();
+        let empty_body_event = FlattenedJson::from_raw(&raw);
+
+        let contains_display_name = PushCondition::ContainsDisplayName;
+
+        assert!(!contains_display_name.applies(&empty_body_event, &context));
+        assert!(!contains_display_name.applies(&first_flattened_event(), &context));
+    }
+
     #[test]
     fn sender_notification_permission_applies() {
         let context = push_context();
@@ -820,6 +847,45 @@ mod tests {
         assert!(!room_version_condition.applies(&simple_event, &context_not_matching));
     }
 
+    #[cfg(feature = "unstable-msc3931")]
+    #[test]
+    fn room_version_supports_custom_feature_applies() {
+        let mut context = push_context();
+        let event_raw = serde_json::from_str::<Raw<JsonValue>>(
+            r#"{
+                "sender": "@worthy_whale:server.name",
+                "content": {
+                    "msgtype": "m.text",
+                    "body": "Boom!"
+                }
+            }"#,
+        )
+        .unwrap();
+        let event = FlattenedJson::from_raw(&event_raw);
+
+        let room_version_condition = PushCondition::RoomVersionSupports {
+            feature: super::RoomVersionFeature::from("org.example.feature"),
+        };
+        assert!(!room_version_condition.applies(&event, &context));
+
+        context.supported_features = vec![super::RoomVersionFeature::from("org.example.feature")];
+        assert!(room_version_condition.applies(&event, &context));
+    }
+
+    #[cfg(feature = "unstable-msc3932")]
+    #[test]
+    fn room_version_features() {
+        use crate::RoomVersionId;
+
+        assert_eq!(super::RoomVersionFeature::list_for_room_version(&RoomVersionId::V11), vec![]);
+        assert_eq!(
+            super::RoomVersionFeature::list_for_room_version(
+                &RoomVersionId::try_from("org.matrix.msc1767.10").unwrap()
+            ),
+            vec![super::RoomVersionFeature::ExtensibleEvents]
+        );
+    }
+
     #[test]
     fn event_property_is_applies() {
         use crate::push::condition::ScalarJsonValue;
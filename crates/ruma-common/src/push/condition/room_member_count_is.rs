@@ -67,7 +67,7 @@ pub struct RoomMemberCountIs {
 }
 
 impl RoomMemberCountIs {
-    /// Creates an instance of `RoomMemberCount` equivalent to `<X`,
+    /// Creates an instance of `RoomMemberCount` equivalent to `>X`,
     /// where X is the specified member count.
     pub fn gt(count: UInt) -> Self {
         RoomMemberCountIs { prefix: ComparisonOperator::Gt, count }
@@ -183,7 +183,7 @@ mod tests {
 
     use js_int::uint;
 
-    use super::RoomMemberCountIs;
+    use super::{ComparisonOperator, RoomMemberCountIs};
 
     #[test]
     fn eq_range_contains_its_own_count() {
@@ -208,4 +208,33 @@ mod tests {
 
         assert!(!range.contains(&initial_point));
     }
+
+    #[test]
+    fn parse_prefixes() {
+        for (s, prefix) in [
+            ("3", ComparisonOperator::Eq),
+            ("==3", ComparisonOperator::Eq),
+            ("<3", ComparisonOperator::Lt),
+            (">3", ComparisonOperator::Gt),
+            (">=3", ComparisonOperator::Ge),
+            ("<=3", ComparisonOperator::Le),
+        ] {
+            let is = s.parse::<RoomMemberCountIs>().unwrap();
+            assert_eq!(is, RoomMemberCountIs { prefix, count: uint!(3) }, "{s}");
+        }
+
+        "=3".parse::<RoomMemberCountIs>().unwrap_err();
+        "<".parse::<RoomMemberCountIs>().unwrap_err();
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let is = serde_json::from_str::<RoomMemberCountIs>(r#""<=10""#).unwrap();
+        assert_eq!(is, RoomMemberCountIs::from(..=uint!(10)));
+        assert_eq!(serde_json::to_string(&is).unwrap(), r#""<=10""#);
+
+        // `==` is the default and is omitted.
+        let is = serde_json::from_str::<RoomMemberCountIs>(r#""==2""#).unwrap();
+        assert_eq!(serde_json::to_string(&is).unwrap(), r#""2""#);
+    }
 }
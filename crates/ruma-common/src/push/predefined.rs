@@ -13,6 +13,9 @@ use crate::{PrivOwnedStr, UserId};
 impl Ruleset {
     /// The list of all [predefined push rules].
     ///
+    /// The rules of each kind are in the order of priority defined in the specification, followed
+    /// by the unstable rules enabled with cargo features.
+    ///
     /// # Parameters
     ///
     /// - `user_id`: the user for which to generate the default rules. Some rules depend on the
@@ -747,6 +750,48 @@ mod tests {
         user_id,
     };
 
+    #[test]
+    fn server_default_rules_order() {
+        let ruleset = Ruleset::server_default(user_id!("@user:localhost"));
+
+        // Unstable rules are added at the end.
+        let override_ids = ruleset.override_.iter().map(|rule| rule.rule_id.as_str());
+        assert_eq!(
+            override_ids.take(12).collect::<Vec<_>>(),
+            [
+                ".m.rule.master",
+                ".m.rule.suppress_notices",
+                ".m.rule.invite_for_me",
+                ".m.rule.member_event",
+                ".m.rule.is_user_mention",
+                ".m.rule.contains_display_name",
+                ".m.rule.is_room_mention",
+                ".m.rule.roomnotif",
+                ".m.rule.tombstone",
+                ".m.rule.reaction",
+                ".m.rule.room.server_acl",
+                ".m.rule.suppress_edits",
+            ]
+        );
+
+        let content_ids = ruleset.content.iter().map(|rule| rule.rule_id.as_str());
+        assert_eq!(content_ids.collect::<Vec<_>>(), [".m.rule.contains_user_name"]);
+
+        let underride_ids = ruleset.underride.iter().map(|rule| rule.rule_id.as_str());
+        assert_eq!(
+            underride_ids.take(5).collect::<Vec<_>>(),
+            [
+                ".m.rule.call",
+                ".m.rule.encrypted_room_one_to_one",
+                ".m.rule.room_one_to_one",
+                ".m.rule.message",
+                ".m.rule.encrypted",
+            ]
+        );
+
+        assert!(ruleset.iter().all(|rule| rule.is_server_default()));
+    }
+
     #[test]
     fn update_with_server_default() {
         let user_rule_id = "user_always_true";
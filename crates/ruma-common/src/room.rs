@@ -15,3 +15,19 @@ pub enum RoomType {
     #[doc(hidden)]
     _Custom(PrivOwnedStr),
 }
+
+/// The stability of a room version.
+#[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/doc/string_enum.md"))]
+#[derive(Clone, PartialEq, Eq, StringEnum)]
+#[ruma_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RoomVersionStability {
+    /// Support for the given version is stable.
+    Stable,
+
+    /// Support for the given version is unstable.
+    Unstable,
+
+    #[doc(hidden)]
+    _Custom(PrivOwnedStr),
+}
@@ -0,0 +1,338 @@
+//! The rules of the different room versions.
+//!
+//! These rules are the single source of truth for the behavioral differences between room
+//! versions, used for example during event authorization, state resolution, redaction and
+//! signature verification.
+
+use crate::room::RoomVersionStability;
+
+/// The stability of a room version.
+///
+/// Can be converted to a [`RoomVersionStability`] to advertise the room version, for example in
+/// the `m.room_versions` capability.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum RoomDisposition {
+    /// A room version that has a stable specification.
+    Stable,
+    /// A room version that is not yet fully specified.
+    Unstable,
+}
+
+impl From<RoomDisposition> for RoomVersionStability {
+    fn from(disposition: RoomDisposition) -> Self {
+        match disposition {
+            RoomDisposition::Stable => RoomVersionStability::Stable,
+            RoomDisposition::Unstable => RoomVersionStability::Unstable,
+        }
+    }
+}
+
+/// The format of the event IDs of a room version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub enum EventFormatVersion {
+    /// $id:server event id format
+    V1,
+    /// MSC1659-style $hash event id format: introduced for room v3
+    V2,
+    /// MSC1884-style $hash format: introduced for room v4
+    V3,
+}
+
+/// The version of the state resolution algorithm of a room version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub enum StateResolutionVersion {
+    /// State resolution for rooms at version 1.
+    V1,
+    /// State resolution for room at version 2 or later.
+    V2,
+}
+
+/// The behavioral differences between room versions.
+///
+/// Use [`RoomVersionId::rules()`](crate::RoomVersionId::rules) to get the rules of a room version
+/// from its ID, or one of the associated constants.
+///
+/// The flags that differ between the latest room versions are:
+///
+/// * Room version 9 has the same rules as room version 8, it only fixes the redaction algorithm for
+///   `m.room.member` events.
+/// * Room version 10 adds the `knock_restricted` join rule and enforces integer power levels.
+/// * Room version 11 uses the `sender` of the `m.room.create` event to determine the room creator,
+///   and updates the redaction algorithm.
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct RoomVersionRules {
+    /// The stability of this room.
+    pub disposition: RoomDisposition,
+    /// The format of the EventId.
+    pub event_format: EventFormatVersion,
+    /// Which state resolution algorithm is used.
+    pub state_res: StateResolutionVersion,
+    /// Enforce the `valid_until_ts` of the signing keys of servers when verifying the signatures
+    /// of events.
+    ///
+    /// See: [MSC2076](https://github.com/matrix-org/matrix-spec-proposals/pull/2076) for more information.
+    pub enforce_key_validity: bool,
+
+    /// `m.room.aliases` had special auth rules and redaction rules
+    /// before room version 6.
+    ///
+    /// before MSC2261/MSC2432,
+    pub special_case_aliases_auth: bool,
+    /// Strictly enforce canonical json, do not allow:
+    /// * Integers outside the range of [-2 ^ 53 + 1, 2 ^ 53 - 1]
+    /// * Floats
+    /// * NaN, Infinity, -Infinity
+    pub strict_canonicaljson: bool,
+    /// Verify notifications key while checking m.room.power_levels.
+    ///
+    /// bool: MSC2209: Check 'notifications'
+    pub limit_notifications_power_levels: bool,
+    /// Extra rules when verifying redaction events.
+    pub extra_redaction_checks: bool,
+    /// Allow knocking in event authentication.
+    ///
+    /// See [room v7 specification](https://spec.matrix.org/latest/rooms/v7/) for more information.
+    pub allow_knocking: bool,
+    /// Adds support for the restricted join rule.
+    ///
+    /// A signature of the server of the user in the `join_authorised_via_users_server` field of
+    /// an `m.room.member` event is also required.
+    ///
+    /// See: [MSC3289](https://github.com/matrix-org/matrix-spec-proposals/pull/3289) for more information.
+    pub restricted_join_rules: bool,
+    /// Adds support for the knock_restricted join rule.
+    ///
+    /// See: [MSC3787](https://github.com/matrix-org/matrix-spec-proposals/pull/3787) for more information.
+    pub knock_restricted_join_rule: bool,
+    /// Enforces integer power levels.
+    ///
+    /// See: [MSC3667](https://github.com/matrix-org/matrix-spec-proposals/pull/3667) for more information.
+    pub integer_power_levels: bool,
+    /// Determine the room creator using the `m.room.create` event's `sender`,
+    /// instead of the event content's `creator` field.
+    ///
+    /// See: [MSC2175](https://github.com/matrix-org/matrix-spec-proposals/pull/2175) for more information.
+    pub use_room_create_sender: bool,
+    /// The rules of the redaction algorithm.
+    pub redaction: RedactionRules,
+}
+
+impl RoomVersionRules {
+    /// The rules of room version 1.
+    pub const V1: Self = Self {
+        disposition: RoomDisposition::Stable,
+        event_format: EventFormatVersion::V1,
+        state_res: StateResolutionVersion::V1,
+        enforce_key_validity: false,
+        special_case_aliases_auth: true,
+        strict_canonicaljson: false,
+        limit_notifications_power_levels: false,
+        extra_redaction_checks: false,
+        allow_knocking: false,
+        restricted_join_rules: false,
+        knock_restricted_join_rule: false,
+        integer_power_levels: false,
+        use_room_create_sender: false,
+        redaction: RedactionRules::V1,
+    };
+
+    /// The rules of room version 2.
+    pub const V2: Self = Self { state_res: StateResolutionVersion::V2, ..Self::V1 };
+
+    /// The rules of room version 3.
+    pub const V3: Self =
+        Self { event_format: EventFormatVersion::V2, extra_redaction_checks: true, ..Self::V2 };
+
+    /// The rules of room version 4.
+    pub const V4: Self = Self { event_format: EventFormatVersion::V3, ..Self::V3 };
+
+    /// The rules of room version 5.
+    pub const V5: Self = Self { enforce_key_validity: true, ..Self::V4 };
+
+    /// The rules of room version 6.
+    pub const V6: Self = Self {
+        special_case_aliases_auth: false,
+        strict_canonicaljson: true,
+        limit_notifications_power_levels: true,
+        redaction: RedactionRules::V6,
+        ..Self::V5
+    };
+
+    /// The rules of room version 7.
+    pub const V7: Self = Self { allow_knocking: true, ..Self::V6 };
+
+    /// The rules of room version 8.
+    pub const V8: Self =
+        Self { restricted_join_rules: true, redaction: RedactionRules::V8, ..Self::V7 };
+
+    /// The rules of room version 9.
+    pub const V9: Self = Self { redaction: RedactionRules::V9, ..Self::V8 };
+
+    /// The rules of room version 10.
+    pub const V10: Self =
+        Self { knock_restricted_join_rule: true, integer_power_levels: true, ..Self::V9 };
+
+    /// The rules of room version 11.
+    pub const V11: Self =
+        Self { use_room_create_sender: true, redaction: RedactionRules::V11, ..Self::V10 };
+
+    /// The rules of the unstable room version for [MSC2870].
+    ///
+    /// [MSC2870]: https://github.com/matrix-org/matrix-spec-proposals/pull/2870
+    #[cfg(feature = "unstable-msc2870")]
+    pub const MSC2870: Self = Self {
+        disposition: RoomDisposition::Unstable,
+        redaction: RedactionRules::MSC2870,
+        ..Self::V11
+    };
+}
+
+/// The differences of the redaction algorithm between room versions.
+///
+/// The fields that are always kept during redaction are not listed here.
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct RedactionRules {
+    /// Keep the `origin`, `membership` and `prev_state` top-level fields of events.
+    ///
+    /// Removed in room version 11.
+    pub keep_origin_membership_prev_state: bool,
+    /// Keep the `aliases` field of the content of `m.room.aliases` events.
+    ///
+    /// Removed in room version 6.
+    pub keep_room_aliases_aliases: bool,
+    /// Keep the `allow` field of the content of `m.room.join_rules` events.
+    ///
+    /// Added in room version 8.
+    pub keep_room_join_rules_allow: bool,
+    /// Keep the `join_authorised_via_users_server` field of the content of `m.room.member`
+    /// events.
+    ///
+    /// Added in room version 9.
+    pub keep_room_member_join_authorised_via_users_server: bool,
+    /// Keep the `signed` field of the `third_party_invite` of the content of `m.room.member`
+    /// events.
+    ///
+    /// Added in room version 11.
+    pub keep_room_member_third_party_invite_signed: bool,
+    /// Keep the whole content of `m.room.create` events, instead of only the `creator` field.
+    ///
+    /// Added in room version 11.
+    pub keep_room_create_content: bool,
+    /// Keep the `invite` field of the content of `m.room.power_levels` events.
+    ///
+    /// Added in room version 11.
+    pub keep_room_power_levels_invite: bool,
+    /// Keep the `redacts` field of the content of `m.room.redaction` events.
+    ///
+    /// Added in room version 11.
+    pub keep_room_redaction_redacts: bool,
+    /// Keep the `allow`, `deny` and `allow_ip_literals` fields of the content of
+    /// `m.room.server_acl` events.
+    ///
+    /// See: [MSC2870](https://github.com/matrix-org/matrix-spec-proposals/pull/2870) for more information.
+    #[cfg(feature = "unstable-msc2870")]
+    pub keep_room_server_acl_allow_deny_allow_ip_literals: bool,
+}
+
+impl RedactionRules {
+    /// The redaction rules of room versions 1 through 5.
+    pub const V1: Self = Self {
+        keep_origin_membership_prev_state: true,
+        keep_room_aliases_aliases: true,
+        keep_room_join_rules_allow: false,
+        keep_room_member_join_authorised_via_users_server: false,
+        keep_room_member_third_party_invite_signed: false,
+        keep_room_create_content: false,
+        keep_room_power_levels_invite: false,
+        keep_room_redaction_redacts: false,
+        #[cfg(feature = "unstable-msc2870")]
+        keep_room_server_acl_allow_deny_allow_ip_literals: false,
+    };
+
+    /// The redaction rules of room versions 6 and 7.
+    pub const V6: Self = Self { keep_room_aliases_aliases: false, ..Self::V1 };
+
+    /// The redaction rules of room version 8.
+    pub const V8: Self = Self { keep_room_join_rules_allow: true, ..Self::V6 };
+
+    /// The redaction rules of room versions 9 and 10.
+    pub const V9: Self =
+        Self { keep_room_member_join_authorised_via_users_server: true, ..Self::V8 };
+
+    /// The redaction rules of room version 11.
+    pub const V11: Self = Self {
+        keep_origin_membership_prev_state: false,
+        keep_room_member_third_party_invite_signed: true,
+        keep_room_create_content: true,
+        keep_room_power_levels_invite: true,
+        keep_room_redaction_redacts: true,
+        ..Self::V9
+    };
+
+    /// The redaction rules of the unstable room version for [MSC2870].
+    ///
+    /// [MSC2870]: https://github.com/matrix-org/matrix-spec-proposals/pull/2870
+    #[cfg(feature = "unstable-msc2870")]
+    pub const MSC2870: Self =
+        Self { keep_room_server_acl_allow_deny_allow_ip_literals: true, ..Self::V11 };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StateResolutionVersion;
+    use crate::RoomVersionId;
+
+    #[test]
+    fn latest_room_versions_rules() {
+        let v8 = RoomVersionId::V8.rules().unwrap();
+        let v9 = RoomVersionId::V9.rules().unwrap();
+        let v10 = RoomVersionId::V10.rules().unwrap();
+        let v11 = RoomVersionId::V11.rules().unwrap();
+
+        for rules in [&v8, &v9, &v10, &v11] {
+            assert_eq!(rules.state_res, StateResolutionVersion::V2);
+            assert!(rules.allow_knocking);
+            assert!(rules.restricted_join_rules);
+            assert!(!rules.special_case_aliases_auth);
+            assert!(rules.redaction.keep_room_join_rules_allow);
+        }
+
+        assert!(!v8.redaction.keep_room_member_join_authorised_via_users_server);
+
+        assert!(!v9.knock_restricted_join_rule);
+        assert!(!v9.integer_power_levels);
+        assert!(!v9.use_room_create_sender);
+        assert!(v9.redaction.keep_room_member_join_authorised_via_users_server);
+        assert!(v9.redaction.keep_origin_membership_prev_state);
+
+        assert!(v10.knock_restricted_join_rule);
+        assert!(v10.integer_power_levels);
+        assert!(!v10.use_room_create_sender);
+        assert!(!v10.redaction.keep_room_create_content);
+
+        assert!(v11.knock_restricted_join_rule);
+        assert!(v11.integer_power_levels);
+        assert!(v11.use_room_create_sender);
+        assert!(!v11.redaction.keep_origin_membership_prev_state);
+        assert!(v11.redaction.keep_room_create_content);
+        assert!(v11.redaction.keep_room_power_levels_invite);
+        assert!(v11.redaction.keep_room_redaction_redacts);
+    }
+
+    #[test]
+    fn unsupported_room_version() {
+        let version = RoomVersionId::try_from("io.ruma.1").unwrap();
+        assert!(version.rules().is_none());
+    }
+
+    #[test]
+    fn aliases_redaction_rules() {
+        assert!(RoomVersionId::V5.rules().unwrap().redaction.keep_room_aliases_aliases);
+        assert!(!RoomVersionId::V6.rules().unwrap().redaction.keep_room_aliases_aliases);
+    }
+}
@@ -1,8 +1,10 @@
 //! (De)serialization helpers for other Ruma crates.
 //!
-//! Part of that is a fork of [serde_urlencoded], with support for sequences in `Deserialize` /
-//! `Serialize` structs (e.g. `Vec<Something>`) that are (de)serialized as `field=val1&field=val2`.
+//! Query strings are (de)serialized with [serde_html_form], a fork of [serde_urlencoded] with
+//! support for sequences in `Deserialize` / `Serialize` structs (e.g. `Vec<Something>`) that are
+//! (de)serialized as `field=val1&field=val2`.
 //!
+//! [serde_html_form]: https://docs.rs/serde_html_form
 //! [serde_urlencoded]: https://github.com/nox/serde_urlencoded
 
 use serde::{de, Deserialize, Deserializer};
@@ -23,7 +25,7 @@ pub use self::{
     base64::{Base64, Base64DecodeError},
     buf::{json_to_buf, slice_to_buf},
     can_be_empty::{is_empty, CanBeEmpty},
-    cow::deserialize_cow_str,
+    cow::{deserialize_cow_str, deserialize_optional_cow_str},
     raw::Raw,
     strings::{
         btreemap_deserialize_v1_powerlevel_values, deserialize_as_f64_or_string,
@@ -121,6 +121,21 @@ impl<'de, C: Base64Config> Deserialize<'de> for Base64<C> {
     }
 }
 
+impl<'de, C: Base64Config, const N: usize> Deserialize<'de> for Base64<C, [u8; N]> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoded = super::deserialize_cow_str(deserializer)?;
+        let bytes = Base64::<C>::parse(&*encoded).map_err(de::Error::custom)?.into_inner();
+        let bytes = bytes.try_into().map_err(|bytes: Vec<u8>| {
+            de::Error::invalid_length(bytes.len(), &format!("{N} bytes").as_str())
+        })?;
+
+        Ok(Self::new(bytes))
+    }
+}
+
 impl<C: Base64Config, B: AsRef<[u8]>> Serialize for Base64<C, B> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -162,4 +177,14 @@ mod tests {
         Base64::<Standard>::parse(INPUT_WITH_PADDING)
             .expect("We should be able to decode padded Base64");
     }
+
+    #[test]
+    fn deserialize_fixed_size_base64() {
+        let hash: Base64<Standard, [u8; 32]> =
+            serde_json::from_str(r#""5jM4wQpv6lnBo7CLIghJuHdW+s2CMBJPUOGOC89ncos""#).unwrap();
+        assert_eq!(hash.encode(), "5jM4wQpv6lnBo7CLIghJuHdW+s2CMBJPUOGOC89ncos");
+
+        serde_json::from_str::<Base64<Standard, [u8; 32]>>(r#""im9+knCkMNQNh9o6sbdcZw""#)
+            .unwrap_err();
+    }
 }
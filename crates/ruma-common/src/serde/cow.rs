@@ -16,6 +16,22 @@ where
     deserializer.deserialize_string(CowStrVisitor)
 }
 
+/// Deserialize an `Option<Cow<'de, str>>`.
+///
+/// Like [`deserialize_cow_str`], this borrows from the input when possible, which serde's
+/// implementation of `Deserialize` for `Option<Cow<'de, str>>` doesn't do even with
+/// `#[serde(borrow)]`.
+///
+/// To allow the field to be missing, use it with `#[serde(default)]`.
+pub fn deserialize_optional_cow_str<'de, D>(
+    deserializer: D,
+) -> Result<Option<Cow<'de, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_option(OptionalCowStrVisitor)
+}
+
 struct CowStrVisitor;
 
 impl<'de> Visitor<'de> for CowStrVisitor {
@@ -76,3 +92,34 @@ impl<'de> Visitor<'de> for CowStrVisitor {
         }
     }
 }
+
+struct OptionalCowStrVisitor;
+
+impl<'de> Visitor<'de> for OptionalCowStrVisitor {
+    type Value = Option<Cow<'de, str>>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a string or null")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_cow_str(deserializer).map(Some)
+    }
+}
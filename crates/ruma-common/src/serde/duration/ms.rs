@@ -1,4 +1,4 @@
-//! De-/serialization functions for `Option<std::time::Duration>` objects represented as
+//! De-/serialization functions for `std::time::Duration` objects represented as
 //! milliseconds.
 //!
 //! Delegates to `js_int::UInt` to ensure integer size is within bounds.
@@ -11,7 +11,7 @@ use serde::{
     ser::{Error, Serialize, Serializer},
 };
 
-/// Serializes a Duration to an integer representing seconds.
+/// Serializes a Duration to an integer representing milliseconds.
 ///
 /// Will fail if integer is greater than the maximum integer that can be
 /// unambiguously represented by an f64.
@@ -25,7 +25,7 @@ where
     }
 }
 
-/// Deserializes an integer representing seconds into a Duration.
+/// Deserializes an integer representing milliseconds into a Duration.
 ///
 /// Will fail if integer is greater than the maximum integer that can be
 /// unambiguously represented by an f64.
@@ -64,4 +64,10 @@ mod tests {
         let test = DurationTest { timeout: Duration::from_millis(7000) };
         assert_eq!(serde_json::to_value(test).unwrap(), json!({ "timeout": 7000 }));
     }
+
+    #[test]
+    fn serialize_overflow() {
+        let test = DurationTest { timeout: Duration::MAX };
+        serde_json::to_value(test).unwrap_err();
+    }
 }
@@ -1,5 +1,5 @@
 //! De-/serialization functions for `Option<std::time::Duration>` objects represented as
-//! milliseconds.
+//! seconds.
 //!
 //! Delegates to `js_int::UInt` to ensure integer size is within bounds.
 
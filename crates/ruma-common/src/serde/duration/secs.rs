@@ -1,5 +1,5 @@
-//! De-/serialization functions for `Option<std::time::Duration>` objects represented as
-//! milliseconds.
+//! De-/serialization functions for `std::time::Duration` objects represented as
+//! seconds.
 //!
 //! Delegates to `js_int::UInt` to ensure integer size is within bounds.
 
@@ -64,4 +64,10 @@ mod tests {
         let test = DurationTest { timeout: Duration::from_millis(7000) };
         assert_eq!(serde_json::to_value(test).unwrap(), json!({ "timeout": 7 }));
     }
+
+    #[test]
+    fn serialize_overflow() {
+        let test = DurationTest { timeout: Duration::MAX };
+        serde_json::to_value(test).unwrap_err();
+    }
 }
@@ -89,6 +89,10 @@ impl<T> Raw<T> {
     ///
     /// Returns `Ok(None)` when the field doesn't exist or is `null`.
     ///
+    /// The field can be deserialized to a type that borrows from `self`, like `&str`, to inspect
+    /// an event without allocating. Note that deserializing a JSON string that contains escape
+    /// sequences to a `&str` fails, use `Cow<'_, str>` or `String` if that can happen.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -154,7 +158,7 @@ impl<T> Raw<T> {
             type Value = Option<T>;
 
             fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("a string")
+                formatter.write_str("a JSON object")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -164,7 +168,7 @@ impl<T> Raw<T> {
                 let mut res = None;
                 while let Some(is_right_field) = map.next_key_seed(Field(self.field_name))? {
                     if is_right_field {
-                        res = Some(map.next_value()?);
+                        res = map.next_value()?;
                     } else {
                         map.next_value::<IgnoredAny>()?;
                     }
@@ -265,6 +269,12 @@ mod tests {
         assert_eq!(raw.get_field::<u8>("b")?, None);
         raw.get_field::<u8>("a").unwrap_err();
 
+        let raw: Raw<()> = from_json_str(r#"{ "a": null }"#)?;
+        assert_eq!(raw.get_field::<String>("a")?, None);
+
+        let raw: Raw<()> = from_json_str(r#"["a"]"#)?;
+        raw.get_field::<String>("a").unwrap_err();
+
         Ok(())
     }
 }
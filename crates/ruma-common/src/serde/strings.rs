@@ -35,7 +35,9 @@ where
 /// and forward `Some`s to the `Serialize` implementation for `T`.
 ///
 /// To be used like this:
-/// `#[serde(serialize_with = "empty_string_as_none")]`
+/// `#[serde(serialize_with = "none_as_empty_string")]`
+///
+/// This is the reverse of [`empty_string_as_none`], so both are usually used together.
 pub fn none_as_empty_string<T: Serialize, S>(
     value: &Option<T>,
     serializer: S,
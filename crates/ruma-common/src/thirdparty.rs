@@ -39,7 +39,7 @@ pub struct Protocol {
 
 /// Initial set of fields of `Protocol`.
 ///
-/// This struct will not be updated even if additional fields are added to `Prococol` in a new
+/// This struct will not be updated even if additional fields are added to `Protocol` in a new
 /// (non-breaking) release of the Matrix specification.
 #[derive(Debug)]
 #[allow(clippy::exhaustive_structs)]
@@ -94,10 +94,10 @@ pub struct ProtocolInstance {
     pub instance_id: String,
 }
 
-/// Initial set of fields of `Protocol`.
+/// Initial set of fields of `ProtocolInstance`.
 ///
-/// This struct will not be updated even if additional fields are added to `Prococol` in a new
-/// (non-breaking) release of the Matrix specification.
+/// This struct will not be updated even if additional fields are added to `ProtocolInstance` in a
+/// new (non-breaking) release of the Matrix specification.
 #[derive(Debug)]
 #[allow(clippy::exhaustive_structs)]
 pub struct ProtocolInstanceInit {
@@ -237,7 +237,7 @@ pub enum Medium {
 /// An identifier external to Matrix.
 ///
 /// To create an instance of this type, first create a `ThirdPartyIdentifierInit` and convert it to
-/// this type using `ThirdPartyIdentifier::Init` / `.into()`.
+/// this type using `ThirdPartyIdentifier::from` / `.into()`.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -286,7 +286,7 @@ impl From<ThirdPartyIdentifierInit> for ThirdPartyIdentifier {
 mod tests {
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::{Medium, ThirdPartyIdentifier};
+    use super::{Medium, Protocol, ThirdPartyIdentifier};
     use crate::MilliSecondsSinceUnixEpoch;
 
     #[test]
@@ -308,4 +308,52 @@ mod tests {
         assert_eq!(to_json_value(third_party_id.clone()).unwrap(), third_party_id_serialized);
         assert_eq!(third_party_id, from_json_value(third_party_id_serialized).unwrap());
     }
+
+    #[test]
+    fn protocol_deserialize() {
+        let json = json!({
+            "user_fields": ["network", "nickname"],
+            "location_fields": ["network", "channel"],
+            "icon": "mxc://example.org/aBcDeFgH",
+            "field_types": {
+                "network": {
+                    "regexp": "([a-z0-9]+\\.)*[a-z0-9]+",
+                    "placeholder": "irc.example.org"
+                },
+                "nickname": {
+                    "regexp": "[^\\s#]+",
+                    "placeholder": "username"
+                },
+                "channel": {
+                    "regexp": "#[^\\s]+",
+                    "placeholder": "#foobar"
+                }
+            },
+            "instances": [
+                {
+                    "desc": "Freenode",
+                    "icon": "mxc://example.org/JkLmNoPq",
+                    "fields": {
+                        "network": "freenode"
+                    },
+                    "network_id": "freenode",
+                    "instance_id": "freenode"
+                }
+            ]
+        });
+
+        let protocol = from_json_value::<Protocol>(json).unwrap();
+        assert_eq!(protocol.user_fields, ["network", "nickname"]);
+        assert_eq!(protocol.location_fields, ["network", "channel"]);
+        assert_eq!(protocol.icon, "mxc://example.org/aBcDeFgH");
+        assert_eq!(protocol.field_types.len(), 3);
+        assert_eq!(protocol.field_types["channel"].placeholder, "#foobar");
+
+        assert_eq!(protocol.instances.len(), 1);
+        let instance = &protocol.instances[0];
+        assert_eq!(instance.desc, "Freenode");
+        assert_eq!(instance.icon.as_deref(), Some("mxc://example.org/JkLmNoPq"));
+        assert_eq!(instance.fields["network"], "freenode");
+        assert_eq!(instance.network_id, "freenode");
+    }
 }
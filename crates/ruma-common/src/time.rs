@@ -61,12 +61,12 @@ impl fmt::Debug for MilliSecondsSinceUnixEpoch {
 pub struct SecondsSinceUnixEpoch(pub UInt);
 
 impl SecondsSinceUnixEpoch {
-    /// Creates a new `MilliSecondsSinceUnixEpoch` from the given `SystemTime`, if it is not before
+    /// Creates a new `SecondsSinceUnixEpoch` from the given `SystemTime`, if it is not before
     /// the unix epoch, or too large to be represented.
     pub fn from_system_time(time: SystemTime) -> Option<Self> {
         let duration = time.duration_since(UNIX_EPOCH).ok()?;
-        let millis = duration.as_secs().try_into().ok()?;
-        Some(Self(millis))
+        let secs = duration.as_secs().try_into().ok()?;
+        Some(Self(secs))
     }
 
     /// The current system-time as seconds since the unix epoch.
@@ -108,7 +108,7 @@ fn f64_to_uint(val: f64) -> UInt {
 mod tests {
     use std::time::{Duration, UNIX_EPOCH};
 
-    use js_int::uint;
+    use js_int::{uint, UInt};
     use serde::{Deserialize, Serialize};
     use serde_json::json;
 
@@ -129,6 +129,26 @@ mod tests {
         assert_eq!(time.secs.to_system_time(), Some(UNIX_EPOCH + Duration::from_secs(60)));
     }
 
+    #[test]
+    #[cfg(not(feature = "compat-float-integers"))]
+    fn deserialize_float_rejected() {
+        let json = json!({ "millis": 3000.0, "secs": 60 });
+        serde_json::from_value::<SystemTimeTest>(json).unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "compat-float-integers")]
+    fn deserialize_float() {
+        let json = json!({ "millis": 3000.0, "secs": 60.0 });
+
+        let time = serde_json::from_value::<SystemTimeTest>(json).unwrap();
+        assert_eq!(time.millis.get(), uint!(3000));
+        assert_eq!(time.secs.get(), uint!(60));
+
+        let json = json!({ "millis": 3000.5, "secs": 60 });
+        serde_json::from_value::<SystemTimeTest>(json).unwrap_err();
+    }
+
     #[test]
     fn serialize() {
         let request = SystemTimeTest {
@@ -139,4 +159,45 @@ mod tests {
 
         assert_eq!(serde_json::to_value(request).unwrap(), json!({ "millis": 2000, "secs": 0 }));
     }
+
+    #[test]
+    fn from_system_time_before_epoch() {
+        let time = UNIX_EPOCH - Duration::from_secs(1);
+
+        assert_eq!(MilliSecondsSinceUnixEpoch::from_system_time(time), None);
+        assert_eq!(SecondsSinceUnixEpoch::from_system_time(time), None);
+    }
+
+    #[test]
+    fn from_system_time_out_of_range() {
+        let Some(time) = UNIX_EPOCH.checked_add(Duration::from_secs(u64::from(UInt::MAX) + 1))
+        else {
+            // The platform can't represent a time this far in the future.
+            return;
+        };
+
+        assert_eq!(MilliSecondsSinceUnixEpoch::from_system_time(time), None);
+        assert_eq!(SecondsSinceUnixEpoch::from_system_time(time), None);
+    }
+
+    #[test]
+    fn round_trip_system_time() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+
+        let millis = MilliSecondsSinceUnixEpoch::from_system_time(time).unwrap();
+        assert_eq!(millis.get(), UInt::new(1_700_000_000_123).unwrap());
+        assert_eq!(millis.as_secs(), UInt::new(1_700_000_000).unwrap());
+        assert_eq!(millis.to_system_time(), Some(time));
+
+        let secs = SecondsSinceUnixEpoch::from_system_time(time).unwrap();
+        assert_eq!(secs.get(), UInt::new(1_700_000_000).unwrap());
+        assert_eq!(secs.to_system_time(), Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000)));
+    }
+
+    #[test]
+    fn to_system_time_max() {
+        // Must not panic, even if the platform can't represent this time.
+        let _ = MilliSecondsSinceUnixEpoch(UInt::MAX).to_system_time();
+        let _ = SecondsSinceUnixEpoch(UInt::MAX).to_system_time();
+    }
 }
@@ -1,10 +1,13 @@
 #![allow(clippy::exhaustive_structs)]
 
+use assert_matches2::assert_matches;
+use bytes::{Bytes, BytesMut};
 use http::header::CONTENT_TYPE;
 use ruma_common::{
     api::{
-        request, response, IncomingRequest as _, MatrixVersion, Metadata, OutgoingRequest as _,
-        OutgoingRequestAppserviceExt, SendAccessToken,
+        error::{DeserializationError, FromHttpResponseError, HeaderDeserializationError},
+        request, response, IncomingRequest as _, IncomingResponse as _, MatrixVersion, Metadata,
+        OutgoingRequest as _, OutgoingRequestAppserviceExt, OutgoingResponse as _, SendAccessToken,
     },
     metadata, owned_user_id, user_id, OwnedUserId,
 };
@@ -128,6 +131,50 @@ fn request_with_user_id_serde() {
     );
 }
 
+#[test]
+fn response_missing_required_header() {
+    let http_res = http::Response::builder().body(br#"{ "hello": "hi" }"# as &[u8]).unwrap();
+
+    let err = Response::try_from_http_response(http_res).unwrap_err();
+    assert_matches!(
+        err,
+        FromHttpResponseError::Deserialization(DeserializationError::Header(
+            HeaderDeserializationError::MissingHeader(header_name)
+        ))
+    );
+    assert_eq!(header_name, "CONTENT_TYPE");
+}
+
+#[test]
+fn request_from_server_http_request() {
+    let http_req = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("/_matrix/foo/barVal/%40bazme%3Aruma.io?q1=one&q2=2")
+        .header(CONTENT_TYPE, "test")
+        .body(Bytes::from_static(br#"{ "hello": "hi" }"#))
+        .unwrap();
+
+    let req = Request::try_from_http_request(http_req, &["barVal", "@bazme:ruma.io"]).unwrap();
+
+    assert_eq!(req.hello, "hi");
+    assert_eq!(req.world, "test");
+    assert_eq!(req.q1, "one");
+    assert_eq!(req.q2, 2);
+    assert_eq!(req.bar, "barVal");
+    assert_eq!(req.user, "@bazme:ruma.io");
+}
+
+#[test]
+fn response_into_server_http_response() {
+    let res = Response { hello: "hi".to_owned(), world: "test".to_owned(), optional_flag: None };
+
+    let http_res = res.try_into_http_response::<BytesMut>().unwrap();
+
+    assert_eq!(http_res.status(), http::StatusCode::OK);
+    assert_eq!(http_res.headers().get(CONTENT_TYPE).unwrap(), "test");
+    assert_eq!(http_res.body().as_ref(), br#"{"hello":"hi"}"#);
+}
+
 mod without_query {
     use http::header::CONTENT_TYPE;
     use ruma_common::{
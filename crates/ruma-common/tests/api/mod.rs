@@ -6,5 +6,7 @@ mod header_override;
 mod manual_endpoint_impl;
 mod no_fields;
 mod optional_headers;
+mod query_params;
+mod raw_body;
 mod ruma_api;
 mod ruma_api_macros;
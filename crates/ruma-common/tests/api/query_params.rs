@@ -0,0 +1,178 @@
+#![allow(clippy::exhaustive_structs)]
+
+use ruma_common::{
+    api::{Direction, IncomingRequest as _, MatrixVersion, OutgoingRequest as _, SendAccessToken},
+    owned_server_name, server_name,
+};
+
+mod sequence {
+    use ruma_common::{
+        api::{request, response, Direction, Metadata},
+        metadata, OwnedServerName,
+    };
+
+    const METADATA: Metadata = metadata! {
+        method: GET,
+        rate_limited: false,
+        authentication: None,
+        history: {
+            unstable => "/_matrix/my/endpoint",
+        }
+    };
+
+    /// Request type for the `sequence` endpoint.
+    #[request]
+    pub struct Request {
+        #[ruma_api(query)]
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub server_name: Vec<OwnedServerName>,
+
+        #[ruma_api(query)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub limit: Option<u32>,
+
+        #[ruma_api(query)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub dir: Option<Direction>,
+    }
+
+    /// Response type for the `sequence` endpoint.
+    #[response]
+    pub struct Response {}
+}
+
+mod map {
+    use ruma_common::{
+        api::{request, response, Metadata},
+        metadata,
+    };
+
+    const METADATA: Metadata = metadata! {
+        method: GET,
+        rate_limited: false,
+        authentication: None,
+        history: {
+            unstable => "/_matrix/my/endpoint",
+        }
+    };
+
+    /// Request type for the `map` endpoint.
+    #[request]
+    pub struct Request {
+        #[ruma_api(query_map)]
+        pub fields: Vec<(String, String)>,
+    }
+
+    /// Response type for the `map` endpoint.
+    #[response]
+    pub struct Response {}
+}
+
+#[test]
+fn sequence_as_repeated_keys() {
+    let req = sequence::Request {
+        server_name: vec![owned_server_name!("a.example"), owned_server_name!("b.example")],
+        limit: Some(5),
+        dir: Some(Direction::Backward),
+    };
+
+    let http_req = req
+        .try_into_http_request::<Vec<u8>>(
+            "https://homeserver.tld",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+
+    assert_eq!(
+        http_req.uri().query(),
+        Some("server_name=a.example&server_name=b.example&limit=5&dir=b")
+    );
+}
+
+#[test]
+fn empty_sequence_is_omitted() {
+    let req = sequence::Request { server_name: Vec::new(), limit: None, dir: None };
+
+    let http_req = req
+        .try_into_http_request::<Vec<u8>>(
+            "https://homeserver.tld",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+
+    assert_eq!(http_req.uri().to_string(), "https://homeserver.tld/_matrix/my/endpoint");
+}
+
+#[test]
+fn query_map_keeps_order_and_escapes() {
+    let req = map::Request {
+        fields: vec![
+            ("search".to_owned(), "a b&c".to_owned()),
+            ("field.1".to_owned(), "x".to_owned()),
+            ("search".to_owned(), "d".to_owned()),
+        ],
+    };
+
+    let http_req = req
+        .try_into_http_request::<Vec<u8>>(
+            "https://homeserver.tld",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+
+    assert_eq!(http_req.uri().query(), Some("search=a+b%26c&field.1=x&search=d"));
+}
+
+fn get_request(query: &str) -> http::Request<&'static [u8]> {
+    http::Request::builder()
+        .uri(format!("https://homeserver.tld/_matrix/my/endpoint?{query}"))
+        .body(&[] as &[u8])
+        .unwrap()
+}
+
+#[test]
+fn parse_repeated_keys_into_sequence() {
+    let req = sequence::Request::try_from_http_request(
+        get_request("server_name=a.example&limit=2&dir=f&server_name=b.example"),
+        &[] as &[String],
+    )
+    .unwrap();
+
+    assert_eq!(req.server_name, [server_name!("a.example"), server_name!("b.example")]);
+    assert_eq!(req.limit, Some(2));
+    assert_eq!(req.dir, Some(Direction::Forward));
+}
+
+#[test]
+fn parse_single_key_into_sequence() {
+    let req = sequence::Request::try_from_http_request(
+        get_request("server_name=a.example"),
+        &[] as &[String],
+    )
+    .unwrap();
+
+    assert_eq!(req.server_name, [server_name!("a.example")]);
+    assert_eq!(req.limit, None);
+    assert_eq!(req.dir, None);
+}
+
+#[test]
+fn parse_query_map() {
+    let req = map::Request::try_from_http_request(
+        get_request("search=a+b%26c&field.1=x&search=d"),
+        &[] as &[String],
+    )
+    .unwrap();
+
+    assert_eq!(
+        req.fields,
+        [
+            ("search".to_owned(), "a b&c".to_owned()),
+            ("field.1".to_owned(), "x".to_owned()),
+            ("search".to_owned(), "d".to_owned()),
+        ]
+    );
+}
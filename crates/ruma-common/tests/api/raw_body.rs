@@ -0,0 +1,165 @@
+#![allow(clippy::exhaustive_structs)]
+
+use assert_matches2::assert_matches;
+use bytes::{Bytes, BytesMut};
+use http::{header::CONTENT_TYPE, StatusCode};
+use ruma_common::{
+    api::{
+        error::FromHttpResponseError, request, response, IncomingRawBodyRequest as _,
+        IncomingRawBodyResponse as _, IncomingRequest as _, IncomingResponse as _, MatrixVersion,
+        Metadata, OutgoingRawBodyRequest as _, OutgoingRawBodyResponse as _, OutgoingRequest as _,
+        OutgoingResponse as _, SendAccessToken,
+    },
+    metadata,
+};
+
+const METADATA: Metadata = metadata! {
+    method: POST,
+    rate_limited: false,
+    authentication: None,
+    history: {
+        unstable => "/_matrix/my/endpoint",
+    }
+};
+
+/// Request type for the `raw_body` endpoint.
+#[request]
+pub struct Request {
+    #[ruma_api(header = CONTENT_TYPE)]
+    pub content_type: Option<String>,
+
+    #[ruma_api(raw_body)]
+    pub file: Vec<u8>,
+}
+
+/// Response type for the `raw_body` endpoint.
+#[response]
+pub struct Response {
+    #[ruma_api(header = CONTENT_TYPE)]
+    pub content_type: Option<String>,
+
+    #[ruma_api(raw_body)]
+    pub file: Vec<u8>,
+}
+
+#[test]
+fn request_default_content_type() {
+    let req = Request { content_type: None, file: b"\x00\x01binary".to_vec() };
+    let http_req = req
+        .try_into_http_request::<Vec<u8>>(
+            "https://homeserver.tld",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+
+    assert_eq!(http_req.headers().get(CONTENT_TYPE).unwrap(), "application/octet-stream");
+    assert_eq!(http_req.body(), b"\x00\x01binary");
+}
+
+#[test]
+fn request_roundtrip() {
+    let req = Request { content_type: Some("image/png".to_owned()), file: b"PNG".to_vec() };
+    let http_req = req
+        .try_into_http_request::<Vec<u8>>(
+            "https://homeserver.tld",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+    assert_eq!(http_req.headers().get(CONTENT_TYPE).unwrap(), "image/png");
+
+    let req = Request::try_from_http_request(http_req, &[] as &[String]).unwrap();
+    assert_eq!(req.content_type.as_deref(), Some("image/png"));
+    assert_eq!(req.file, b"PNG");
+}
+
+#[test]
+fn response_default_content_type() {
+    let res = Response { content_type: None, file: b"\x00\x01binary".to_vec() };
+    let http_res = res.try_into_http_response::<Vec<u8>>().unwrap();
+
+    assert_eq!(http_res.headers().get(CONTENT_TYPE).unwrap(), "application/octet-stream");
+    assert_eq!(http_res.body(), b"\x00\x01binary");
+}
+
+#[test]
+fn response_roundtrip() {
+    let res = Response { content_type: Some("text/plain".to_owned()), file: b"{ hi".to_vec() };
+    let http_res = res.try_into_http_response::<Vec<u8>>().unwrap();
+    assert_eq!(http_res.headers().get(CONTENT_TYPE).unwrap(), "text/plain");
+
+    let res = Response::try_from_http_response(http_res).unwrap();
+    assert_eq!(res.content_type.as_deref(), Some("text/plain"));
+    assert_eq!(res.file, b"{ hi");
+}
+
+#[test]
+fn bytes_bodies() {
+    let req = Request { content_type: None, file: b"\x00\x01binary".to_vec() };
+    let http_req = req
+        .try_into_http_request::<BytesMut>(
+            "https://homeserver.tld",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap()
+        .map(BytesMut::freeze);
+
+    let req = Request::try_from_http_request(http_req, &[] as &[String]).unwrap();
+    assert_eq!(req.file, b"\x00\x01binary");
+
+    let res = Response { content_type: None, file: b"\x00\x01binary".to_vec() };
+    let http_res = res.try_into_http_response::<BytesMut>().unwrap().map(BytesMut::freeze);
+
+    let res = Response::try_from_http_response::<Bytes>(http_res).unwrap();
+    assert_eq!(res.file, b"\x00\x01binary");
+}
+
+/// A body that is not buffered, like a stream of chunks.
+type Chunks = std::vec::IntoIter<Bytes>;
+
+fn chunks() -> Chunks {
+    vec![Bytes::from_static(b"\x00\x01"), Bytes::from_static(b"binary")].into_iter()
+}
+
+#[test]
+fn request_with_body_roundtrip() {
+    let req = Request { content_type: Some("image/png".to_owned()), file: Vec::new() };
+    let http_req = req
+        .try_into_http_request_with_body(
+            "https://homeserver.tld",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_1],
+            chunks(),
+        )
+        .unwrap();
+    assert_eq!(http_req.uri(), "https://homeserver.tld/_matrix/my/endpoint");
+    assert_eq!(http_req.headers().get(CONTENT_TYPE).unwrap(), "image/png");
+
+    let (req, body) = Request::try_from_http_request_with_body(http_req, &[] as &[String]).unwrap();
+    assert_eq!(req.content_type.as_deref(), Some("image/png"));
+    assert!(req.file.is_empty());
+    assert_eq!(body.collect::<Vec<_>>(), chunks().collect::<Vec<_>>());
+}
+
+#[test]
+fn response_with_body_roundtrip() {
+    let res = Response { content_type: None, file: Vec::new() };
+    let http_res = res.try_into_http_response_with_body(chunks()).unwrap();
+    assert_eq!(http_res.headers().get(CONTENT_TYPE).unwrap(), "application/octet-stream");
+
+    let (res, body) = Response::try_from_http_response_with_body(http_res).unwrap();
+    assert_eq!(res.content_type.as_deref(), Some("application/octet-stream"));
+    assert!(res.file.is_empty());
+    assert_eq!(body.collect::<Vec<_>>(), chunks().collect::<Vec<_>>());
+}
+
+#[test]
+fn error_response_with_body() {
+    let http_res = http::Response::builder().status(StatusCode::NOT_FOUND).body(chunks()).unwrap();
+
+    let err = Response::try_from_http_response_with_body(http_res).unwrap_err();
+    assert_matches!(err, FromHttpResponseError::Server(err));
+    assert_eq!(err.status_code, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+
+use assert_matches2::assert_matches;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct StructWithOptionalCow<'a> {
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "ruma_common::serde::deserialize_optional_cow_str"
+    )]
+    x: Option<Cow<'a, str>>,
+}
+
+#[test]
+fn optional_cow_str_borrowed() {
+    let json = r#"{ "x": "value" }"#;
+
+    let res = serde_json::from_str::<StructWithOptionalCow<'_>>(json).unwrap();
+    assert_matches!(res.x, Some(Cow::Borrowed("value")));
+}
+
+#[test]
+fn optional_cow_str_escaped() {
+    let json = r#"{ "x": "\"quoted\" value" }"#;
+
+    let res = serde_json::from_str::<StructWithOptionalCow<'_>>(json).unwrap();
+    assert_matches!(res.x, Some(Cow::Owned(x)));
+    assert_eq!(x, r#""quoted" value"#);
+}
+
+#[test]
+fn optional_cow_str_null_or_missing() {
+    let res = serde_json::from_str::<StructWithOptionalCow<'_>>(r#"{ "x": null }"#).unwrap();
+    assert_matches!(res.x, None);
+
+    let res = serde_json::from_str::<StructWithOptionalCow<'_>>("{}").unwrap();
+    assert_matches!(res.x, None);
+}
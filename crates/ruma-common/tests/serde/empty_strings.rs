@@ -40,6 +40,13 @@ mod string {
         assert_eq!(from_json_value::<StringStruct>(encoded).unwrap(), decoded);
     }
 
+    #[test]
+    fn null_de() {
+        let encoded = json!({ "x": null });
+        let decoded = StringStruct { x: None };
+        assert_eq!(from_json_value::<StringStruct>(encoded).unwrap(), decoded);
+    }
+
     #[test]
     fn some_de() {
         let encoded = json!({ "x": "foo" });
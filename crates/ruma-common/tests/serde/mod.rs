@@ -1,2 +1,3 @@
+mod cow;
 mod empty_strings;
 mod enum_derive;
@@ -9,8 +9,12 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use ruma_common::serde::Raw;
 use ruma_events::{
-    room::power_levels::RoomPowerLevelsEventContent, AnyStateEvent, AnyTimelineEvent,
-    OriginalStateEvent,
+    room::{
+        member::{RoomMemberEventContent, RoomMemberEventContentRef},
+        message::{RoomMessageEventContent, RoomMessageEventContentRef},
+        power_levels::RoomPowerLevelsEventContent,
+    },
+    AnyStateEvent, AnyTimelineEvent, OriginalStateEvent,
 };
 use serde_json::json;
 
@@ -47,13 +51,39 @@ fn power_levels() -> serde_json::Value {
     })
 }
 
+fn message_content() -> String {
+    json!({
+        "msgtype": "m.text",
+        "body": "> <@alice:example.org> Hello, how are you?\n\nI am fine, thanks!",
+        "format": "org.matrix.custom.html",
+        "formatted_body": "<mx-reply><blockquote><a href=\"https://matrix.to/#/!room:example.org/$event\">In reply to</a> <a href=\"https://matrix.to/#/@alice:example.org\">@alice:example.org</a><br>Hello, how are you?</blockquote></mx-reply><p>I am <em>fine</em>, thanks!</p>",
+        "m.relates_to": {
+            "m.in_reply_to": {
+                "event_id": "$event"
+            }
+        }
+    })
+    .to_string()
+}
+
+fn member_content() -> String {
+    json!({
+        "avatar_url": "mxc://example.org/SEsfnsuifSDFSSEF",
+        "displayname": "Alice Margatroid",
+        "membership": "join",
+        "reason": "Looking for support",
+        "join_authorised_via_users_server": "@bob:example.org"
+    })
+    .to_string()
+}
+
 fn deserialize_any_room_event(c: &mut Criterion) {
     let json_data = power_levels();
 
     c.bench_function("deserialize to `AnyTimelineEvent`", |b| {
         b.iter(|| {
             let _ = serde_json::from_value::<AnyTimelineEvent>(json_data.clone()).unwrap();
-        })
+        });
     });
 }
 
@@ -63,7 +93,7 @@ fn deserialize_any_state_event(c: &mut Criterion) {
     c.bench_function("deserialize to `AnyStateEvent`", |b| {
         b.iter(|| {
             let _ = serde_json::from_value::<AnyStateEvent>(json_data.clone()).unwrap();
-        })
+        });
     });
 }
 
@@ -76,15 +106,63 @@ fn deserialize_specific_event(c: &mut Criterion) {
                 json_data.clone(),
             )
             .unwrap();
-        })
+        });
+    });
+}
+
+fn inspect_event_fields(c: &mut Criterion) {
+    let raw_event = Raw::<AnyTimelineEvent>::from_json_string(power_levels().to_string()).unwrap();
+
+    c.bench_function("get borrowed `type` and `sender` fields of `Raw<AnyTimelineEvent>`", |b| {
+        b.iter(|| {
+            let _ = raw_event.get_field::<&str>("type").unwrap().unwrap();
+            let _ = raw_event.get_field::<&str>("sender").unwrap().unwrap();
+        });
+    });
+}
+
+fn deserialize_message_content(c: &mut Criterion) {
+    let json = message_content();
+
+    let mut group = c.benchmark_group("deserialize `m.room.message` content");
+    group.bench_function("owned `RoomMessageEventContent`", |b| {
+        b.iter(|| {
+            let _ = serde_json::from_str::<RoomMessageEventContent>(&json).unwrap();
+        });
+    });
+    group.bench_function("borrowed `RoomMessageEventContentRef`", |b| {
+        b.iter(|| {
+            let _ = serde_json::from_str::<RoomMessageEventContentRef<'_>>(&json).unwrap();
+        });
+    });
+    group.finish();
+}
+
+fn deserialize_member_content(c: &mut Criterion) {
+    let json = member_content();
+
+    let mut group = c.benchmark_group("deserialize `m.room.member` content");
+    group.bench_function("owned `RoomMemberEventContent`", |b| {
+        b.iter(|| {
+            let _ = serde_json::from_str::<RoomMemberEventContent>(&json).unwrap();
+        });
+    });
+    group.bench_function("borrowed `RoomMemberEventContentRef`", |b| {
+        b.iter(|| {
+            let _ = serde_json::from_str::<RoomMemberEventContentRef<'_>>(&json).unwrap();
+        });
     });
+    group.finish();
 }
 
 criterion_group!(
     benches,
     deserialize_any_room_event,
     deserialize_any_state_event,
-    deserialize_specific_event
+    deserialize_specific_event,
+    inspect_event_fields,
+    deserialize_message_content,
+    deserialize_member_content
 );
 
 criterion_main!(benches);
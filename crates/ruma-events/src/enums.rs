@@ -14,6 +14,7 @@ event_enum! {
         "m.direct" => super::direct,
         "m.identity_server" => super::identity_server,
         "m.ignored_user_list" => super::ignored_user_list,
+        #[cfg(feature = "push-rules")]
         "m.push_rules" => super::push_rules,
         "m.secret_storage.default_key" => super::secret_storage::default_key,
         "m.secret_storage.key.*" => super::secret_storage::key,
@@ -39,12 +40,19 @@ event_enum! {
         #[cfg(feature = "unstable-msc3927")]
         #[ruma_enum(alias = "m.audio")]
         "org.matrix.msc1767.audio" => super::audio,
+        #[cfg(feature = "call")]
         "m.call.answer" => super::call::answer,
+        #[cfg(feature = "call")]
         "m.call.invite" => super::call::invite,
+        #[cfg(feature = "call")]
         "m.call.hangup" => super::call::hangup,
+        #[cfg(feature = "call")]
         "m.call.candidates" => super::call::candidates,
+        #[cfg(feature = "call")]
         "m.call.negotiate" => super::call::negotiate,
+        #[cfg(feature = "call")]
         "m.call.reject" => super::call::reject,
+        #[cfg(feature = "call")]
         "m.call.select_answer" => super::call::select_answer,
         #[cfg(feature = "unstable-msc3954")]
         #[ruma_enum(alias = "m.emote")]
@@ -55,15 +63,28 @@ event_enum! {
         #[cfg(feature = "unstable-msc3551")]
         #[ruma_enum(alias = "m.file")]
         "org.matrix.msc1767.file" => super::file,
+        #[cfg(feature = "unstable-msc2716")]
+        #[ruma_enum(ident = HistoryBatch)]
+        "org.matrix.msc2716.batch" => super::history_import,
+        #[cfg(feature = "unstable-msc2716")]
+        #[ruma_enum(ident = HistoryInsertion)]
+        "org.matrix.msc2716.insertion" => super::history_import,
         #[cfg(feature = "unstable-msc3552")]
         #[ruma_enum(alias = "m.image")]
         "org.matrix.msc1767.image" => super::image,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.ready" => super::key::verification::ready,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.start" => super::key::verification::start,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.cancel" => super::key::verification::cancel,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.accept" => super::key::verification::accept,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.key" => super::key::verification::key,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.mac" => super::key::verification::mac,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.done" => super::key::verification::done,
         #[cfg(feature = "unstable-msc3488")]
         "m.location" => super::location,
@@ -103,8 +124,11 @@ event_enum! {
 
     /// Any state event.
     enum State {
+        #[cfg(feature = "policy")]
         "m.policy.rule.room" => super::policy::rule::room,
+        #[cfg(feature = "policy")]
         "m.policy.rule.server" => super::policy::rule::server,
+        #[cfg(feature = "policy")]
         "m.policy.rule.user" => super::policy::rule::user,
         "m.room.aliases" => super::room::aliases,
         "m.room.avatar" => super::room::avatar,
@@ -134,14 +158,23 @@ event_enum! {
         "m.dummy" => super::dummy,
         "m.room_key" => super::room_key,
         "m.room_key_request" => super::room_key_request,
+        "m.room_key.withheld" => super::room_key::withheld,
         "m.forwarded_room_key" => super::forwarded_room_key,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.request" => super::key::verification::request,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.ready" => super::key::verification::ready,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.start" => super::key::verification::start,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.cancel" => super::key::verification::cancel,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.accept" => super::key::verification::accept,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.key" => super::key::verification::key,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.mac" => super::key::verification::mac,
+        #[cfg(feature = "key-verification")]
         "m.key.verification.done" => super::key::verification::done,
         "m.room.encrypted" => super::room::encrypted,
         "m.secret.request"=> super::secret::request,
@@ -304,6 +337,7 @@ impl AnyMessageLikeEventContent {
     /// This is a helper function intended for encryption. There should not be a reason to access
     /// `m.relates_to` without first destructuring an `AnyMessageLikeEventContent` otherwise.
     pub fn relation(&self) -> Option<encrypted::Relation> {
+        #[cfg(feature = "key-verification")]
         use super::key::verification::{
             accept::KeyVerificationAcceptEventContent, cancel::KeyVerificationCancelEventContent,
             done::KeyVerificationDoneEventContent, key::KeyVerificationKeyEventContent,
@@ -319,6 +353,7 @@ impl AnyMessageLikeEventContent {
 
         match self {
             #[rustfmt::skip]
+            #[cfg(feature = "key-verification")]
             Self::KeyVerificationReady(KeyVerificationReadyEventContent { relates_to, .. })
             | Self::KeyVerificationStart(KeyVerificationStartEventContent { relates_to, .. })
             | Self::KeyVerificationCancel(KeyVerificationCancelEventContent { relates_to, .. })
@@ -358,18 +393,19 @@ impl AnyMessageLikeEventContent {
             }
             #[cfg(feature = "unstable-msc3381")]
             Self::PollStart(_) | Self::UnstablePollStart(_) => None,
+            #[cfg(feature = "unstable-msc2716")]
+            Self::HistoryBatch(_) | Self::HistoryInsertion(_) => None,
             #[cfg(feature = "unstable-msc4075")]
             Self::CallNotify(_) => None,
+            #[cfg(feature = "call")]
             Self::CallNegotiate(_)
             | Self::CallReject(_)
             | Self::CallSelectAnswer(_)
             | Self::CallAnswer(_)
             | Self::CallInvite(_)
             | Self::CallHangup(_)
-            | Self::CallCandidates(_)
-            | Self::RoomRedaction(_)
-            | Self::Sticker(_)
-            | Self::_Custom { .. } => None,
+            | Self::CallCandidates(_) => None,
+            Self::RoomRedaction(_) | Self::Sticker(_) | Self::_Custom { .. } => None,
         }
     }
 }
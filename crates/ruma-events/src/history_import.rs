@@ -0,0 +1,117 @@
+//! Types for the events used to import history into rooms ([MSC2716]).
+//!
+//! [MSC2716]: https://github.com/matrix-org/matrix-spec-proposals/pull/2716
+
+use ruma_macros::EventContent;
+use serde::{Deserialize, Serialize};
+
+/// The content of an `org.matrix.msc2716.insertion` event.
+///
+/// Marks a point in the room's DAG where a batch of historical events can be inserted.
+#[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+#[ruma_event(type = "org.matrix.msc2716.insertion", kind = MessageLike)]
+pub struct HistoryInsertionEventContent {
+    /// The ID of the next batch, to be used by the batch that is connected to this insertion
+    /// event.
+    #[serde(rename = "org.matrix.msc2716.next_batch_id")]
+    pub next_batch_id: String,
+
+    /// Whether this event is part of the imported history.
+    #[serde(
+        rename = "org.matrix.msc2716.historical",
+        default,
+        skip_serializing_if = "ruma_common::serde::is_default"
+    )]
+    pub historical: bool,
+}
+
+impl HistoryInsertionEventContent {
+    /// Creates a new `HistoryInsertionEventContent` with the given next batch ID.
+    pub fn new(next_batch_id: String) -> Self {
+        Self { next_batch_id, historical: false }
+    }
+}
+
+/// The content of an `org.matrix.msc2716.batch` event.
+///
+/// Connects a batch of historical events to the insertion event with the matching
+/// `next_batch_id`.
+#[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+#[ruma_event(type = "org.matrix.msc2716.batch", kind = MessageLike)]
+pub struct HistoryBatchEventContent {
+    /// The ID of the batch, matching the `next_batch_id` of an insertion event.
+    #[serde(rename = "org.matrix.msc2716.batch_id")]
+    pub batch_id: String,
+
+    /// Whether this event is part of the imported history.
+    #[serde(
+        rename = "org.matrix.msc2716.historical",
+        default,
+        skip_serializing_if = "ruma_common::serde::is_default"
+    )]
+    pub historical: bool,
+}
+
+impl HistoryBatchEventContent {
+    /// Creates a new `HistoryBatchEventContent` with the given batch ID.
+    pub fn new(batch_id: String) -> Self {
+        Self { batch_id, historical: false }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches2::assert_matches;
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::{HistoryBatchEventContent, HistoryInsertionEventContent};
+    use crate::AnyMessageLikeEvent;
+
+    #[test]
+    fn serialization() {
+        let mut content = HistoryInsertionEventContent::new("next".to_owned());
+        assert_eq!(
+            to_json_value(&content).unwrap(),
+            json!({ "org.matrix.msc2716.next_batch_id": "next" })
+        );
+
+        content.historical = true;
+        assert_eq!(
+            to_json_value(&content).unwrap(),
+            json!({
+                "org.matrix.msc2716.next_batch_id": "next",
+                "org.matrix.msc2716.historical": true,
+            })
+        );
+
+        assert_eq!(
+            to_json_value(HistoryBatchEventContent::new("batch".to_owned())).unwrap(),
+            json!({ "org.matrix.msc2716.batch_id": "batch" })
+        );
+    }
+
+    #[test]
+    fn deserialization() {
+        let json = json!({
+            "content": {
+                "org.matrix.msc2716.batch_id": "batch",
+                "org.matrix.msc2716.historical": true,
+            },
+            "event_id": "$batch:example.org",
+            "origin_server_ts": 1,
+            "room_id": "!room:example.org",
+            "sender": "@bridge:example.org",
+            "type": "org.matrix.msc2716.batch",
+        });
+
+        assert_matches!(
+            from_json_value::<AnyMessageLikeEvent>(json),
+            Ok(AnyMessageLikeEvent::HistoryBatch(ev))
+        );
+        let content = ev.as_original().unwrap().content.clone();
+        assert_eq!(content.batch_id, "batch");
+        assert!(content.historical);
+    }
+}
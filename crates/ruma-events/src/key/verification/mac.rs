@@ -4,7 +4,7 @@
 
 use std::collections::BTreeMap;
 
-use ruma_common::{serde::Base64, OwnedTransactionId};
+use ruma_common::{serde::Base64, DeviceId, OwnedTransactionId, UserId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -42,6 +42,13 @@ impl ToDeviceKeyVerificationMacEventContent {
     ) -> Self {
         Self { transaction_id, mac, keys }
     }
+
+    /// The comma-separated, sorted, list of key IDs in the `mac` property.
+    ///
+    /// This is the input of the MAC in the `keys` property.
+    pub fn key_ids(&self) -> String {
+        key_ids_list(self.mac.keys().map(String::as_str))
+    }
 }
 
 /// The content of an in-room `m.key.verification.` event.
@@ -71,4 +78,119 @@ impl KeyVerificationMacEventContent {
     pub fn new(mac: BTreeMap<String, Base64>, keys: Base64, relates_to: Reference) -> Self {
         Self { mac, keys, relates_to }
     }
+
+    /// The comma-separated, sorted, list of key IDs in the `mac` property.
+    ///
+    /// This is the input of the MAC in the `keys` property.
+    pub fn key_ids(&self) -> String {
+        key_ids_list(self.mac.keys().map(String::as_str))
+    }
+}
+
+/// The users and devices taking part in a key verification, from the point of view of the party
+/// sending the `m.key.verification.mac` event.
+#[derive(Clone, Copy, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct MacInfoParties<'a> {
+    /// The ID of the user sending the MAC.
+    pub sender_user_id: &'a UserId,
+
+    /// The ID of the device sending the MAC.
+    pub sender_device_id: &'a DeviceId,
+
+    /// The ID of the user receiving the MAC.
+    pub recipient_user_id: &'a UserId,
+
+    /// The ID of the device receiving the MAC.
+    pub recipient_device_id: &'a DeviceId,
+
+    /// The transaction ID of a to-device verification, or the event ID of the
+    /// `m.key.verification.request` event of an in-room verification.
+    pub flow_id: &'a str,
+}
+
+impl MacInfoParties<'_> {
+    /// The info parameter used to calculate the MAC of the key with the given ID.
+    pub fn key_info(&self, key_id: &str) -> String {
+        self.info(key_id)
+    }
+
+    /// The info parameter used to calculate the MAC of the list of key IDs, in the `keys`
+    /// property.
+    pub fn key_ids_info(&self) -> String {
+        self.info("KEY_IDS")
+    }
+
+    fn info(&self, suffix: &str) -> String {
+        let Self {
+            sender_user_id,
+            sender_device_id,
+            recipient_user_id,
+            recipient_device_id,
+            flow_id,
+        } = self;
+
+        format!(
+            "MATRIX_KEY_VERIFICATION_MAC{sender_user_id}{sender_device_id}\
+             {recipient_user_id}{recipient_device_id}{flow_id}{suffix}"
+        )
+    }
+}
+
+/// Sorts the given key IDs and joins them with commas.
+///
+/// This is the input of the MAC in the `keys` property of an `m.key.verification.mac` event.
+pub fn key_ids_list<'a>(key_ids: impl IntoIterator<Item = &'a str>) -> String {
+    let mut key_ids: Vec<_> = key_ids.into_iter().collect();
+    key_ids.sort_unstable();
+    key_ids.join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ruma_common::{device_id, serde::Base64, user_id};
+
+    use super::{key_ids_list, MacInfoParties, ToDeviceKeyVerificationMacEventContent};
+
+    #[test]
+    fn info_strings() {
+        let parties = MacInfoParties {
+            sender_user_id: user_id!("@alice:example.org"),
+            sender_device_id: device_id!("ALICEDEVICE"),
+            recipient_user_id: user_id!("@bob:example.org"),
+            recipient_device_id: device_id!("BOBDEVICE"),
+            flow_id: "txn",
+        };
+
+        assert_eq!(
+            parties.key_info("ed25519:ALICEDEVICE"),
+            "MATRIX_KEY_VERIFICATION_MAC@alice:example.orgALICEDEVICE@bob:example.orgBOBDEVICE\
+             txned25519:ALICEDEVICE"
+        );
+        assert_eq!(
+            parties.key_ids_info(),
+            "MATRIX_KEY_VERIFICATION_MAC@alice:example.orgALICEDEVICE@bob:example.orgBOBDEVICE\
+             txnKEY_IDS"
+        );
+    }
+
+    #[test]
+    fn key_ids() {
+        assert_eq!(
+            key_ids_list(["ed25519:MASTERKEY", "ed25519:ALICEDEVICE"]),
+            "ed25519:ALICEDEVICE,ed25519:MASTERKEY"
+        );
+
+        let content = ToDeviceKeyVerificationMacEventContent::new(
+            "txn".into(),
+            BTreeMap::from([
+                ("ed25519:MASTERKEY".to_owned(), Base64::new(b"mac1".to_vec())),
+                ("ed25519:ALICEDEVICE".to_owned(), Base64::new(b"mac2".to_vec())),
+            ]),
+            Base64::new(b"keys".to_vec()),
+        );
+        assert_eq!(content.key_ids(), "ed25519:ALICEDEVICE,ed25519:MASTERKEY");
+    }
 }
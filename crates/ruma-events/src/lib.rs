@@ -20,6 +20,17 @@
 //! Rust type for that event type's `content` field, and any other supporting types required by the
 //! event's other fields.
 //!
+//! The modules of some namespaces can be disabled with Cargo features, to reduce compile times:
+//!
+//! * `call`: the `m.call.*` events, in the [`call`] module.
+//! * `key-verification`: the `m.key.verification.*` events, in the [`key`] module, and the
+//!   `m.key.verification.request` message type.
+//! * `policy`: the `m.policy.rule.*` events, in the [`policy`] module.
+//! * `push-rules`: the `m.push_rules` event, in the [`push_rules`] module.
+//!
+//! These features are enabled by default. When one of them is disabled, the events of this
+//! namespace are deserialized as custom events in the event enums.
+//!
 //! # Extending Ruma with custom events
 //!
 //! For our examples we will start with a simple custom state event. `ruma_event`
@@ -106,7 +117,7 @@
 
 use std::{collections::BTreeSet, fmt};
 
-use ruma_common::{EventEncryptionAlgorithm, OwnedUserId, RoomVersionId};
+use ruma_common::{room_version_rules::RedactionRules, EventEncryptionAlgorithm, OwnedUserId};
 use serde::{de::IgnoredAny, Deserialize, Serialize, Serializer};
 
 // Needs to be public for trybuild tests
@@ -115,6 +126,7 @@ pub mod _custom;
 mod content;
 mod enums;
 mod kinds;
+mod room_state;
 mod state_key;
 mod unsigned;
 
@@ -139,6 +151,7 @@ pub mod macros {
 
 #[cfg(feature = "unstable-msc3927")]
 pub mod audio;
+#[cfg(feature = "call")]
 pub mod call;
 pub mod direct;
 pub mod dummy;
@@ -150,10 +163,13 @@ pub mod encrypted;
 pub mod file;
 pub mod forwarded_room_key;
 pub mod fully_read;
+#[cfg(feature = "unstable-msc2716")]
+pub mod history_import;
 pub mod identity_server;
 pub mod ignored_user_list;
 #[cfg(feature = "unstable-msc3552")]
 pub mod image;
+#[cfg(feature = "key-verification")]
 pub mod key;
 #[cfg(feature = "unstable-msc3488")]
 pub mod location;
@@ -163,10 +179,12 @@ pub mod marked_unread;
 pub mod message;
 #[cfg(feature = "unstable-pdu")]
 pub mod pdu;
+#[cfg(feature = "policy")]
 pub mod policy;
 #[cfg(feature = "unstable-msc3381")]
 pub mod poll;
 pub mod presence;
+#[cfg(feature = "push-rules")]
 pub mod push_rules;
 pub mod reaction;
 pub mod receipt;
@@ -190,6 +208,7 @@ pub use self::{
     enums::*,
     kinds::*,
     relation::{BundledMessageLikeRelations, BundledStateRelations},
+    room_state::{RoomState, RoomStateDiff},
     state_key::EmptyStateKey,
     unsigned::{MessageLikeUnsigned, RedactedUnsigned, StateUnsigned, UnsignedRoomRedactionEvent},
 };
@@ -201,9 +220,11 @@ pub trait RedactContent {
 
     /// Transform `self` into a redacted form (removing most or all fields) according to the spec.
     ///
-    /// A small number of events have room-version specific redaction behavior, so a version has to
-    /// be specified.
-    fn redact(self, version: &RoomVersionId) -> Self::Redacted;
+    /// A small number of events have room-version specific redaction behavior, so the redaction
+    /// rules of the room version have to be specified. They can be obtained with
+    /// [`RoomVersionId::rules()`](ruma_common::RoomVersionId::rules), which returns `None` for room
+    /// versions that are not supported.
+    fn redact(self, rules: &RedactionRules) -> Self::Redacted;
 }
 
 /// Helper struct to determine the event kind from a `serde_json::value::RawValue`.
@@ -61,7 +61,7 @@ impl UnstablePollStartEventContent {
 impl RedactContent for UnstablePollStartEventContent {
     type Redacted = RedactedUnstablePollStartEventContent;
 
-    fn redact(self, _version: &crate::RoomVersionId) -> Self::Redacted {
+    fn redact(self, _rules: &ruma_common::room_version_rules::RedactionRules) -> Self::Redacted {
         RedactedUnstablePollStartEventContent::default()
     }
 }
@@ -38,6 +38,23 @@ impl ReceiptEventContent {
             Some((event_id.as_ref(), receipt))
         })
     }
+
+    /// Get the receipt for the given user ID with the given receipt type in the given thread, if
+    /// it exists.
+    ///
+    /// Since a user can have a receipt of the same type in several threads, this should be
+    /// preferred over [`ReceiptEventContent::user_receipt()`] by clients that support threads.
+    pub fn user_thread_receipt(
+        &self,
+        user_id: &UserId,
+        receipt_type: ReceiptType,
+        thread: &ReceiptThread,
+    ) -> Option<(&EventId, &Receipt)> {
+        self.iter().find_map(|(event_id, receipts)| {
+            let receipt = receipts.get(&receipt_type)?.get(user_id)?;
+            (receipt.thread == *thread).then_some((event_id.as_ref(), receipt))
+        })
+    }
 }
 
 impl Deref for ReceiptEventContent {
@@ -212,10 +229,10 @@ where
 #[cfg(test)]
 mod tests {
     use assert_matches2::assert_matches;
-    use ruma_common::{owned_event_id, MilliSecondsSinceUnixEpoch};
+    use ruma_common::{event_id, owned_event_id, user_id, MilliSecondsSinceUnixEpoch};
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::{Receipt, ReceiptThread};
+    use super::{Receipt, ReceiptEventContent, ReceiptThread, ReceiptType};
 
     #[test]
     fn serialize_receipt() {
@@ -272,4 +289,42 @@ mod tests {
         assert_matches!(&receipt.thread, ReceiptThread::_Custom(_));
         assert_eq!(receipt.thread.as_str().unwrap(), "io.ruma.unknown");
     }
+
+    #[test]
+    fn user_thread_receipt() {
+        let content = from_json_value::<ReceiptEventContent>(json!({
+            "$main": {
+                "m.read": {
+                    "@alice:localhost": { "ts": 1, "thread_id": "main" },
+                },
+            },
+            "$in_thread": {
+                "m.read": {
+                    "@alice:localhost": { "ts": 2, "thread_id": "$root" },
+                },
+            },
+        }))
+        .unwrap();
+        let alice = user_id!("@alice:localhost");
+
+        let (event_id, receipt) =
+            content.user_thread_receipt(alice, ReceiptType::Read, &ReceiptThread::Main).unwrap();
+        assert_eq!(event_id, event_id!("$main"));
+        assert_eq!(receipt.thread, ReceiptThread::Main);
+
+        let thread = ReceiptThread::Thread(owned_event_id!("$root"));
+        let (event_id, receipt) =
+            content.user_thread_receipt(alice, ReceiptType::Read, &thread).unwrap();
+        assert_eq!(event_id, event_id!("$in_thread"));
+        assert_eq!(receipt.thread, thread);
+
+        assert_matches!(
+            content.user_thread_receipt(alice, ReceiptType::Read, &ReceiptThread::Unthreaded),
+            None
+        );
+        assert_matches!(
+            content.user_thread_receipt(alice, ReceiptType::ReadPrivate, &ReceiptThread::Main),
+            None
+        );
+    }
 }
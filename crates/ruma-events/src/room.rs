@@ -13,6 +13,8 @@ use serde::{de, Deserialize, Serialize};
 
 pub mod aliases;
 pub mod avatar;
+#[cfg(feature = "unstable-msc2448")]
+mod blurhash_serde;
 pub mod canonical_alias;
 pub mod create;
 pub mod encrypted;
@@ -102,7 +104,7 @@ pub struct ImageInfo {
     /// This uses the unstable prefix in
     /// [MSC2448](https://github.com/matrix-org/matrix-spec-proposals/pull/2448).
     #[cfg(feature = "unstable-msc2448")]
-    #[serde(rename = "xyz.amorgan.blurhash", skip_serializing_if = "Option::is_none")]
+    #[serde(flatten, with = "blurhash_serde", skip_serializing_if = "Option::is_none")]
     pub blurhash: Option<String>,
 }
 
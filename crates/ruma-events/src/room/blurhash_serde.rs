@@ -0,0 +1,101 @@
+//! De-/serialization functions for `Option<String>` objects representing a BlurHash.
+//!
+//! The BlurHash can be found under the stable `blurhash` key or the unstable
+//! `xyz.amorgan.blurhash` key from [MSC2448], and some clients send both.
+//!
+//! [MSC2448]: https://github.com/matrix-org/matrix-spec-proposals/pull/2448
+
+use serde::{
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Deserializer,
+};
+
+/// Serializes a BlurHash under the unstable key.
+pub(crate) fn serialize<S>(blurhash: &Option<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if let Some(blurhash) = blurhash {
+        let mut st = serializer.serialize_struct("BlurHash", 1)?;
+        st.serialize_field("xyz.amorgan.blurhash", blurhash)?;
+        st.end()
+    } else {
+        serializer.serialize_none()
+    }
+}
+
+/// Deserializes a BlurHash from the stable or the unstable key.
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct BlurHashJsonRepr {
+        blurhash: Option<String>,
+        #[serde(rename = "xyz.amorgan.blurhash")]
+        unstable_blurhash: Option<String>,
+    }
+
+    let BlurHashJsonRepr { blurhash, unstable_blurhash } =
+        BlurHashJsonRepr::deserialize(deserializer)?;
+
+    // Prefer the stable key if it is set
+    Ok(blurhash.or(unstable_blurhash))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    struct BlurHashTest {
+        #[serde(flatten, with = "super", skip_serializing_if = "Option::is_none")]
+        blurhash: Option<String>,
+    }
+
+    #[test]
+    fn deserialize_stable() {
+        let json = json!({ "blurhash": "LKO2?U%2Tw=w]~RBVZRi};RPxuwH" });
+
+        let test = serde_json::from_value::<BlurHashTest>(json).unwrap();
+        assert_eq!(test.blurhash.as_deref(), Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH"));
+    }
+
+    #[test]
+    fn deserialize_unstable() {
+        let json = json!({ "xyz.amorgan.blurhash": "LKO2?U%2Tw=w]~RBVZRi};RPxuwH" });
+
+        let test = serde_json::from_value::<BlurHashTest>(json).unwrap();
+        assert_eq!(test.blurhash.as_deref(), Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH"));
+    }
+
+    #[test]
+    fn deserialize_both_prefers_stable() {
+        let json = json!({
+            "blurhash": "LKO2?U%2Tw=w]~RBVZRi};RPxuwH",
+            "xyz.amorgan.blurhash": "L6PZfSi_.AyE_3t7t7R**0o#DgR4",
+        });
+
+        let test = serde_json::from_value::<BlurHashTest>(json).unwrap();
+        assert_eq!(test.blurhash.as_deref(), Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH"));
+    }
+
+    #[test]
+    fn deserialize_none() {
+        let test = serde_json::from_value::<BlurHashTest>(json!({})).unwrap();
+        assert_eq!(test.blurhash, None);
+    }
+
+    #[test]
+    fn serialize() {
+        let test = BlurHashTest { blurhash: Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH".to_owned()) };
+        assert_eq!(
+            serde_json::to_value(test).unwrap(),
+            json!({ "xyz.amorgan.blurhash": "LKO2?U%2Tw=w]~RBVZRi};RPxuwH" })
+        );
+
+        let test = BlurHashTest { blurhash: None };
+        assert_eq!(serde_json::to_value(test).unwrap(), json!({}));
+    }
+}
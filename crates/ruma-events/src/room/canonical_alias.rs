@@ -2,7 +2,7 @@
 //!
 //! [`m.room.canonical_alias`]: https://spec.matrix.org/latest/client-server-api/#mroomcanonical_alias
 
-use ruma_common::OwnedRoomAliasId;
+use ruma_common::{OwnedRoomAliasId, RoomAliasId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -36,11 +36,52 @@ impl RoomCanonicalAliasEventContent {
     pub fn new() -> Self {
         Self { alias: None, alt_aliases: Vec::new() }
     }
+
+    /// Adds the given alias to the alternative aliases of the room.
+    ///
+    /// Returns `false` if the alias was already the canonical alias or one of the alternative
+    /// aliases, in which case the content is left untouched.
+    pub fn add_alt_alias(&mut self, alias: OwnedRoomAliasId) -> bool {
+        if self.contains(&alias) {
+            return false;
+        }
+
+        self.alt_aliases.push(alias);
+        true
+    }
+
+    /// Removes the given alias from the canonical alias and the alternative aliases of the room.
+    ///
+    /// The other aliases are left untouched. Returns `false` if the alias was not found.
+    pub fn remove_alias(&mut self, alias: &RoomAliasId) -> bool {
+        let mut removed = false;
+
+        if self.alias.as_deref() == Some(alias) {
+            self.alias = None;
+            removed = true;
+        }
+
+        let alt_aliases_len = self.alt_aliases.len();
+        self.alt_aliases.retain(|alt_alias| alt_alias != alias);
+
+        removed || self.alt_aliases.len() != alt_aliases_len
+    }
+
+    /// Whether the given alias is the canonical alias or one of the alternative aliases of the
+    /// room.
+    pub fn contains(&self, alias: &RoomAliasId) -> bool {
+        self.aliases().any(|a| a == alias)
+    }
+
+    /// Iterate over the canonical alias and the alternative aliases of the room, in this order.
+    pub fn aliases(&self) -> impl Iterator<Item = &RoomAliasId> {
+        self.alias.as_deref().into_iter().chain(self.alt_aliases.iter().map(|alias| &**alias))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use ruma_common::owned_room_alias_id;
+    use ruma_common::{owned_room_alias_id, room_alias_id};
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
     use super::RoomCanonicalAliasEventContent;
@@ -148,4 +189,33 @@ mod tests {
             alias
         );
     }
+
+    #[test]
+    fn add_and_remove_aliases() {
+        let mut content = RoomCanonicalAliasEventContent {
+            alias: Some(owned_room_alias_id!("#somewhere:localhost")),
+            alt_aliases: vec![owned_room_alias_id!("#elsewhere:localhost")],
+        };
+
+        assert!(!content.add_alt_alias(owned_room_alias_id!("#somewhere:localhost")));
+        assert!(!content.add_alt_alias(owned_room_alias_id!("#elsewhere:localhost")));
+        assert!(content.add_alt_alias(owned_room_alias_id!("#nowhere:localhost")));
+        assert_eq!(
+            content.aliases().collect::<Vec<_>>(),
+            [
+                room_alias_id!("#somewhere:localhost"),
+                room_alias_id!("#elsewhere:localhost"),
+                room_alias_id!("#nowhere:localhost")
+            ]
+        );
+
+        assert!(content.remove_alias(room_alias_id!("#somewhere:localhost")));
+        assert_eq!(content.alias, None);
+        assert_eq!(content.alt_aliases.len(), 2);
+
+        assert!(content.remove_alias(room_alias_id!("#elsewhere:localhost")));
+        assert!(!content.remove_alias(room_alias_id!("#elsewhere:localhost")));
+        assert!(!content.contains(room_alias_id!("#elsewhere:localhost")));
+        assert_eq!(content.alt_aliases, [owned_room_alias_id!("#nowhere:localhost")]);
+    }
 }
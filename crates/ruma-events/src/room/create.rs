@@ -2,7 +2,10 @@
 //!
 //! [`m.room.create`]: https://spec.matrix.org/latest/client-server-api/#mroomcreate
 
-use ruma_common::{room::RoomType, OwnedEventId, OwnedRoomId, OwnedUserId, RoomVersionId};
+use ruma_common::{
+    room::RoomType, room_version_rules::RedactionRules, OwnedEventId, OwnedRoomId, OwnedUserId,
+    RoomVersionId,
+};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -80,29 +83,26 @@ impl RoomCreateEventContent {
             room_type: None,
         }
     }
+
+    /// Whether the room is a space.
+    pub fn is_space(&self) -> bool {
+        self.room_type == Some(RoomType::Space)
+    }
 }
 
 impl RedactContent for RoomCreateEventContent {
     type Redacted = RedactedRoomCreateEventContent;
 
-    fn redact(self, version: &RoomVersionId) -> Self::Redacted {
-        #[allow(deprecated)]
-        match version {
-            RoomVersionId::V1
-            | RoomVersionId::V2
-            | RoomVersionId::V3
-            | RoomVersionId::V4
-            | RoomVersionId::V5
-            | RoomVersionId::V6
-            | RoomVersionId::V7
-            | RoomVersionId::V8
-            | RoomVersionId::V9
-            | RoomVersionId::V10 => Self {
+    fn redact(self, rules: &RedactionRules) -> Self::Redacted {
+        if rules.keep_room_create_content {
+            self
+        } else {
+            #[allow(deprecated)]
+            Self {
                 room_version: default_room_version_id(),
                 creator: self.creator,
                 ..Self::new_v11()
-            },
-            _ => self,
+            }
         }
     }
 }
@@ -221,10 +221,24 @@ mod tests {
         });
 
         let content = from_json_value::<RoomCreateEventContent>(json).unwrap();
+        assert!(content.is_space());
         assert_eq!(content.creator.unwrap(), "@carl:example.com");
         assert!(content.federate);
         assert_eq!(content.room_version, RoomVersionId::V4);
         assert_matches!(content.predecessor, None);
         assert_eq!(content.room_type, Some(RoomType::Space));
     }
+
+    #[test]
+    fn custom_room_type_deserialization() {
+        let json = json!({
+            "room_version": "11",
+            "type": "dev.ruma.custom"
+        });
+
+        let content = from_json_value::<RoomCreateEventContent>(json).unwrap();
+        assert_eq!(content.room_type.as_ref().map(RoomType::as_str), Some("dev.ruma.custom"));
+        assert!(!content.is_space());
+        assert_eq!(to_json_value(&content).unwrap()["type"], "dev.ruma.custom");
+    }
 }
@@ -5,7 +5,7 @@
 use std::{borrow::Cow, collections::BTreeMap};
 
 use js_int::UInt;
-use ruma_common::{serde::JsonObject, OwnedDeviceId, OwnedEventId};
+use ruma_common::{serde::JsonObject, EventEncryptionAlgorithm, OwnedDeviceId, OwnedEventId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +13,7 @@ use super::message;
 use crate::relation::{Annotation, CustomRelation, InReplyTo, Reference, RelationType, Thread};
 
 mod relation_serde;
+mod scheme_serde;
 
 /// The content of an `m.room.encrypted` event.
 #[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
@@ -65,17 +66,58 @@ impl From<EncryptedEventScheme> for ToDeviceRoomEncryptedEventContent {
 }
 
 /// The encryption scheme for `RoomEncryptedEventContent`.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+///
+/// The variant is selected by the `algorithm` field. Events using an unknown algorithm are
+/// deserialized without error into a hidden custom variant, which can be identified via
+/// [`EncryptedEventScheme::algorithm()`].
+#[derive(Clone, Debug)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
-#[serde(tag = "algorithm")]
 pub enum EncryptedEventScheme {
     /// An event encrypted with `m.olm.v1.curve25519-aes-sha2`.
-    #[serde(rename = "m.olm.v1.curve25519-aes-sha2")]
     OlmV1Curve25519AesSha2(OlmV1Curve25519AesSha2Content),
 
     /// An event encrypted with `m.megolm.v1.aes-sha2`.
-    #[serde(rename = "m.megolm.v1.aes-sha2")]
     MegolmV1AesSha2(MegolmV1AesSha2Content),
+
+    /// An event encrypted with an unknown algorithm.
+    #[doc(hidden)]
+    _Custom(_CustomContent),
+}
+
+impl EncryptedEventScheme {
+    /// Returns the algorithm of this encryption scheme.
+    pub fn algorithm(&self) -> EventEncryptionAlgorithm {
+        match self {
+            Self::OlmV1Curve25519AesSha2(_) => EventEncryptionAlgorithm::OlmV1Curve25519AesSha2,
+            Self::MegolmV1AesSha2(_) => EventEncryptionAlgorithm::MegolmV1AesSha2,
+            Self::_Custom(c) => c.algorithm().into(),
+        }
+    }
+}
+
+/// Algorithm-specific content of an event encrypted with an unknown algorithm.
+#[doc(hidden)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[allow(clippy::exhaustive_structs)]
+pub struct _CustomContent {
+    /// The name of the algorithm.
+    algorithm: String,
+
+    /// The additional fields that the algorithm contains.
+    #[serde(flatten)]
+    data: JsonObject,
+}
+
+impl _CustomContent {
+    /// The name of the algorithm.
+    pub fn algorithm(&self) -> &str {
+        &self.algorithm
+    }
+
+    /// The additional fields that the algorithm contains.
+    pub fn data(&self) -> &JsonObject {
+        &self.data
+    }
 }
 
 /// Relationship information about an encrypted event.
@@ -271,7 +313,7 @@ impl From<MegolmV1AesSha2ContentInit> for MegolmV1AesSha2Content {
 mod tests {
     use assert_matches2::assert_matches;
     use js_int::uint;
-    use ruma_common::{owned_event_id, serde::Raw};
+    use ruma_common::{owned_event_id, serde::Raw, EventEncryptionAlgorithm};
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
     use super::{
@@ -363,6 +405,38 @@ mod tests {
         assert_matches!(content.relates_to, None);
     }
 
+    #[test]
+    fn custom_algorithm_roundtrip() {
+        let json_data = json!({
+            "algorithm": "dev.ruma.custom.v1",
+            "ciphertext": "ciphertext",
+            "custom_field": 42,
+        });
+
+        let content: RoomEncryptedEventContent = from_json_value(json_data.clone()).unwrap();
+        assert_eq!(content.scheme.algorithm().as_str(), "dev.ruma.custom.v1");
+        assert!(content.relates_to.is_none());
+
+        assert_matches!(&content.scheme, EncryptedEventScheme::_Custom(custom));
+        assert_eq!(custom.algorithm(), "dev.ruma.custom.v1");
+        assert_eq!(custom.data().len(), 2);
+        assert_eq!(custom.data()["custom_field"], 42);
+
+        assert_eq!(to_json_value(&content).unwrap(), json_data);
+    }
+
+    #[test]
+    fn algorithm() {
+        let json_data = json!({
+            "sender_key": "test_key",
+            "ciphertext": {},
+            "algorithm": "m.olm.v1.curve25519-aes-sha2"
+        });
+        let content: RoomEncryptedEventContent = from_json_value(json_data).unwrap();
+
+        assert_eq!(content.scheme.algorithm(), EventEncryptionAlgorithm::OlmV1Curve25519AesSha2);
+    }
+
     #[test]
     fn deserialization_failure() {
         from_json_value::<Raw<RoomEncryptedEventContent>>(
@@ -0,0 +1,62 @@
+//! `Serialize` and `Deserialize` implementations for `EncryptedEventScheme`.
+
+use ruma_common::serde::JsonObject;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value as JsonValue;
+
+use super::{
+    EncryptedEventScheme, MegolmV1AesSha2Content, OlmV1Curve25519AesSha2Content, _CustomContent,
+};
+
+impl<'de> Deserialize<'de> for EncryptedEventScheme {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // The scheme is flattened into the event content, so it can't be deserialized from a
+        // `RawValue`.
+        let mut data = JsonObject::deserialize(deserializer)?;
+        let algorithm = match data.remove("algorithm") {
+            Some(JsonValue::String(algorithm)) => algorithm,
+            Some(_) => return Err(de::Error::custom("`algorithm` must be a string")),
+            None => return Err(de::Error::missing_field("algorithm")),
+        };
+
+        Ok(match algorithm.as_str() {
+            "m.olm.v1.curve25519-aes-sha2" => Self::OlmV1Curve25519AesSha2(
+                serde_json::from_value(data.into()).map_err(de::Error::custom)?,
+            ),
+            "m.megolm.v1.aes-sha2" => Self::MegolmV1AesSha2(
+                serde_json::from_value(data.into()).map_err(de::Error::custom)?,
+            ),
+            _ => Self::_Custom(_CustomContent { algorithm, data }),
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "algorithm")]
+enum EncryptedEventSchemeSerHelper<'a> {
+    #[serde(rename = "m.olm.v1.curve25519-aes-sha2")]
+    OlmV1Curve25519AesSha2(&'a OlmV1Curve25519AesSha2Content),
+
+    #[serde(rename = "m.megolm.v1.aes-sha2")]
+    MegolmV1AesSha2(&'a MegolmV1AesSha2Content),
+}
+
+impl Serialize for EncryptedEventScheme {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::OlmV1Curve25519AesSha2(c) => {
+                EncryptedEventSchemeSerHelper::OlmV1Curve25519AesSha2(c).serialize(serializer)
+            }
+            Self::MegolmV1AesSha2(c) => {
+                EncryptedEventSchemeSerHelper::MegolmV1AesSha2(c).serialize(serializer)
+            }
+            Self::_Custom(c) => c.serialize(serializer),
+        }
+    }
+}
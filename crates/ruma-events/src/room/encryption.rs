@@ -2,6 +2,8 @@
 //!
 //! [`m.room.encryption`]: https://spec.matrix.org/latest/client-server-api/#mroomencryption
 
+use std::time::Duration;
+
 use js_int::{uint, UInt};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
@@ -45,11 +47,93 @@ impl RoomEncryptionEventContent {
     /// Note that changing the values of the fields is not a breaking change and you shouldn't rely
     /// on those specific values.
     pub fn with_recommended_defaults() -> Self {
-        // Defaults defined at <https://spec.matrix.org/latest/client-server-api/#mroomencryption>
         Self {
             algorithm: EventEncryptionAlgorithm::MegolmV1AesSha2,
-            rotation_period_ms: Some(uint!(604_800_000)),
-            rotation_period_msgs: Some(uint!(100)),
+            rotation_period_ms: Some(default_rotation_period_ms()),
+            rotation_period_msgs: Some(default_rotation_period_msgs()),
         }
     }
+
+    /// Whether the algorithm of this event can be used to encrypt messages sent in a room.
+    ///
+    /// The only algorithm allowed by the specification is `m.megolm.v1.aes-sha2`, messages
+    /// should not be sent in rooms that use another algorithm.
+    pub fn is_algorithm_supported(&self) -> bool {
+        self.algorithm == EventEncryptionAlgorithm::MegolmV1AesSha2
+    }
+
+    /// How long the session should be used before changing it.
+    ///
+    /// Returns the recommended default of a week if `rotation_period_ms` is not set.
+    pub fn rotation_period(&self) -> Duration {
+        Duration::from_millis(
+            self.rotation_period_ms.unwrap_or_else(default_rotation_period_ms).into(),
+        )
+    }
+
+    /// How many messages should be sent before changing the session.
+    ///
+    /// Returns the recommended default of 100 messages if `rotation_period_msgs` is not set.
+    pub fn rotation_period_messages(&self) -> UInt {
+        self.rotation_period_msgs.unwrap_or_else(default_rotation_period_msgs)
+    }
+}
+
+// Defaults defined at <https://spec.matrix.org/latest/client-server-api/#mroomencryption>
+fn default_rotation_period_ms() -> UInt {
+    uint!(604_800_000)
+}
+
+fn default_rotation_period_msgs() -> UInt {
+    uint!(100)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use js_int::uint;
+    use serde_json::{from_value as from_json_value, json};
+
+    use super::RoomEncryptionEventContent;
+    use crate::EventEncryptionAlgorithm;
+
+    #[test]
+    fn default_rotation_periods() {
+        let content = from_json_value::<RoomEncryptionEventContent>(json!({
+            "algorithm": "m.megolm.v1.aes-sha2",
+        }))
+        .unwrap();
+
+        assert!(content.is_algorithm_supported());
+        assert_eq!(content.rotation_period(), Duration::from_secs(7 * 24 * 60 * 60));
+        assert_eq!(content.rotation_period_messages(), uint!(100));
+    }
+
+    #[test]
+    fn custom_rotation_periods() {
+        let content = from_json_value::<RoomEncryptionEventContent>(json!({
+            "algorithm": "m.megolm.v1.aes-sha2",
+            "rotation_period_ms": 3_600_000,
+            "rotation_period_msgs": 10,
+        }))
+        .unwrap();
+
+        assert_eq!(content.rotation_period(), Duration::from_secs(60 * 60));
+        assert_eq!(content.rotation_period_messages(), uint!(10));
+    }
+
+    #[test]
+    fn unsupported_algorithm() {
+        let content = from_json_value::<RoomEncryptionEventContent>(json!({
+            "algorithm": "dev.ruma.custom",
+        }))
+        .unwrap();
+        assert_eq!(content.algorithm.as_str(), "dev.ruma.custom");
+        assert!(!content.is_algorithm_supported());
+
+        let content =
+            RoomEncryptionEventContent::new(EventEncryptionAlgorithm::OlmV1Curve25519AesSha2);
+        assert!(!content.is_algorithm_supported());
+    }
 }
@@ -6,9 +6,9 @@ use std::collections::BTreeMap;
 
 use js_int::Int;
 use ruma_common::{
+    room_version_rules::RedactionRules,
     serde::{CanBeEmpty, Raw, StringEnum},
-    OwnedMxcUri, OwnedServerName, OwnedServerSigningKeyId, OwnedTransactionId, OwnedUserId,
-    RoomVersionId, UserId,
+    OwnedMxcUri, OwnedServerName, OwnedServerSigningKeyId, OwnedTransactionId, OwnedUserId, UserId,
 };
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
@@ -18,10 +18,14 @@ use crate::{
     PrivOwnedStr, RedactContent, RedactedStateEventContent, StateEventType,
 };
 
+mod borrowed;
 mod change;
 
 use self::change::membership_change;
-pub use self::change::{Change, MembershipChange, MembershipDetails};
+pub use self::{
+    borrowed::RoomMemberEventContentRef,
+    change::{Change, MembershipChange, MembershipDetails},
+};
 
 /// The content of an `m.room.member` event.
 ///
@@ -89,7 +93,11 @@ pub struct RoomMemberEventContent {
     /// This uses the unstable prefix in
     /// [MSC2448](https://github.com/matrix-org/matrix-spec-proposals/pull/2448).
     #[cfg(feature = "unstable-msc2448")]
-    #[serde(rename = "xyz.amorgan.blurhash", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        flatten,
+        with = "crate::room::blurhash_serde",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub blurhash: Option<String>,
 
     /// User-supplied text for why their membership has changed.
@@ -163,20 +171,16 @@ impl RoomMemberEventContent {
 impl RedactContent for RoomMemberEventContent {
     type Redacted = RedactedRoomMemberEventContent;
 
-    fn redact(self, version: &RoomVersionId) -> RedactedRoomMemberEventContent {
+    fn redact(self, rules: &RedactionRules) -> RedactedRoomMemberEventContent {
         RedactedRoomMemberEventContent {
             membership: self.membership,
-            third_party_invite: self.third_party_invite.and_then(|i| i.redact(version)),
-            join_authorized_via_users_server: match version {
-                RoomVersionId::V1
-                | RoomVersionId::V2
-                | RoomVersionId::V3
-                | RoomVersionId::V4
-                | RoomVersionId::V5
-                | RoomVersionId::V6
-                | RoomVersionId::V7
-                | RoomVersionId::V8 => None,
-                _ => self.join_authorized_via_users_server,
+            third_party_invite: self.third_party_invite.and_then(|i| i.redact(rules)),
+            join_authorized_via_users_server: if rules
+                .keep_room_member_join_authorised_via_users_server
+            {
+                self.join_authorized_via_users_server
+            } else {
+                None
             },
         }
     }
@@ -328,22 +332,12 @@ impl ThirdPartyInvite {
 
     /// Transform `self` into a redacted form (removing most or all fields) according to the spec.
     ///
-    /// Returns `None` if the field for this object was redacted in the given room version,
+    /// Returns `None` if the field for this object was redacted according to the given rules,
     /// otherwise returns the redacted form.
-    fn redact(self, version: &RoomVersionId) -> Option<RedactedThirdPartyInvite> {
-        match version {
-            RoomVersionId::V1
-            | RoomVersionId::V2
-            | RoomVersionId::V3
-            | RoomVersionId::V4
-            | RoomVersionId::V5
-            | RoomVersionId::V6
-            | RoomVersionId::V7
-            | RoomVersionId::V8
-            | RoomVersionId::V9
-            | RoomVersionId::V10 => None,
-            _ => Some(RedactedThirdPartyInvite { signed: self.signed }),
-        }
+    fn redact(self, rules: &RedactionRules) -> Option<RedactedThirdPartyInvite> {
+        rules
+            .keep_room_member_third_party_invite_signed
+            .then_some(RedactedThirdPartyInvite { signed: self.signed })
     }
 }
 
@@ -586,8 +580,8 @@ mod tests {
     };
     use serde_json::{from_value as from_json_value, json};
 
-    use super::{MembershipState, RoomMemberEventContent};
-    use crate::OriginalStateEvent;
+    use super::{MembershipState, RoomMemberEventContent, RoomMemberEventContentRef};
+    use crate::{room::member::MembershipChange, OriginalStateEvent};
 
     #[test]
     fn serde_with_no_prev_content() {
@@ -820,4 +814,44 @@ mod tests {
             Some(user_id!("@notcarl:example.com"))
         );
     }
+
+    #[test]
+    fn deserialize_borrowed_content() {
+        use std::borrow::Cow;
+
+        let json = r#"{
+            "avatar_url": "mxc://example.org/SEsfnsuifSDFSSEF",
+            "displayname": "Alice \\o/",
+            "membership": "join",
+            "join_authorised_via_users_server": "@notcarl:example.com"
+        }"#;
+
+        let content = serde_json::from_str::<RoomMemberEventContentRef<'_>>(json).unwrap();
+
+        let prev_content =
+            serde_json::from_str::<RoomMemberEventContentRef<'_>>(r#"{ "membership": "invite" }"#)
+                .unwrap();
+        assert_matches!(
+            content.membership_change(
+                Some(prev_content.details()),
+                user_id!("@alice:example.org"),
+                user_id!("@alice:example.org"),
+            ),
+            MembershipChange::InvitationAccepted
+        );
+
+        assert_eq!(content.membership, MembershipState::Join);
+        assert_matches!(
+            content.avatar_url,
+            Some(Cow::Borrowed("mxc://example.org/SEsfnsuifSDFSSEF"))
+        );
+        assert_matches!(content.displayname, Some(Cow::Owned(displayname)));
+        assert_eq!(displayname, r"Alice \o/");
+        assert_eq!(content.is_direct, None);
+        assert_matches!(content.reason, None);
+        assert_matches!(
+            content.join_authorized_via_users_server,
+            Some(Cow::Borrowed("@notcarl:example.com"))
+        );
+    }
 }
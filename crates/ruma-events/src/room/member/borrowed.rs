@@ -0,0 +1,87 @@
+use std::borrow::Cow;
+
+use ruma_common::{MxcUri, UserId};
+use serde::Deserialize;
+
+use super::{membership_change, MembershipChange, MembershipDetails, MembershipState};
+
+/// A borrowed view of the content of an `m.room.member` event.
+///
+/// This contains the most commonly inspected fields of [`RoomMemberEventContent`], and borrows
+/// them from the JSON input when possible instead of allocating, which makes it cheaper to
+/// deserialize. Strings that contain escape sequences are still allocated.
+///
+/// It can be deserialized from a `Raw<RoomMemberEventContent>` with [`Raw::deserialize_as()`], or
+/// from the `content` of a raw event with [`Raw::get_field()`].
+///
+/// [`RoomMemberEventContent`]: super::RoomMemberEventContent
+/// [`Raw::deserialize_as()`]: ruma_common::serde::Raw::deserialize_as
+/// [`Raw::get_field()`]: ruma_common::serde::Raw::get_field
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct RoomMemberEventContentRef<'a> {
+    /// The membership state of this user.
+    pub membership: MembershipState,
+
+    /// The avatar URL for this user, if any.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "ruma_common::serde::deserialize_optional_cow_str"
+    )]
+    pub avatar_url: Option<Cow<'a, str>>,
+
+    /// The display name for this user, if any.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "ruma_common::serde::deserialize_optional_cow_str"
+    )]
+    pub displayname: Option<Cow<'a, str>>,
+
+    /// Flag indicating whether the room containing this event was created with the intention of
+    /// being a direct chat.
+    pub is_direct: Option<bool>,
+
+    /// User-supplied text for why their membership has changed.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "ruma_common::serde::deserialize_optional_cow_str"
+    )]
+    pub reason: Option<Cow<'a, str>>,
+
+    /// Arbitrarily chosen `UserId` (MxID) of a local user who can send an invite.
+    #[serde(
+        borrow,
+        default,
+        rename = "join_authorised_via_users_server",
+        deserialize_with = "ruma_common::serde::deserialize_optional_cow_str"
+    )]
+    pub join_authorized_via_users_server: Option<Cow<'a, str>>,
+}
+
+impl<'a> RoomMemberEventContentRef<'a> {
+    /// Obtain the details about this event that are required to calculate a membership change.
+    pub fn details(&self) -> MembershipDetails<'_> {
+        MembershipDetails {
+            avatar_url: self.avatar_url.as_deref().map(<&MxcUri>::from),
+            displayname: self.displayname.as_deref(),
+            membership: &self.membership,
+        }
+    }
+
+    /// Helper function for membership change.
+    ///
+    /// Works like [`RoomMemberEventContent::membership_change()`].
+    ///
+    /// [`RoomMemberEventContent::membership_change()`]: super::RoomMemberEventContent::membership_change
+    pub fn membership_change<'b>(
+        &'b self,
+        prev_details: Option<MembershipDetails<'b>>,
+        sender: &UserId,
+        state_key: &UserId,
+    ) -> MembershipChange<'b> {
+        membership_change(self.details(), prev_details, sender, state_key)
+    }
+}
@@ -24,10 +24,12 @@ use crate::{
 };
 
 mod audio;
+mod borrowed;
 mod content_serde;
 mod emote;
 mod file;
 mod image;
+#[cfg(feature = "key-verification")]
 mod key_verification_request;
 mod location;
 mod notice;
@@ -44,12 +46,14 @@ mod without_relation;
 pub use self::audio::{
     UnstableAmplitude, UnstableAudioDetailsContentBlock, UnstableVoiceContentBlock,
 };
+#[cfg(feature = "key-verification")]
+pub use self::key_verification_request::KeyVerificationRequestEventContent;
 pub use self::{
     audio::{AudioInfo, AudioMessageEventContent},
+    borrowed::RoomMessageEventContentRef,
     emote::EmoteMessageEventContent,
     file::{FileInfo, FileMessageEventContent},
     image::ImageMessageEventContent,
-    key_verification_request::KeyVerificationRequestEventContent,
     location::{LocationInfo, LocationMessageEventContent},
     notice::NoticeMessageEventContent,
     relation::{Relation, RelationWithoutReplacement},
@@ -497,6 +501,7 @@ pub enum MessageType {
     Video(VideoMessageEventContent),
 
     /// A request to initiate a key verification.
+    #[cfg(feature = "key-verification")]
     VerificationRequest(KeyVerificationRequestEventContent),
 
     /// A custom message.
@@ -538,6 +543,7 @@ impl MessageType {
             "m.server_notice" => Self::ServerNotice(deserialize_variant(body, data)?),
             "m.text" => Self::Text(deserialize_variant(body, data)?),
             "m.video" => Self::Video(deserialize_variant(body, data)?),
+            #[cfg(feature = "key-verification")]
             "m.key.verification.request" => {
                 Self::VerificationRequest(deserialize_variant(body, data)?)
             }
@@ -605,6 +611,7 @@ impl MessageType {
             Self::ServerNotice(_) => "m.server_notice",
             Self::Text(_) => "m.text",
             Self::Video(_) => "m.video",
+            #[cfg(feature = "key-verification")]
             Self::VerificationRequest(_) => "m.key.verification.request",
             Self::_Custom(c) => &c.msgtype,
         }
@@ -622,6 +629,7 @@ impl MessageType {
             MessageType::ServerNotice(m) => &m.body,
             MessageType::Text(m) => &m.body,
             MessageType::Video(m) => &m.body,
+            #[cfg(feature = "key-verification")]
             MessageType::VerificationRequest(m) => &m.body,
             MessageType::_Custom(m) => &m.body,
         }
@@ -655,6 +663,7 @@ impl MessageType {
             Self::ServerNotice(d) => Cow::Owned(serialize(d)),
             Self::Text(d) => Cow::Owned(serialize(d)),
             Self::Video(d) => Cow::Owned(serialize(d)),
+            #[cfg(feature = "key-verification")]
             Self::VerificationRequest(d) => Cow::Owned(serialize(d)),
             Self::_Custom(c) => Cow::Borrowed(&c.data),
         }
@@ -713,6 +722,7 @@ impl MessageType {
                 MessageType::Location(m) => (&mut m.body, None),
                 MessageType::ServerNotice(m) => (&mut m.body, None),
                 MessageType::Video(m) => (&mut m.body, None),
+                #[cfg(feature = "key-verification")]
                 MessageType::VerificationRequest(m) => (&mut m.body, None),
                 MessageType::_Custom(m) => (&mut m.body, None),
             }
@@ -755,6 +765,7 @@ impl MessageType {
                 MessageType::Location(m) => (&mut m.body, None),
                 MessageType::ServerNotice(m) => (&mut m.body, None),
                 MessageType::Video(m) => (&mut m.body, None),
+                #[cfg(feature = "key-verification")]
                 MessageType::VerificationRequest(m) => (&mut m.body, None),
                 MessageType::_Custom(m) => (&mut m.body, None),
             }
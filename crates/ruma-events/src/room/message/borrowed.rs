@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use serde::Deserialize;
+use serde_json::value::RawValue as RawJsonValue;
+
+/// A borrowed view of the content of an `m.room.message` event.
+///
+/// This only contains the fields that are common to all message types, and borrows them from the
+/// JSON input when possible instead of allocating, which makes it cheaper to deserialize than
+/// [`RoomMessageEventContent`] when only those fields need to be inspected. Strings that contain
+/// escape sequences are still allocated.
+///
+/// It can be deserialized from a `Raw<RoomMessageEventContent>` with [`Raw::deserialize_as()`], or
+/// from the `content` of a raw event with [`Raw::get_field()`].
+///
+/// [`RoomMessageEventContent`]: super::RoomMessageEventContent
+/// [`Raw::deserialize_as()`]: ruma_common::serde::Raw::deserialize_as
+/// [`Raw::get_field()`]: ruma_common::serde::Raw::get_field
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct RoomMessageEventContentRef<'a> {
+    /// The type of the message.
+    #[serde(borrow)]
+    pub msgtype: Cow<'a, str>,
+
+    /// The body of the message.
+    #[serde(borrow)]
+    pub body: Cow<'a, str>,
+
+    /// The format used in `formatted_body`, if any.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "ruma_common::serde::deserialize_optional_cow_str"
+    )]
+    pub format: Option<Cow<'a, str>>,
+
+    /// The formatted version of the body, if any.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "ruma_common::serde::deserialize_optional_cow_str"
+    )]
+    pub formatted_body: Option<Cow<'a, str>>,
+
+    /// The raw JSON of the `m.relates_to` field of the message, if any.
+    #[serde(borrow, default, rename = "m.relates_to")]
+    pub relates_to: Option<&'a RawJsonValue>,
+}
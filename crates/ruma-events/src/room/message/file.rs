@@ -85,6 +85,18 @@ pub struct FileInfo {
         skip_serializing_if = "Option::is_none"
     )]
     pub thumbnail_source: Option<MediaSource>,
+
+    /// The [BlurHash](https://blurha.sh) for the thumbnail of this file.
+    ///
+    /// This uses the unstable prefix in
+    /// [MSC2448](https://github.com/matrix-org/matrix-spec-proposals/pull/2448).
+    #[cfg(feature = "unstable-msc2448")]
+    #[serde(
+        flatten,
+        with = "crate::room::blurhash_serde",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub blurhash: Option<String>,
 }
 
 impl FileInfo {
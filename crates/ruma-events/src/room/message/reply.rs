@@ -34,6 +34,7 @@ impl<'a> From<&'a OriginalRoomMessageEvent> for OriginalEventData<'a> {
             MessageType::ServerNotice(c) => (&*c.body, None, false),
             MessageType::Text(c) => (&*c.body, c.formatted.as_ref(), false),
             MessageType::Video(_) => ("sent a video.", None, false),
+            #[cfg(feature = "key-verification")]
             MessageType::VerificationRequest(c) => (&*c.body, None, false),
             MessageType::_Custom(c) => (&*c.body, None, false),
         };
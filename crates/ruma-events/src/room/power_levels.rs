@@ -8,7 +8,8 @@ use js_int::{int, Int};
 use ruma_common::{
     power_levels::{default_power_level, NotificationPowerLevels},
     push::PushConditionPowerLevelsCtx,
-    OwnedUserId, RoomVersionId, UserId,
+    room_version_rules::RedactionRules,
+    OwnedUserId, UserId,
 };
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
@@ -126,6 +127,16 @@ impl RoomPowerLevelsEventContent {
             notifications: NotificationPowerLevels::default(),
         }
     }
+
+    /// Creates a new `RoomPowerLevelsEventContent` for a room created by the given user.
+    ///
+    /// This uses the default values of the specification, and gives the creator the power level
+    /// `100`, like homeservers do when they create a room.
+    pub fn with_creator(creator: OwnedUserId) -> Self {
+        let mut content = Self::new();
+        content.users.insert(creator, int!(100));
+        content
+    }
 }
 
 impl Default for RoomPowerLevelsEventContent {
@@ -137,7 +148,7 @@ impl Default for RoomPowerLevelsEventContent {
 impl RedactContent for RoomPowerLevelsEventContent {
     type Redacted = RedactedRoomPowerLevelsEventContent;
 
-    fn redact(self, version: &RoomVersionId) -> Self::Redacted {
+    fn redact(self, rules: &RedactionRules) -> Self::Redacted {
         let Self {
             ban,
             events,
@@ -151,19 +162,7 @@ impl RedactContent for RoomPowerLevelsEventContent {
             ..
         } = self;
 
-        let invite = match version {
-            RoomVersionId::V1
-            | RoomVersionId::V2
-            | RoomVersionId::V3
-            | RoomVersionId::V4
-            | RoomVersionId::V5
-            | RoomVersionId::V6
-            | RoomVersionId::V7
-            | RoomVersionId::V8
-            | RoomVersionId::V9
-            | RoomVersionId::V10 => int!(0),
-            _ => invite,
-        };
+        let invite = if rules.keep_room_power_levels_invite { invite } else { int!(0) };
 
         RedactedRoomPowerLevelsEventContent {
             ban,
@@ -751,4 +750,20 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn with_creator() {
+        let creator = user_id!("@alice:example.com");
+        let content = RoomPowerLevelsEventContent::with_creator(creator.to_owned());
+
+        assert_eq!(content.users, btreemap! { creator.to_owned() => int!(100) });
+        assert_eq!(
+            to_json_value(&content).unwrap(),
+            json!({
+                "users": {
+                    "@alice:example.com": 100,
+                },
+            })
+        );
+    }
 }
@@ -0,0 +1,194 @@
+//! Types for the [`m.room_key.withheld`] event.
+//!
+//! [`m.room_key.withheld`]: https://spec.matrix.org/latest/client-server-api/#mroom_keywithheld
+
+use ruma_common::{serde::StringEnum, EventEncryptionAlgorithm, OwnedRoomId};
+use ruma_macros::EventContent;
+use serde::{Deserialize, Serialize};
+
+use crate::PrivOwnedStr;
+
+/// The content of an `m.room_key.withheld` event.
+///
+/// Sent to devices to indicate that the sender is not going to share the keys of a session with
+/// them, or that a new Olm session could not be established with them.
+#[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+#[ruma_event(type = "m.room_key.withheld", kind = ToDevice)]
+pub struct ToDeviceRoomKeyWithheldEventContent {
+    /// The encryption algorithm the key in this event is to be used with.
+    ///
+    /// Must be `m.megolm.v1.aes-sha2`.
+    pub algorithm: EventEncryptionAlgorithm,
+
+    /// The reason why the key was withheld.
+    pub code: WithheldCode,
+
+    /// A human-readable reason for why the key was not sent.
+    ///
+    /// The receiving client should only use this string if it does not understand the `code`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// The room for the key.
+    ///
+    /// Required if `code` is not `m.no_olm`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub room_id: Option<OwnedRoomId>,
+
+    /// The Curve25519 key of the session creator.
+    pub sender_key: String,
+
+    /// The ID of the session that the key is for.
+    ///
+    /// Required if `code` is not `m.no_olm`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+}
+
+impl ToDeviceRoomKeyWithheldEventContent {
+    /// Creates a new `ToDeviceRoomKeyWithheldEventContent` for a withheld session with the given
+    /// algorithm, code, room ID, sender key and session ID.
+    pub fn new(
+        algorithm: EventEncryptionAlgorithm,
+        code: WithheldCode,
+        room_id: OwnedRoomId,
+        sender_key: String,
+        session_id: String,
+    ) -> Self {
+        Self {
+            algorithm,
+            code,
+            reason: None,
+            room_id: Some(room_id),
+            sender_key,
+            session_id: Some(session_id),
+        }
+    }
+
+    /// Creates a new `ToDeviceRoomKeyWithheldEventContent` with the `m.no_olm` code and the given
+    /// algorithm and sender key.
+    pub fn no_olm(algorithm: EventEncryptionAlgorithm, sender_key: String) -> Self {
+        Self {
+            algorithm,
+            code: WithheldCode::NoOlm,
+            reason: None,
+            room_id: None,
+            sender_key,
+            session_id: None,
+        }
+    }
+}
+
+/// The reason why a key was withheld.
+#[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/doc/string_enum.md"))]
+#[derive(Clone, PartialEq, Eq, StringEnum)]
+#[ruma_enum(rename_all = "m.snake_case")]
+#[non_exhaustive]
+pub enum WithheldCode {
+    /// The user or device was blacklisted.
+    Blacklisted,
+
+    /// The user or device was not verified, and the sender is only sharing keys with verified
+    /// users or devices.
+    Unverified,
+
+    /// The user or device is not allowed to have the key.
+    ///
+    /// For example, this could be sent in response to a key request if the user or device was
+    /// not in the room when the original message was sent.
+    Unauthorised,
+
+    /// The sender was unable to retrieve the key, or has not got the key for the session anymore.
+    Unavailable,
+
+    /// An Olm session could not be established.
+    NoOlm,
+
+    #[doc(hidden)]
+    _Custom(PrivOwnedStr),
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches2::assert_matches;
+    use ruma_common::owned_room_id;
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::{ToDeviceRoomKeyWithheldEventContent, WithheldCode};
+    use crate::{AnyToDeviceEvent, EventEncryptionAlgorithm};
+
+    #[test]
+    fn serialization() {
+        let content = ToDeviceRoomKeyWithheldEventContent::new(
+            EventEncryptionAlgorithm::MegolmV1AesSha2,
+            WithheldCode::Blacklisted,
+            owned_room_id!("!Cuyf34gef24t:localhost"),
+            "RF3s+E7RkTQTGF2d8Deol0FkQvgII2aJDf3/Jp5mxVU".to_owned(),
+            "X3lUlvLELLYxeTx4yOVu6UDpasGEVO0Jbu+QFnm0cKQ".to_owned(),
+        );
+
+        assert_eq!(
+            to_json_value(content).unwrap(),
+            json!({
+                "algorithm": "m.megolm.v1.aes-sha2",
+                "code": "m.blacklisted",
+                "room_id": "!Cuyf34gef24t:localhost",
+                "sender_key": "RF3s+E7RkTQTGF2d8Deol0FkQvgII2aJDf3/Jp5mxVU",
+                "session_id": "X3lUlvLELLYxeTx4yOVu6UDpasGEVO0Jbu+QFnm0cKQ",
+            })
+        );
+
+        let content = ToDeviceRoomKeyWithheldEventContent::no_olm(
+            EventEncryptionAlgorithm::MegolmV1AesSha2,
+            "RF3s+E7RkTQTGF2d8Deol0FkQvgII2aJDf3/Jp5mxVU".to_owned(),
+        );
+
+        assert_eq!(
+            to_json_value(content).unwrap(),
+            json!({
+                "algorithm": "m.megolm.v1.aes-sha2",
+                "code": "m.no_olm",
+                "sender_key": "RF3s+E7RkTQTGF2d8Deol0FkQvgII2aJDf3/Jp5mxVU",
+            })
+        );
+    }
+
+    #[test]
+    fn deserialization() {
+        let json = json!({
+            "content": {
+                "algorithm": "m.megolm.v1.aes-sha2",
+                "code": "m.unverified",
+                "reason": "Device not verified",
+                "room_id": "!Cuyf34gef24t:localhost",
+                "sender_key": "RF3s+E7RkTQTGF2d8Deol0FkQvgII2aJDf3/Jp5mxVU",
+                "session_id": "X3lUlvLELLYxeTx4yOVu6UDpasGEVO0Jbu+QFnm0cKQ",
+            },
+            "sender": "@alice:example.org",
+            "type": "m.room_key.withheld",
+        });
+
+        assert_matches!(
+            from_json_value::<AnyToDeviceEvent>(json),
+            Ok(AnyToDeviceEvent::RoomKeyWithheld(ev))
+        );
+        assert_eq!(ev.sender, "@alice:example.org");
+        assert_eq!(ev.content.algorithm, EventEncryptionAlgorithm::MegolmV1AesSha2);
+        assert_eq!(ev.content.code, WithheldCode::Unverified);
+        assert_eq!(ev.content.reason.as_deref(), Some("Device not verified"));
+        assert_eq!(ev.content.room_id.unwrap(), "!Cuyf34gef24t:localhost");
+        assert_eq!(ev.content.sender_key, "RF3s+E7RkTQTGF2d8Deol0FkQvgII2aJDf3/Jp5mxVU");
+        assert_eq!(
+            ev.content.session_id.as_deref(),
+            Some("X3lUlvLELLYxeTx4yOVu6UDpasGEVO0Jbu+QFnm0cKQ")
+        );
+    }
+
+    #[test]
+    fn codes() {
+        assert_eq!(WithheldCode::Unauthorised.as_str(), "m.unauthorised");
+        assert_eq!(WithheldCode::Unavailable.as_str(), "m.unavailable");
+        assert_eq!(WithheldCode::from("m.no_olm"), WithheldCode::NoOlm);
+    }
+}
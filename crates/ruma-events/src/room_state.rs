@@ -0,0 +1,233 @@
+//! A snapshot of the state of a room.
+
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use ruma_common::{serde::Raw, EventId, OwnedEventId};
+use serde::Deserialize;
+
+use crate::{
+    AnyStateEvent, EventContentFromType, RedactContent, RedactedStateEventContent, StateEvent,
+    StateEventType, StaticEventContent, StaticStateEventContent,
+};
+
+/// A snapshot of the state of a room.
+///
+/// This holds one state event for each `(event type, state key)` pair, like the state of a room at
+/// a given event. The events are kept in their raw form, they are only deserialized when they are
+/// accessed with [`RoomState::get()`].
+#[derive(Clone, Debug, Default)]
+pub struct RoomState {
+    events: BTreeMap<StateEventType, BTreeMap<String, (OwnedEventId, Raw<AnyStateEvent>)>>,
+}
+
+impl RoomState {
+    /// Creates an empty `RoomState`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a `RoomState` from the given state events.
+    ///
+    /// If several events have the same event type and state key, the last one is kept.
+    ///
+    /// Returns an error if the `type`, `state_key` or `event_id` of one of the events can't be
+    /// deserialized.
+    pub fn from_events(
+        events: impl IntoIterator<Item = Raw<AnyStateEvent>>,
+    ) -> serde_json::Result<Self> {
+        let mut state = Self::new();
+
+        for event in events {
+            state.insert(event)?;
+        }
+
+        Ok(state)
+    }
+
+    /// Inserts the given state event, replacing the event with the same event type and state key.
+    ///
+    /// Returns the replaced event, if any.
+    ///
+    /// Returns an error if the `type`, `state_key` or `event_id` of the event can't be
+    /// deserialized.
+    pub fn insert(
+        &mut self,
+        event: Raw<AnyStateEvent>,
+    ) -> serde_json::Result<Option<Raw<AnyStateEvent>>> {
+        let StateEventKeyDeHelper { event_type, state_key, event_id } = event.deserialize_as()?;
+
+        Ok(self
+            .events
+            .entry(event_type)
+            .or_default()
+            .insert(state_key, (event_id, event))
+            .map(|(_, event)| event))
+    }
+
+    /// Removes the state event with the given event type and state key.
+    ///
+    /// Returns the removed event, if any.
+    pub fn remove(
+        &mut self,
+        event_type: &StateEventType,
+        state_key: &str,
+    ) -> Option<Raw<AnyStateEvent>> {
+        let events = self.events.get_mut(event_type)?;
+        let (_, event) = events.remove(state_key)?;
+
+        if events.is_empty() {
+            self.events.remove(event_type);
+        }
+
+        Some(event)
+    }
+
+    /// Get the raw state event with the given event type and state key.
+    pub fn get_raw(
+        &self,
+        event_type: &StateEventType,
+        state_key: &str,
+    ) -> Option<&Raw<AnyStateEvent>> {
+        self.get_entry(event_type, state_key).map(|(_, event)| event)
+    }
+
+    /// Get the ID of the state event with the given event type and state key.
+    pub fn get_event_id(&self, event_type: &StateEventType, state_key: &str) -> Option<&EventId> {
+        self.get_entry(event_type, state_key).map(|(event_id, _)| &**event_id)
+    }
+
+    /// Get the state event with the event type of `C` and the given state key, deserialized as a
+    /// [`StateEvent`].
+    ///
+    /// Returns `None` if there is no such event, and `Some(Err(_))` if it can't be deserialized.
+    pub fn get<C, K>(&self, state_key: &K) -> Option<serde_json::Result<StateEvent<C>>>
+    where
+        C: StaticStateEventContent + StaticEventContent + EventContentFromType + RedactContent,
+        C::Redacted: RedactedStateEventContent<StateKey = C::StateKey> + EventContentFromType,
+        C::StateKey: Borrow<K>,
+        K: AsRef<str> + ?Sized,
+    {
+        self.get_raw(&C::TYPE.into(), state_key.as_ref()).map(|event| event.deserialize_as())
+    }
+
+    /// Whether there is a state event with the given event type and state key.
+    pub fn contains(&self, event_type: &StateEventType, state_key: &str) -> bool {
+        self.get_entry(event_type, state_key).is_some()
+    }
+
+    /// The number of state events in this snapshot.
+    pub fn len(&self) -> usize {
+        self.events.values().map(BTreeMap::len).sum()
+    }
+
+    /// Whether this snapshot contains no state events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Iterate over the state events, with their event type and state key.
+    pub fn iter(&self) -> impl Iterator<Item = (&StateEventType, &str, &Raw<AnyStateEvent>)> {
+        self.entries().map(|(event_type, state_key, (_, event))| (event_type, state_key, event))
+    }
+
+    /// Compute the changes from `self` to `other`.
+    ///
+    /// Two events with the same event type and state key are considered equal if they have the
+    /// same event ID.
+    pub fn diff(&self, other: &RoomState) -> RoomStateDiff {
+        let mut diff = RoomStateDiff::default();
+
+        for (event_type, state_key, (event_id, _)) in self.entries() {
+            match other.get_entry(event_type, state_key) {
+                None => {
+                    diff.removed.insert((event_type.clone(), state_key.to_owned()));
+                }
+                Some((other_event_id, _)) if other_event_id != event_id => {
+                    diff.changed.insert((event_type.clone(), state_key.to_owned()));
+                }
+                Some(_) => {}
+            }
+        }
+
+        diff.added.extend(
+            other
+                .entries()
+                .filter(|(event_type, state_key, _)| !self.contains(event_type, state_key))
+                .map(|(event_type, state_key, _)| (event_type.clone(), state_key.to_owned())),
+        );
+
+        diff
+    }
+
+    /// The IDs of the state events, with their event type and state key.
+    ///
+    /// The state maps used by state resolution implementations, like the `StateMap` of
+    /// `ruma-state-res` which is a `HashMap`, can be built from it with
+    /// `.into_iter().collect()`.
+    pub fn event_ids(&self) -> BTreeMap<(StateEventType, String), OwnedEventId> {
+        self.entries()
+            .map(|(event_type, state_key, (event_id, _))| {
+                ((event_type.clone(), state_key.to_owned()), event_id.clone())
+            })
+            .collect()
+    }
+
+    /// Convert this snapshot into a list of state events.
+    ///
+    /// The events can be converted to the state lists used in the federation API with
+    /// [`Raw::into_json()`].
+    pub fn into_events(self) -> Vec<Raw<AnyStateEvent>> {
+        self.events.into_values().flat_map(BTreeMap::into_values).map(|(_, event)| event).collect()
+    }
+
+    fn get_entry(
+        &self,
+        event_type: &StateEventType,
+        state_key: &str,
+    ) -> Option<&(OwnedEventId, Raw<AnyStateEvent>)> {
+        self.events.get(event_type)?.get(state_key)
+    }
+
+    fn entries(
+        &self,
+    ) -> impl Iterator<Item = (&StateEventType, &str, &(OwnedEventId, Raw<AnyStateEvent>))> {
+        self.events.iter().flat_map(|(event_type, events)| {
+            events.iter().map(move |(state_key, entry)| (event_type, state_key.as_str(), entry))
+        })
+    }
+}
+
+/// The changes between two [`RoomState`] snapshots, as returned by [`RoomState::diff()`].
+///
+/// The state is identified by its event type and state key.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RoomStateDiff {
+    /// The state that is only in the new snapshot.
+    pub added: BTreeSet<(StateEventType, String)>,
+
+    /// The state that is in both snapshots, but with a different event.
+    pub changed: BTreeSet<(StateEventType, String)>,
+
+    /// The state that is only in the old snapshot.
+    pub removed: BTreeSet<(StateEventType, String)>,
+}
+
+impl RoomStateDiff {
+    /// Whether there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Helper struct to get the key and ID of a state event.
+#[derive(Deserialize)]
+struct StateEventKeyDeHelper {
+    #[serde(rename = "type")]
+    event_type: StateEventType,
+    state_key: String,
+    event_id: OwnedEventId,
+}
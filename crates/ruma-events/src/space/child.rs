@@ -2,7 +2,9 @@
 //!
 //! [`m.space.child`]: https://spec.matrix.org/latest/client-server-api/#mspacechild
 
-use ruma_common::{MilliSecondsSinceUnixEpoch, OwnedRoomId, OwnedServerName, OwnedUserId};
+use std::cmp::Ordering;
+
+use ruma_common::{MilliSecondsSinceUnixEpoch, OwnedRoomId, OwnedServerName, OwnedUserId, RoomId};
 use ruma_macros::{Event, EventContent};
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +31,8 @@ pub struct SpaceChildEventContent {
     /// not consist solely of ascii characters in the range `\x20` (space) to `\x7E` (`~`), or
     /// consist of more than 50 characters, are forbidden and the field should be ignored if
     /// received.
+    ///
+    /// Use [`SpaceChildEventContent::valid_order()`] to only get a valid `order`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<String>,
 
@@ -49,6 +53,44 @@ impl SpaceChildEventContent {
     pub fn new(via: Vec<OwnedServerName>) -> Self {
         Self { via, order: None, suggested: false }
     }
+
+    /// The `order` of this child, if it is valid.
+    ///
+    /// Returns `None` if there is no `order`, or if it contains characters outside of the range
+    /// `\x20` (space) to `\x7E` (`~`) or more than 50 characters, in which case it must be ignored.
+    pub fn valid_order(&self) -> Option<&str> {
+        self.order
+            .as_deref()
+            .filter(|order| order.len() <= 50 && order.bytes().all(|b| (0x20..=0x7E).contains(&b)))
+    }
+}
+
+impl OriginalSpaceChildEvent {
+    /// Compare the position of this child with `other` in the list of children of a space.
+    ///
+    /// This implements the [ordering of children] of the spec: children are sorted by their valid
+    /// `order` first, with children without it coming last, then by the `origin_server_ts` of their
+    /// `m.space.child` event, then by their room ID.
+    ///
+    /// [ordering of children]: https://spec.matrix.org/latest/client-server-api/#ordering-of-children-within-a-space
+    pub fn cmp_space_order(&self, other: &Self) -> Ordering {
+        cmp_space_children(
+            (&self.content, self.origin_server_ts, &self.state_key),
+            (&other.content, other.origin_server_ts, &other.state_key),
+        )
+    }
+}
+
+impl OriginalSyncSpaceChildEvent {
+    /// Compare the position of this child with `other` in the list of children of a space.
+    ///
+    /// See [`OriginalSpaceChildEvent::cmp_space_order()`] for details.
+    pub fn cmp_space_order(&self, other: &Self) -> Ordering {
+        cmp_space_children(
+            (&self.content, self.origin_server_ts, &self.state_key),
+            (&other.content, other.origin_server_ts, &other.state_key),
+        )
+    }
 }
 
 /// An `m.space.child` event represented as a Stripped State Event with an added `origin_server_ts`
@@ -69,14 +111,56 @@ pub struct HierarchySpaceChildEvent {
     pub origin_server_ts: MilliSecondsSinceUnixEpoch,
 }
 
+impl HierarchySpaceChildEvent {
+    /// Compare the position of this child with `other` in the list of children of a space.
+    ///
+    /// See [`OriginalSpaceChildEvent::cmp_space_order()`] for details.
+    pub fn cmp_space_order(&self, other: &Self) -> Ordering {
+        cmp_space_children(
+            (&self.content, self.origin_server_ts, &self.state_key),
+            (&other.content, other.origin_server_ts, &other.state_key),
+        )
+    }
+}
+
+/// Compare two space children, with the content, timestamp and room ID of their `m.space.child`
+/// event.
+fn cmp_space_children(
+    (a_content, a_ts, a_room_id): (&SpaceChildEventContent, MilliSecondsSinceUnixEpoch, &RoomId),
+    (b_content, b_ts, b_room_id): (&SpaceChildEventContent, MilliSecondsSinceUnixEpoch, &RoomId),
+) -> Ordering {
+    let order = match (a_content.valid_order(), b_content.valid_order()) {
+        (Some(a_order), Some(b_order)) => a_order.cmp(b_order),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    };
+
+    order.then_with(|| a_ts.cmp(&b_ts)).then_with(|| a_room_id.cmp(b_room_id))
+}
+
 #[cfg(test)]
 mod tests {
+    use std::cmp::Ordering;
+
     use js_int::uint;
-    use ruma_common::{server_name, MilliSecondsSinceUnixEpoch};
+    use ruma_common::{owned_user_id, server_name, MilliSecondsSinceUnixEpoch};
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
     use super::{HierarchySpaceChildEvent, SpaceChildEventContent};
 
+    fn hierarchy_child(room_id: &str, order: Option<&str>, ts: u32) -> HierarchySpaceChildEvent {
+        let mut content = SpaceChildEventContent::new(vec![server_name!("example.org").to_owned()]);
+        content.order = order.map(ToOwned::to_owned);
+
+        HierarchySpaceChildEvent {
+            content,
+            sender: owned_user_id!("@alice:example.org"),
+            state_key: room_id.try_into().unwrap(),
+            origin_server_ts: MilliSecondsSinceUnixEpoch(ts.into()),
+        }
+    }
+
     #[test]
     fn space_child_serialization() {
         let content = SpaceChildEventContent {
@@ -124,4 +208,56 @@ mod tests {
         assert_eq!(ev.content.order, None);
         assert!(!ev.content.suggested);
     }
+
+    #[test]
+    fn valid_order() {
+        let mut content = SpaceChildEventContent::new(vec![]);
+        assert_eq!(content.valid_order(), None);
+
+        content.order = Some("a ~ z".to_owned());
+        assert_eq!(content.valid_order(), Some("a ~ z"));
+
+        content.order = Some("x".repeat(50));
+        assert!(content.valid_order().is_some());
+
+        content.order = Some("x".repeat(51));
+        assert_eq!(content.valid_order(), None);
+
+        content.order = Some("tab\t".to_owned());
+        assert_eq!(content.valid_order(), None);
+
+        content.order = Some("ünicode".to_owned());
+        assert_eq!(content.valid_order(), None);
+    }
+
+    #[test]
+    fn space_children_ordering() {
+        let mut children = vec![
+            hierarchy_child("!no_order_late:example.org", None, 20),
+            hierarchy_child("!order_b:example.org", Some("b"), 1),
+            hierarchy_child("!invalid_order:example.org", Some("\u{7F}"), 5),
+            hierarchy_child("!order_a_late:example.org", Some("a"), 10),
+            hierarchy_child("!no_order_b:example.org", None, 5),
+            hierarchy_child("!order_a:example.org", Some("a"), 2),
+            hierarchy_child("!no_order_a:example.org", None, 5),
+        ];
+        children.sort_by(|a, b| a.cmp_space_order(b));
+
+        let room_ids = children.iter().map(|child| child.state_key.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            room_ids,
+            [
+                "!order_a:example.org",
+                "!order_a_late:example.org",
+                "!order_b:example.org",
+                "!invalid_order:example.org",
+                "!no_order_a:example.org",
+                "!no_order_b:example.org",
+                "!no_order_late:example.org",
+            ]
+        );
+
+        let child = hierarchy_child("!a:example.org", None, 0);
+        assert_eq!(child.cmp_space_order(&child), Ordering::Equal);
+    }
 }
@@ -1,8 +1,11 @@
+use std::collections::BTreeMap;
+
 use js_int::Int;
 use ruma_common::{
     serde::CanBeEmpty, MilliSecondsSinceUnixEpoch, OwnedEventId, OwnedTransactionId, OwnedUserId,
 };
 use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value as JsonValue;
 
 use super::{
     relation::{BundledMessageLikeRelations, BundledStateRelations},
@@ -31,12 +34,24 @@ pub struct MessageLikeUnsigned<C: MessageLikeEventContent> {
     /// [Bundled aggregations]: https://spec.matrix.org/latest/client-server-api/#aggregations-of-child-events
     #[serde(rename = "m.relations", default)]
     pub relations: BundledMessageLikeRelations<OriginalSyncMessageLikeEvent<C>>,
+
+    /// Other keys of the unsigned data, that are not known by this type.
+    ///
+    /// This contains the unsigned data added by servers outside of the specification, for
+    /// example by an unstable feature.
+    #[serde(flatten)]
+    pub custom_fields: BTreeMap<String, JsonValue>,
 }
 
 impl<C: MessageLikeEventContent> MessageLikeUnsigned<C> {
     /// Create a new `Unsigned` with fields set to `None`.
     pub fn new() -> Self {
-        Self { age: None, transaction_id: None, relations: BundledMessageLikeRelations::default() }
+        Self {
+            age: None,
+            transaction_id: None,
+            relations: BundledMessageLikeRelations::default(),
+            custom_fields: BTreeMap::new(),
+        }
     }
 }
 
@@ -47,13 +62,16 @@ impl<C: MessageLikeEventContent> Default for MessageLikeUnsigned<C> {
 }
 
 impl<C: MessageLikeEventContent> CanBeEmpty for MessageLikeUnsigned<C> {
-    /// Whether this unsigned data is empty (all fields are `None`).
+    /// Whether this unsigned data is empty (all fields are `None` or empty).
     ///
     /// This method is used to determine whether to skip serializing the `unsigned` field in room
     /// events. Do not use it to determine whether an incoming `unsigned` field was present - it
-    /// could still have been present but contained none of the known fields.
+    /// could still have been present but been empty.
     fn is_empty(&self) -> bool {
-        self.age.is_none() && self.transaction_id.is_none() && self.relations.is_empty()
+        self.age.is_none()
+            && self.transaction_id.is_none()
+            && self.relations.is_empty()
+            && self.custom_fields.is_empty()
     }
 }
 
@@ -80,26 +98,40 @@ pub struct StateUnsigned<C: PossiblyRedactedStateEventContent> {
     /// [Bundled aggregations]: https://spec.matrix.org/latest/client-server-api/#aggregations-of-child-events
     #[serde(rename = "m.relations", default)]
     pub relations: BundledStateRelations,
+
+    /// Other keys of the unsigned data, that are not known by this type.
+    ///
+    /// This contains the unsigned data added by servers outside of the specification, for
+    /// example by an unstable feature.
+    #[serde(flatten)]
+    pub custom_fields: BTreeMap<String, JsonValue>,
 }
 
 impl<C: PossiblyRedactedStateEventContent> StateUnsigned<C> {
     /// Create a new `Unsigned` with fields set to `None`.
     pub fn new() -> Self {
-        Self { age: None, transaction_id: None, prev_content: None, relations: Default::default() }
+        Self {
+            age: None,
+            transaction_id: None,
+            prev_content: None,
+            relations: Default::default(),
+            custom_fields: BTreeMap::new(),
+        }
     }
 }
 
 impl<C: PossiblyRedactedStateEventContent> CanBeEmpty for StateUnsigned<C> {
-    /// Whether this unsigned data is empty (all fields are `None`).
+    /// Whether this unsigned data is empty (all fields are `None` or empty).
     ///
     /// This method is used to determine whether to skip serializing the `unsigned` field in room
     /// events. Do not use it to determine whether an incoming `unsigned` field was present - it
-    /// could still have been present but contained none of the known fields.
+    /// could still have been present but been empty.
     fn is_empty(&self) -> bool {
         self.age.is_none()
             && self.transaction_id.is_none()
             && self.prev_content.is_none()
             && self.relations.is_empty()
+            && self.custom_fields.is_empty()
     }
 }
 
@@ -115,12 +147,19 @@ impl<C: PossiblyRedactedStateEventContent> Default for StateUnsigned<C> {
 pub struct RedactedUnsigned {
     /// The event that redacted this event, if any.
     pub redacted_because: UnsignedRoomRedactionEvent,
+
+    /// Other keys of the unsigned data, that are not known by this type.
+    ///
+    /// This contains the unsigned data added by servers outside of the specification, for
+    /// example by an unstable feature.
+    #[serde(flatten)]
+    pub custom_fields: BTreeMap<String, JsonValue>,
 }
 
 impl RedactedUnsigned {
     /// Create a new `RedactedUnsigned` with the given redaction event.
     pub fn new(redacted_because: UnsignedRoomRedactionEvent) -> Self {
-        Self { redacted_because }
+        Self { redacted_because, custom_fields: BTreeMap::new() }
     }
 }
 
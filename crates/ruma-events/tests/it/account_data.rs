@@ -0,0 +1,94 @@
+use assert_matches2::assert_matches;
+use ruma_common::{owned_room_id, user_id};
+use ruma_events::{
+    direct::DirectEventContent, tag::TagEventContent, AnyGlobalAccountDataEvent,
+    AnyRoomAccountDataEvent, GlobalAccountDataEvent, GlobalAccountDataEventType,
+    RoomAccountDataEventType,
+};
+use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+#[test]
+fn serialize_global_account_data() {
+    let mut content = DirectEventContent::default();
+    content
+        .insert(user_id!("@bob:example.org").to_owned(), vec![owned_room_id!("!dm:example.org")]);
+    let event = GlobalAccountDataEvent { content };
+
+    assert_eq!(
+        to_json_value(&event).unwrap(),
+        json!({
+            "content": {
+                "@bob:example.org": ["!dm:example.org"],
+            },
+            "type": "m.direct",
+        })
+    );
+}
+
+#[test]
+fn deserialize_global_account_data() {
+    let json = json!({
+        "content": {
+            "@bob:example.org": ["!dm:example.org"],
+        },
+        "type": "m.direct",
+    });
+
+    assert_matches!(
+        from_json_value::<AnyGlobalAccountDataEvent>(json),
+        Ok(AnyGlobalAccountDataEvent::Direct(event))
+    );
+    assert_eq!(
+        event.content[user_id!("@bob:example.org")],
+        vec![owned_room_id!("!dm:example.org")]
+    );
+}
+
+#[test]
+fn deserialize_room_account_data() {
+    let json = json!({
+        "content": {
+            "tags": {
+                "u.work": {
+                    "order": 0.9,
+                },
+            },
+        },
+        "type": "m.tag",
+    });
+
+    assert_matches!(
+        from_json_value::<AnyRoomAccountDataEvent>(json),
+        Ok(AnyRoomAccountDataEvent::Tag(event))
+    );
+    let TagEventContent { tags, .. } = event.content;
+    assert_eq!(tags.len(), 1);
+}
+
+#[test]
+fn global_account_data_in_room_is_custom() {
+    let json = json!({
+        "content": {
+            "@bob:example.org": ["!dm:example.org"],
+        },
+        "type": "m.direct",
+    });
+
+    let event = from_json_value::<AnyRoomAccountDataEvent>(json).unwrap();
+    assert_matches!(&event, AnyRoomAccountDataEvent::_Custom(_));
+    assert_eq!(event.event_type(), RoomAccountDataEventType::from("m.direct"));
+}
+
+#[test]
+fn room_account_data_in_global_is_custom() {
+    let json = json!({
+        "content": {
+            "tags": {},
+        },
+        "type": "m.tag",
+    });
+
+    let event = from_json_value::<AnyGlobalAccountDataEvent>(json).unwrap();
+    assert_matches!(&event, AnyGlobalAccountDataEvent::_Custom(_));
+    assert_eq!(event.event_type(), GlobalAccountDataEventType::from("m.tag"));
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "call")]
+
 #[cfg(feature = "unstable-msc4075")]
 use std::collections::BTreeSet;
 
@@ -282,3 +282,59 @@ fn serialize_and_deserialize_from_display_form() {
     serde_json_eq(StateEventType::RoomCreate, json!("m.room.create"));
     serde_json_eq(EphemeralRoomEventType::Typing, json!("m.typing"));
 }
+
+#[test]
+fn dispatch_on_type_anywhere_in_object() {
+    let json = r#"{
+        "content": {
+            "body": "Hello world!",
+            "msgtype": "m.text"
+        },
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "type": "m.room.message"
+    }"#;
+
+    assert_matches!(
+        serde_json::from_str::<AnySyncMessageLikeEvent>(json),
+        Ok(AnySyncMessageLikeEvent::RoomMessage(SyncMessageLikeEvent::Original(ev)))
+    );
+    assert_eq!(ev.content.body(), "Hello world!");
+}
+
+#[test]
+fn dispatch_on_escaped_type() {
+    let json = r#"{
+        "type": "m.room\u002emessage",
+        "content": {
+            "body": "Hello world!",
+            "msgtype": "m.text"
+        },
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com"
+    }"#;
+
+    assert_matches!(
+        serde_json::from_str::<AnySyncMessageLikeEvent>(json),
+        Ok(AnySyncMessageLikeEvent::RoomMessage(_))
+    );
+}
+
+#[test]
+fn dispatch_unknown_type_to_custom() {
+    let json_data = json!({
+        "content": {
+            "foo": "bar"
+        },
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "type": "dev.ruma.custom"
+    });
+
+    let event = from_json_value::<AnySyncMessageLikeEvent>(json_data).unwrap();
+    assert_eq!(event.event_type().to_string(), "dev.ruma.custom");
+    assert_eq!(event.event_id(), "$h29iv0s8:example.com");
+}
@@ -1,11 +1,13 @@
 use assert_matches2::assert_matches;
 use js_int::uint;
 use maplit::btreemap;
-use ruma_common::{event_id, owned_event_id, owned_user_id, user_id, MilliSecondsSinceUnixEpoch};
+use ruma_common::{
+    event_id, owned_event_id, owned_room_id, owned_user_id, user_id, MilliSecondsSinceUnixEpoch,
+};
 use ruma_events::{
     receipt::{Receipt, ReceiptEventContent, ReceiptType},
-    typing::TypingEventContent,
-    AnyEphemeralRoomEvent,
+    typing::{SyncTypingEvent, TypingEvent, TypingEventContent},
+    AnyEphemeralRoomEvent, AnySyncEphemeralRoomEvent, EphemeralRoomEventType,
 };
 use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
@@ -94,3 +96,58 @@ fn deserialize_ephemeral_receipt() {
     let user_receipt = type_receipts.get(user_id).unwrap();
     assert_eq!(user_receipt.ts, Some(MilliSecondsSinceUnixEpoch(uint!(1))));
 }
+
+#[test]
+fn deserialize_sync_ephemeral_typing() {
+    let json_data = json!({
+        "content": {
+            "user_ids": [ "@carl:example.com" ]
+        },
+        "type": "m.typing"
+    });
+
+    let sync_event = from_json_value::<AnySyncEphemeralRoomEvent>(json_data).unwrap();
+    assert_eq!(sync_event.event_type(), EphemeralRoomEventType::Typing);
+
+    assert_matches!(
+        sync_event.into_full_event(owned_room_id!("!roomid:room.com")),
+        AnyEphemeralRoomEvent::Typing(typing_event)
+    );
+    assert_eq!(typing_event.content.user_ids, vec![owned_user_id!("@carl:example.com")]);
+    assert_eq!(typing_event.room_id, "!roomid:room.com");
+}
+
+#[test]
+fn deserialize_custom_ephemeral() {
+    let json_data = json!({
+        "content": {
+            "foo": "bar"
+        },
+        "room_id": "!roomid:room.com",
+        "type": "dev.ruma.custom_ephemeral"
+    });
+
+    let event = from_json_value::<AnyEphemeralRoomEvent>(json_data).unwrap();
+    assert_matches!(&event, AnyEphemeralRoomEvent::_Custom(_));
+    assert_eq!(event.event_type().to_string(), "dev.ruma.custom_ephemeral");
+    assert_eq!(event.room_id(), "!roomid:room.com");
+
+    let sync_event = AnySyncEphemeralRoomEvent::from(event);
+    assert_matches!(&sync_event, AnySyncEphemeralRoomEvent::_Custom(_));
+    assert_eq!(sync_event.event_type().to_string(), "dev.ruma.custom_ephemeral");
+}
+
+#[test]
+fn sync_ephemeral_room_event_from_into_full_event() {
+    let event = TypingEvent {
+        content: TypingEventContent::new(vec![owned_user_id!("@carl:example.com")]),
+        room_id: owned_room_id!("!roomid:room.com"),
+    };
+
+    let sync_event = SyncTypingEvent::from(event);
+    assert_eq!(sync_event.content.user_ids, vec![owned_user_id!("@carl:example.com")]);
+
+    let event = sync_event.into_full_event(owned_room_id!("!other:room.com"));
+    assert_eq!(event.content.user_ids, vec![owned_user_id!("@carl:example.com")]);
+    assert_eq!(event.room_id, "!other:room.com");
+}
@@ -1,8 +1,10 @@
 use assert_matches2::assert_matches;
 use js_int::uint;
 use ruma_common::{serde::CanBeEmpty, MilliSecondsSinceUnixEpoch, VoipVersionId};
-use ruma_events::{AnyMessageLikeEvent, MessageLikeEvent};
-use serde_json::{from_value as from_json_value, json};
+use ruma_events::{
+    AnyMessageLikeEvent, MessageLikeEvent, MessageLikeEventType, StateEventType, TimelineEventType,
+};
+use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
 #[test]
 fn ui() {
@@ -46,3 +48,34 @@ fn deserialize_message_event() {
     assert_eq!(content.call_id, "foofoo");
     assert_eq!(content.version, VoipVersionId::V0);
 }
+
+#[test]
+fn timeline_event_type_kind() {
+    let room_name = TimelineEventType::RoomName;
+    assert!(room_name.is_state());
+    assert!(!room_name.is_message_like());
+
+    let room_message = TimelineEventType::RoomMessage;
+    assert!(!room_message.is_state());
+    assert!(room_message.is_message_like());
+
+    let from_state = TimelineEventType::from(StateEventType::RoomMember);
+    assert!(from_state.is_state());
+
+    let from_message_like = TimelineEventType::from(MessageLikeEventType::Reaction);
+    assert!(from_message_like.is_message_like());
+
+    let custom = TimelineEventType::from("dev.ruma.custom");
+    assert!(!custom.is_state());
+    assert!(!custom.is_message_like());
+}
+
+#[test]
+fn custom_event_type_roundtrip() {
+    let ev_type = from_json_value::<TimelineEventType>(json!("dev.ruma.custom")).unwrap();
+    assert_eq!(ev_type.to_string(), "dev.ruma.custom");
+    assert_eq!(to_json_value(&ev_type).unwrap(), json!("dev.ruma.custom"));
+
+    let state_type = StateEventType::from("dev.ruma.custom");
+    assert_eq!(TimelineEventType::from(state_type), ev_type);
+}
@@ -1,3 +1,4 @@
+mod account_data;
 mod audio;
 mod call;
 mod encrypted;
@@ -17,10 +18,12 @@ mod redacted;
 mod redaction;
 mod relations;
 mod room_message;
+mod room_state;
 mod state_event;
 mod sticker;
 mod stripped;
 mod to_device;
+mod unsigned;
 mod video;
 mod voice;
 mod without_relation;
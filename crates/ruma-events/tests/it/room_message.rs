@@ -15,7 +15,7 @@ use ruma_events::{
             FileMessageEventContent, ForwardThread, ImageMessageEventContent,
             KeyVerificationRequestEventContent, MessageType, OriginalRoomMessageEvent,
             OriginalSyncRoomMessageEvent, Relation, ReplyWithinThread, RoomMessageEventContent,
-            TextMessageEventContent, VideoMessageEventContent,
+            RoomMessageEventContentRef, TextMessageEventContent, VideoMessageEventContent,
         },
         EncryptedFileInit, JsonWebKeyInit, MediaSource,
     },
@@ -1006,6 +1006,110 @@ fn image_msgtype_deserialization() {
     assert_eq!(url, "mxc://notareal.hs/file");
 }
 
+#[cfg(feature = "unstable-msc2448")]
+#[test]
+fn image_msgtype_blurhash_serialization() {
+    use ruma_events::room::{ImageInfo, ThumbnailInfo};
+
+    let mut thumbnail_info = ThumbnailInfo::new();
+    thumbnail_info.width = Some(uint!(32));
+    thumbnail_info.height = Some(uint!(24));
+    thumbnail_info.mimetype = Some("image/jpeg".to_owned());
+
+    let mut info = ImageInfo::new();
+    info.thumbnail_source =
+        Some(MediaSource::Plain(mxc_uri!("mxc://notareal.hs/thumb").to_owned()));
+    info.thumbnail_info = Some(Box::new(thumbnail_info));
+    info.blurhash = Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH".to_owned());
+
+    let message_event_content = RoomMessageEventContent::new(MessageType::Image(
+        ImageMessageEventContent::plain(
+            "Upload: my_image.jpg".to_owned(),
+            mxc_uri!("mxc://notareal.hs/file").to_owned(),
+        )
+        .info(Box::new(info)),
+    ));
+
+    let json = json!({
+        "body": "Upload: my_image.jpg",
+        "url": "mxc://notareal.hs/file",
+        "info": {
+            "thumbnail_url": "mxc://notareal.hs/thumb",
+            "thumbnail_info": {
+                "w": 32,
+                "h": 24,
+                "mimetype": "image/jpeg",
+            },
+            "xyz.amorgan.blurhash": "LKO2?U%2Tw=w]~RBVZRi};RPxuwH",
+        },
+        "msgtype": "m.image",
+    });
+    assert_eq!(to_json_value(&message_event_content).unwrap(), json);
+
+    let event_content = from_json_value::<RoomMessageEventContent>(json).unwrap();
+    assert_matches!(event_content.msgtype, MessageType::Image(content));
+    let info = content.info.unwrap();
+    assert_eq!(info.blurhash.as_deref(), Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH"));
+    assert_eq!(info.thumbnail_info.unwrap().width, Some(uint!(32)));
+}
+
+#[cfg(feature = "unstable-msc2448")]
+#[test]
+fn stable_blurhash_deserialization() {
+    let image_json = json!({
+        "body": "Upload: my_image.jpg",
+        "url": "mxc://notareal.hs/file",
+        "info": { "blurhash": "LKO2?U%2Tw=w]~RBVZRi};RPxuwH" },
+        "msgtype": "m.image",
+    });
+    let event_content = from_json_value::<RoomMessageEventContent>(image_json).unwrap();
+    assert_matches!(event_content.msgtype, MessageType::Image(content));
+    assert_eq!(content.info.unwrap().blurhash.as_deref(), Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH"));
+
+    let video_json = json!({
+        "body": "Upload: my_video.mp4",
+        "url": "mxc://notareal.hs/file",
+        "info": { "blurhash": "LKO2?U%2Tw=w]~RBVZRi};RPxuwH" },
+        "msgtype": "m.video",
+    });
+    let event_content = from_json_value::<RoomMessageEventContent>(video_json).unwrap();
+    assert_matches!(event_content.msgtype, MessageType::Video(content));
+    assert_eq!(content.info.unwrap().blurhash.as_deref(), Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH"));
+
+    let file_json = json!({
+        "body": "Upload: my_file.pdf",
+        "url": "mxc://notareal.hs/file",
+        "info": {
+            "thumbnail_url": "mxc://notareal.hs/thumb",
+            "blurhash": "LKO2?U%2Tw=w]~RBVZRi};RPxuwH",
+        },
+        "msgtype": "m.file",
+    });
+    let event_content = from_json_value::<RoomMessageEventContent>(file_json).unwrap();
+    assert_matches!(event_content.msgtype, MessageType::File(content));
+    let info = content.info.unwrap();
+    assert_eq!(info.blurhash.as_deref(), Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH"));
+    assert_matches!(info.thumbnail_source, Some(MediaSource::Plain(thumbnail_url)));
+    assert_eq!(thumbnail_url, "mxc://notareal.hs/thumb");
+}
+
+#[cfg(feature = "unstable-msc2448")]
+#[test]
+fn stable_and_unstable_blurhash_deserialization() {
+    let json = json!({
+        "body": "Upload: my_image.jpg",
+        "url": "mxc://notareal.hs/file",
+        "info": {
+            "blurhash": "LKO2?U%2Tw=w]~RBVZRi};RPxuwH",
+            "xyz.amorgan.blurhash": "L6PZfSi_.AyE_3t7t7R**0o#DgR4",
+        },
+        "msgtype": "m.image",
+    });
+    let event_content = from_json_value::<RoomMessageEventContent>(json).unwrap();
+    assert_matches!(event_content.msgtype, MessageType::Image(content));
+    assert_eq!(content.info.unwrap().blurhash.as_deref(), Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH"));
+}
+
 #[cfg(not(feature = "unstable-msc3488"))]
 #[test]
 fn location_msgtype_serialization() {
@@ -1258,3 +1362,54 @@ fn invalid_replacement() {
     assert_matches!(&data, Cow::Borrowed(_)); // data is stored in JSON form because it's invalid
     assert_eq!(JsonValue::Object(data.into_owned()), relation);
 }
+
+#[test]
+fn borrowed_content_deserialization() {
+    let json = r#"{
+        "msgtype": "m.text",
+        "body": "Hello, \"World\"!",
+        "format": "org.matrix.custom.html",
+        "formatted_body": "Hello, <em>World</em>!",
+        "m.relates_to": { "m.in_reply_to": { "event_id": "$eventid" } }
+    }"#;
+    let raw = Raw::<RoomMessageEventContent>::from_json_string(json.to_owned()).unwrap();
+
+    let content = raw.deserialize_as::<RoomMessageEventContentRef<'_>>().unwrap();
+    assert_matches!(content.msgtype, Cow::Borrowed("m.text"));
+    // Strings with escape sequences cannot be borrowed.
+    assert_matches!(content.body, Cow::Owned(body));
+    assert_eq!(body, r#"Hello, "World"!"#);
+    assert_matches!(content.format, Some(Cow::Borrowed("org.matrix.custom.html")));
+    assert_matches!(content.formatted_body, Some(Cow::Borrowed("Hello, <em>World</em>!")));
+
+    assert_eq!(
+        serde_json::from_str::<JsonValue>(content.relates_to.unwrap().get()).unwrap(),
+        json!({ "m.in_reply_to": { "event_id": "$eventid" } })
+    );
+}
+
+#[test]
+fn borrowed_content_from_raw_event() {
+    let raw_event = Raw::<AnySyncTimelineEvent>::from_json_string(
+        json!({
+            "content": {
+                "msgtype": "m.notice",
+                "body": "Hello",
+            },
+            "event_id": "$143273582443PhrSn:example.org",
+            "origin_server_ts": 10_000,
+            "sender": "@user:example.org",
+            "type": "m.room.message",
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    let content =
+        raw_event.get_field::<RoomMessageEventContentRef<'_>>("content").unwrap().unwrap();
+    assert_matches!(content.msgtype, Cow::Borrowed("m.notice"));
+    assert_matches!(content.body, Cow::Borrowed("Hello"));
+    assert_matches!(content.format, None);
+    assert_matches!(content.formatted_body, None);
+    assert_matches!(content.relates_to, None);
+}
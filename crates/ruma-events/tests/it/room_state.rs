@@ -0,0 +1,127 @@
+use assert_matches2::assert_matches;
+use ruma_common::{event_id, serde::Raw, user_id};
+use ruma_events::{
+    room::{
+        member::{MembershipState, RoomMemberEventContent},
+        name::RoomNameEventContent,
+    },
+    AnyStateEvent, EmptyStateKey, RoomState, StateEvent, StateEventType,
+};
+use serde_json::{from_value as from_json_value, json, Value as JsonValue};
+
+use crate::state_event::state_event_with_key;
+
+fn with_event_id(mut event: JsonValue, event_id: &str) -> Raw<AnyStateEvent> {
+    event["event_id"] = event_id.into();
+    from_json_value(event).unwrap()
+}
+
+fn alice_join(event_id: &str) -> Raw<AnyStateEvent> {
+    with_event_id(
+        state_event_with_key("m.room.member", json!({ "membership": "join" }), "@alice:localhost"),
+        event_id,
+    )
+}
+
+fn room_name(event_id: &str, name: &str) -> Raw<AnyStateEvent> {
+    with_event_id(state_event_with_key("m.room.name", json!({ "name": name }), ""), event_id)
+}
+
+#[test]
+fn insert_and_get() {
+    let mut state = RoomState::new();
+    assert!(state.is_empty());
+
+    assert_matches!(state.insert(alice_join("$join")), Ok(None));
+    assert_matches!(state.insert(room_name("$name", "Room")), Ok(None));
+    assert_eq!(state.len(), 2);
+
+    assert!(state.contains(&StateEventType::RoomMember, "@alice:localhost"));
+    assert!(!state.contains(&StateEventType::RoomMember, "@bob:localhost"));
+    assert_eq!(state.get_event_id(&StateEventType::RoomName, ""), Some(event_id!("$name")));
+
+    let member =
+        state.get::<RoomMemberEventContent, _>(user_id!("@alice:localhost")).unwrap().unwrap();
+    assert_matches!(member, StateEvent::Original(member));
+    assert_eq!(member.content.membership, MembershipState::Join);
+    assert!(state.get::<RoomMemberEventContent, _>(user_id!("@bob:localhost")).is_none());
+
+    let name = state.get::<RoomNameEventContent, _>(&EmptyStateKey).unwrap().unwrap();
+    assert_matches!(name, StateEvent::Original(name));
+    assert_eq!(name.content.name, "Room");
+}
+
+#[test]
+fn insert_replaces() {
+    let mut state = RoomState::from_events([room_name("$old", "Old")]).unwrap();
+
+    let replaced = state.insert(room_name("$new", "New")).unwrap().unwrap();
+    assert_eq!(replaced.get_field::<String>("event_id").unwrap().as_deref(), Some("$old"));
+    assert_eq!(state.len(), 1);
+    assert_eq!(state.get_event_id(&StateEventType::RoomName, ""), Some(event_id!("$new")));
+
+    let removed = state.remove(&StateEventType::RoomName, "").unwrap();
+    assert_eq!(removed.get_field::<String>("event_id").unwrap().as_deref(), Some("$new"));
+    assert!(state.is_empty());
+}
+
+#[test]
+fn insert_invalid() {
+    let mut state = RoomState::new();
+
+    let without_state_key = from_json_value(json!({
+        "content": { "name": "Room" },
+        "event_id": "$name",
+        "origin_server_ts": 1,
+        "room_id": "!room:localhost",
+        "sender": "@alice:localhost",
+        "type": "m.room.name",
+    }))
+    .unwrap();
+    state.insert(without_state_key).unwrap_err();
+    assert!(state.is_empty());
+}
+
+#[test]
+fn diff() {
+    let old = RoomState::from_events([alice_join("$join"), room_name("$name", "Room")]).unwrap();
+    let new = RoomState::from_events([
+        room_name("$new_name", "New Room"),
+        with_event_id(
+            state_event_with_key("m.room.topic", json!({ "topic": "Topic" }), ""),
+            "$topic",
+        ),
+    ])
+    .unwrap();
+
+    let diff = old.diff(&new);
+    assert_eq!(
+        diff.added.into_iter().collect::<Vec<_>>(),
+        [(StateEventType::RoomTopic, String::new())]
+    );
+    assert_eq!(
+        diff.changed.into_iter().collect::<Vec<_>>(),
+        [(StateEventType::RoomName, String::new())]
+    );
+    assert_eq!(
+        diff.removed.into_iter().collect::<Vec<_>>(),
+        [(StateEventType::RoomMember, "@alice:localhost".to_owned())]
+    );
+
+    assert!(old.diff(&old.clone()).is_empty());
+}
+
+#[test]
+fn conversions() {
+    let state = RoomState::from_events([alice_join("$join"), room_name("$name", "Room")]).unwrap();
+
+    let event_ids = state.event_ids();
+    assert_eq!(event_ids.len(), 2);
+    assert_eq!(
+        event_ids[&(StateEventType::RoomMember, "@alice:localhost".to_owned())],
+        event_id!("$join")
+    );
+
+    let events = state.into_events();
+    assert_eq!(events.len(), 2);
+}
@@ -173,3 +173,85 @@ fn deserialize_full_event_convert_to_sync() {
     );
     assert_eq!(sync_ev.sender, "@carl:example.com");
 }
+
+pub(crate) fn state_event_with_key(
+    event_type: &str,
+    content: JsonValue,
+    state_key: &str,
+) -> JsonValue {
+    json!({
+        "content": content,
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "room_id": "!roomid:room.com",
+        "sender": "@carl:example.com",
+        "state_key": state_key,
+        "type": event_type,
+    })
+}
+
+#[test]
+fn deserialize_typed_state_keys() {
+    let name_content = json!({ "name": "The room" });
+    let event =
+        from_json_value::<AnyStateEvent>(state_event_with_key("m.room.name", name_content, ""))
+            .unwrap();
+    assert_matches!(event, AnyStateEvent::RoomName(StateEvent::Original(event)));
+    assert_eq!(event.state_key.as_ref(), "");
+
+    let member_content = json!({ "membership": "join" });
+    let event = from_json_value::<AnyStateEvent>(state_event_with_key(
+        "m.room.member",
+        member_content,
+        "@alice:example.org",
+    ))
+    .unwrap();
+    assert_matches!(event, AnyStateEvent::RoomMember(StateEvent::Original(event)));
+    assert_eq!(event.state_key, "@alice:example.org");
+
+    let space_child_content = json!({ "via": ["example.org"] });
+    let event = from_json_value::<AnyStateEvent>(state_event_with_key(
+        "m.space.child",
+        space_child_content,
+        "!child:example.org",
+    ))
+    .unwrap();
+    assert_matches!(event, AnyStateEvent::SpaceChild(StateEvent::Original(event)));
+    assert_eq!(event.state_key, "!child:example.org");
+
+    let custom_content = json!({ "foo": "bar" });
+    let event = from_json_value::<AnyStateEvent>(state_event_with_key(
+        "dev.ruma.custom_state",
+        custom_content,
+        "any key",
+    ))
+    .unwrap();
+    assert_eq!(event.state_key(), "any key");
+}
+
+#[test]
+fn deserialize_invalid_state_keys() {
+    let name_content = json!({ "name": "The room" });
+    from_json_value::<AnyStateEvent>(state_event_with_key(
+        "m.room.name",
+        name_content,
+        "not empty",
+    ))
+    .unwrap_err();
+
+    let member_content = json!({ "membership": "join" });
+    from_json_value::<AnyStateEvent>(state_event_with_key(
+        "m.room.member",
+        member_content,
+        "alice",
+    ))
+    .unwrap_err();
+
+    let space_child_content = json!({ "via": ["example.org"] });
+    from_json_value::<AnyStateEvent>(state_event_with_key(
+        "m.space.child",
+        space_child_content,
+        "#alias:example.org",
+    ))
+    .unwrap_err();
+}
@@ -1,6 +1,7 @@
+use assert_matches2::assert_matches;
 use ruma_common::{owned_room_id, EventEncryptionAlgorithm};
-use ruma_events::room_key::ToDeviceRoomKeyEventContent;
-use serde_json::{json, to_value as to_json_value};
+use ruma_events::{room_key::ToDeviceRoomKeyEventContent, AnyToDeviceEvent, ToDeviceEventType};
+use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
 #[test]
 fn serialization() {
@@ -21,3 +22,43 @@ fn serialization() {
         })
     );
 }
+
+#[test]
+fn deserialization() {
+    let json = json!({
+        "content": {
+            "algorithm": "m.megolm.v1.aes-sha2",
+            "room_id": "!testroomid:example.org",
+            "session_id": "SessId",
+            "session_key": "SessKey",
+        },
+        "sender": "@alice:example.org",
+        "type": "m.room_key",
+    });
+
+    assert_matches!(
+        from_json_value::<AnyToDeviceEvent>(json),
+        Ok(AnyToDeviceEvent::RoomKey(event))
+    );
+    assert_eq!(event.sender, "@alice:example.org");
+    assert_eq!(event.content.algorithm, EventEncryptionAlgorithm::MegolmV1AesSha2);
+    assert_eq!(event.content.room_id, "!testroomid:example.org");
+    assert_eq!(event.content.session_id, "SessId");
+    assert_eq!(event.content.session_key, "SessKey");
+}
+
+#[test]
+fn custom_deserialization() {
+    let json = json!({
+        "content": {
+            "foo": "bar",
+        },
+        "sender": "@alice:example.org",
+        "type": "dev.ruma.custom_to_device",
+    });
+
+    let event = from_json_value::<AnyToDeviceEvent>(json).unwrap();
+    assert_matches!(&event, AnyToDeviceEvent::_Custom(_));
+    assert_eq!(event.sender(), "@alice:example.org");
+    assert_eq!(event.event_type(), ToDeviceEventType::from("dev.ruma.custom_to_device"));
+}
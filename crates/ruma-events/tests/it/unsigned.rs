@@ -0,0 +1,198 @@
+use assert_matches2::assert_matches;
+use js_int::{int, uint};
+use ruma_common::serde::CanBeEmpty;
+use ruma_events::{
+    room::{
+        message::{OriginalSyncRoomMessageEvent, RoomMessageEventContent},
+        topic::OriginalSyncRoomTopicEvent,
+    },
+    AnySyncMessageLikeEvent, AnySyncTimelineEvent, MessageLikeUnsigned, SyncMessageLikeEvent,
+};
+use serde_json::{from_value as from_json_value, json};
+
+#[test]
+fn message_like_unsigned() {
+    let json = json!({
+        "content": {
+            "body": "Hello",
+            "msgtype": "m.text",
+        },
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "type": "m.room.message",
+        "unsigned": {
+            "age": 1234,
+            "transaction_id": "m1476648745605.19",
+            "m.relations": {
+                "m.thread": {
+                    "latest_event": {
+                        "content": {
+                            "body": "Hello in thread",
+                            "msgtype": "m.text",
+                        },
+                        "event_id": "$thread_reply:example.com",
+                        "origin_server_ts": 2,
+                        "sender": "@carl:example.com",
+                        "type": "m.room.message",
+                    },
+                    "count": 1,
+                    "current_user_participated": true,
+                },
+            },
+        },
+    });
+
+    let event = from_json_value::<AnySyncTimelineEvent>(json.clone()).unwrap();
+    assert_eq!(event.transaction_id().unwrap(), "m1476648745605.19");
+
+    let event = from_json_value::<OriginalSyncRoomMessageEvent>(json).unwrap();
+    assert_eq!(event.unsigned.age, Some(int!(1234)));
+    assert_eq!(event.unsigned.transaction_id.as_deref().unwrap(), "m1476648745605.19");
+    let thread = event.unsigned.relations.thread.unwrap();
+    assert_eq!(
+        thread.latest_event.get_field::<String>("event_id").unwrap().as_deref(),
+        Some("$thread_reply:example.com")
+    );
+    assert_eq!(thread.count, uint!(1));
+    assert!(thread.current_user_participated);
+}
+
+#[test]
+fn unknown_unsigned_fields() {
+    let json = json!({
+        "content": {
+            "body": "Hello",
+            "msgtype": "m.text",
+        },
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "type": "m.room.message",
+        "unsigned": {
+            "dev.ruma.unknown": true,
+        },
+    });
+
+    let event = from_json_value::<OriginalSyncRoomMessageEvent>(json).unwrap();
+    assert!(!event.unsigned.is_empty());
+    assert_eq!(event.unsigned.age, None);
+    assert_eq!(event.unsigned.custom_fields.len(), 1);
+    assert_eq!(event.unsigned.custom_fields.get("dev.ruma.unknown"), Some(&json!(true)));
+}
+
+#[test]
+fn unknown_state_and_redacted_unsigned_fields() {
+    let json = json!({
+        "content": {
+            "topic": "New topic",
+        },
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "state_key": "",
+        "type": "m.room.topic",
+        "unsigned": {
+            "age": 1234,
+            "dev.ruma.unknown": { "foo": "bar" },
+        },
+    });
+
+    let event = from_json_value::<OriginalSyncRoomTopicEvent>(json).unwrap();
+    assert_eq!(event.unsigned.age, Some(int!(1234)));
+    assert_eq!(
+        event.unsigned.custom_fields.get("dev.ruma.unknown"),
+        Some(&json!({ "foo": "bar" }))
+    );
+
+    let json = json!({
+        "content": {},
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "type": "m.room.message",
+        "unsigned": {
+            "redacted_because": {
+                "content": {},
+                "event_id": "$redaction:example.com",
+                "origin_server_ts": 2,
+                "redacts": "$h29iv0s8:example.com",
+                "sender": "@admin:example.com",
+                "type": "m.room.redaction",
+            },
+            "dev.ruma.unknown": 42,
+        },
+    });
+
+    assert_matches!(
+        from_json_value::<AnySyncTimelineEvent>(json),
+        Ok(AnySyncTimelineEvent::MessageLike(AnySyncMessageLikeEvent::RoomMessage(event)))
+    );
+    assert_matches!(event, SyncMessageLikeEvent::Redacted(event));
+    assert_eq!(event.unsigned.redacted_because.event_id, "$redaction:example.com");
+    assert_eq!(event.unsigned.custom_fields.get("dev.ruma.unknown"), Some(&json!(42)));
+}
+
+#[test]
+fn state_unsigned() {
+    let json = json!({
+        "content": {
+            "topic": "New topic",
+        },
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "state_key": "",
+        "type": "m.room.topic",
+        "unsigned": {
+            "age": 1234,
+            "prev_content": {
+                "topic": "Old topic",
+            },
+        },
+    });
+
+    let event = from_json_value::<OriginalSyncRoomTopicEvent>(json).unwrap();
+    assert_eq!(event.unsigned.age, Some(int!(1234)));
+    assert_eq!(event.unsigned.prev_content.unwrap().topic.as_deref(), Some("Old topic"));
+    assert!(event.unsigned.transaction_id.is_none());
+}
+
+#[test]
+fn redacted_unsigned() {
+    let json = json!({
+        "content": {},
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "type": "m.room.message",
+        "unsigned": {
+            "redacted_because": {
+                "content": {
+                    "reason": "Spam",
+                },
+                "event_id": "$redaction:example.com",
+                "origin_server_ts": 2,
+                "redacts": "$h29iv0s8:example.com",
+                "sender": "@admin:example.com",
+                "type": "m.room.redaction",
+            },
+        },
+    });
+
+    assert_matches!(
+        from_json_value::<AnySyncTimelineEvent>(json),
+        Ok(AnySyncTimelineEvent::MessageLike(AnySyncMessageLikeEvent::RoomMessage(event)))
+    );
+    assert_matches!(event, SyncMessageLikeEvent::Redacted(event));
+    let redacted_because = event.unsigned.redacted_because;
+    assert_eq!(redacted_because.event_id, "$redaction:example.com");
+    assert_eq!(redacted_because.sender, "@admin:example.com");
+    assert_eq!(redacted_because.content.reason.as_deref(), Some("Spam"));
+}
+
+#[test]
+fn empty_unsigned() {
+    let unsigned = MessageLikeUnsigned::<RoomMessageEventContent>::new();
+    assert!(unsigned.is_empty());
+}
@@ -29,7 +29,7 @@ pub mod v1 {
         #[ruma_api(path)]
         pub room_id: OwnedRoomId,
 
-        /// The timestamp to search from.
+        /// The timestamp to search from, inclusively.
         #[ruma_api(query)]
         pub ts: MilliSecondsSinceUnixEpoch,
 
@@ -53,6 +53,22 @@ pub mod v1 {
         pub fn new(room_id: OwnedRoomId, ts: MilliSecondsSinceUnixEpoch, dir: Direction) -> Self {
             Self { room_id, ts, dir }
         }
+
+        /// Creates a new `Request` with the given room ID and timestamp, and the direction set to
+        /// `Backward`.
+        ///
+        /// Allows to have the latest event before or including the given timestamp.
+        pub fn until(room_id: OwnedRoomId, ts: MilliSecondsSinceUnixEpoch) -> Self {
+            Self::new(room_id, ts, Direction::Backward)
+        }
+
+        /// Creates a new `Request` with the given room ID and timestamp, and the direction set to
+        /// `Forward`.
+        ///
+        /// Allows to have the earliest event including or after the given timestamp.
+        pub fn since(room_id: OwnedRoomId, ts: MilliSecondsSinceUnixEpoch) -> Self {
+            Self::new(room_id, ts, Direction::Forward)
+        }
     }
 
     impl Response {
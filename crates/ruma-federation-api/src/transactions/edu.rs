@@ -8,9 +8,9 @@ use ruma_common::{
     presence::PresenceState,
     serde::{from_raw_json_value, Raw},
     to_device::DeviceIdOrAllDevices,
-    OwnedDeviceId, OwnedEventId, OwnedRoomId, OwnedTransactionId, OwnedUserId,
+    OwnedDeviceId, OwnedEventId, OwnedRoomId, OwnedTransactionId, OwnedUserId, UserId,
 };
-use ruma_events::{receipt::Receipt, AnyToDeviceEventContent, ToDeviceEventType};
+use ruma_events::{receipt::Receipt, AnyToDeviceEvent, AnyToDeviceEventContent, ToDeviceEventType};
 use serde::{de, Deserialize, Serialize};
 use serde_json::{value::RawValue as RawJsonValue, Value as JsonValue};
 
@@ -281,6 +281,24 @@ impl DirectDeviceContent {
     ) -> Self {
         Self { sender, ev_type, message_id, messages: DirectDeviceMessages::new() }
     }
+
+    /// Creates the to-device event for the given message content, as it is received by the
+    /// recipient in the `to_device` section of a sync response.
+    pub fn to_device_event(
+        &self,
+        content: &Raw<AnyToDeviceEventContent>,
+    ) -> serde_json::Result<Raw<AnyToDeviceEvent>> {
+        #[derive(Serialize)]
+        struct ToDeviceEventSerHelper<'a> {
+            sender: &'a UserId,
+            #[serde(rename = "type")]
+            ev_type: &'a ToDeviceEventType,
+            content: &'a Raw<AnyToDeviceEventContent>,
+        }
+
+        Raw::new(&ToDeviceEventSerHelper { sender: &self.sender, ev_type: &self.ev_type, content })
+            .map(Raw::cast)
+    }
 }
 
 /// Direct device message contents.
@@ -316,8 +334,8 @@ impl SigningKeyUpdateContent {
 mod tests {
     use assert_matches2::assert_matches;
     use js_int::uint;
-    use ruma_common::{room_id, user_id};
-    use ruma_events::ToDeviceEventType;
+    use ruma_common::{device_id, room_id, to_device::DeviceIdOrAllDevices, user_id};
+    use ruma_events::{receipt::ReceiptThread, AnyToDeviceEvent, ToDeviceEventType};
     use serde_json::json;
 
     use super::{DeviceListUpdateContent, Edu, ReceiptContent};
@@ -440,6 +458,37 @@ mod tests {
         assert_eq!(serde_json::to_value(&edu).unwrap(), json);
     }
 
+    #[test]
+    fn threaded_receipt_edu() {
+        let json = json!({
+            "content": {
+                "!some_room:example.org": {
+                    "m.read": {
+                        "@john:matrix.org": {
+                            "data": {
+                                "ts": 1_533_358,
+                                "thread_id": "$thread_root:matrix.org"
+                            },
+                            "event_ids": [
+                                "$read_this_event:matrix.org"
+                            ]
+                        }
+                    }
+                }
+            },
+            "edu_type": "m.receipt"
+        });
+
+        let edu = serde_json::from_value::<Edu>(json.clone()).unwrap();
+        assert_matches!(&edu, Edu::Receipt(ReceiptContent { receipts }));
+        let receipt =
+            &receipts[room_id!("!some_room:example.org")].read[user_id!("@john:matrix.org")];
+        assert_matches!(&receipt.data.thread, ReceiptThread::Thread(thread_root));
+        assert_eq!(thread_root, "$thread_root:matrix.org");
+
+        assert_eq!(serde_json::to_value(&edu).unwrap(), json);
+    }
+
     #[test]
     fn typing_edu() {
         let json = json!({
@@ -491,6 +540,38 @@ mod tests {
         assert_eq!(serde_json::to_value(&edu).unwrap(), json);
     }
 
+    #[test]
+    fn direct_to_device_event() {
+        let json = json!({
+            "content": {
+                "message_id": "hiezohf6Hoo7kaev",
+                "messages": {
+                    "@alice:example.org": {
+                        "IWHQUZUIAH": {
+                            "action": "request_cancellation",
+                            "request_id": "1495474790150.19",
+                            "requesting_device_id": "RJYKSTBOIE"
+                        }
+                    }
+                },
+                "sender": "@john:example.com",
+                "type": "m.room_key_request"
+            },
+            "edu_type": "m.direct_to_device"
+        });
+
+        let edu = serde_json::from_value::<Edu>(json).unwrap();
+        assert_matches!(edu, Edu::DirectToDevice(content));
+        let message = &content.messages[user_id!("@alice:example.org")]
+            [&DeviceIdOrAllDevices::DeviceId(device_id!("IWHQUZUIAH").to_owned())];
+
+        let event = content.to_device_event(message).unwrap().deserialize().unwrap();
+        assert_matches!(event, AnyToDeviceEvent::RoomKeyRequest(event));
+        assert_eq!(event.sender, "@john:example.com");
+        assert_eq!(event.content.request_id, "1495474790150.19");
+        assert_eq!(event.content.requesting_device_id, "RJYKSTBOIE");
+    }
+
     #[test]
     fn signing_key_update_edu() {
         let json = json!({
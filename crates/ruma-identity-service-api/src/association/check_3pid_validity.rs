@@ -7,12 +7,11 @@ pub mod v2 {
     //!
     //! [spec]: https://spec.matrix.org/latest/identity-service-api/#get_matrixidentityv23pidgetvalidated3pid
 
-    use js_int::UInt;
     use ruma_common::{
         api::{request, response, Metadata},
         metadata,
         thirdparty::Medium,
-        OwnedClientSecret, OwnedSessionId,
+        MilliSecondsSinceUnixEpoch, OwnedClientSecret, OwnedSessionId,
     };
 
     const METADATA: Metadata = metadata! {
@@ -46,7 +45,7 @@ pub mod v2 {
         pub address: String,
 
         /// Timestamp, in milliseconds, indicating the time that the 3PID was validated.
-        pub validated_at: UInt,
+        pub validated_at: MilliSecondsSinceUnixEpoch,
     }
 
     impl Request {
@@ -58,7 +57,11 @@ pub mod v2 {
 
     impl Response {
         /// Creates a `Response` with the given medium, address and validation timestamp.
-        pub fn new(medium: Medium, address: String, validated_at: UInt) -> Self {
+        pub fn new(
+            medium: Medium,
+            address: String,
+            validated_at: MilliSecondsSinceUnixEpoch,
+        ) -> Self {
             Self { medium, address, validated_at }
         }
     }
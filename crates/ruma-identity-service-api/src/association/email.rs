@@ -1,4 +1,17 @@
 //! Endpoints to create an association between a Matrix ID and an email ID on the identity server.
+//!
+//! The validation of an email address starts with a call to
+//! [`create_email_validation_session`], which returns the ID of the session and sends a token to
+//! the email address. The token is then submitted with [`validate_email`], or by the end-user
+//! with [`validate_email_by_end_user`]. The session can then be used with
+//! [`check_3pid_validity`](super::check_3pid_validity) and [`bind_3pid`](super::bind_3pid).
+//!
+//! The session ID and client secret use the same [`OwnedSessionId`] and [`OwnedClientSecret`] types
+//! as the third-party identifier endpoints of the Client-Server API, so a session created by the
+//! homeserver or the client can be passed along unchanged.
+//!
+//! [`OwnedSessionId`]: ruma_common::OwnedSessionId
+//! [`OwnedClientSecret`]: ruma_common::OwnedClientSecret
 
 pub mod create_email_validation_session;
 pub mod validate_email;
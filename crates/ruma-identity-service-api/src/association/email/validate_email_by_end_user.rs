@@ -55,4 +55,52 @@ pub mod v2 {
             Self {}
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::api::{MatrixVersion, OutgoingRequest, SendAccessToken};
+
+            use super::Request;
+
+            let request: http::Request<Vec<u8>> = Request::new(
+                "abc123".try_into().unwrap(),
+                "secret".try_into().unwrap(),
+                "atoken".to_owned(),
+            )
+            .try_into_http_request(
+                "https://identity.example.org",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_0],
+            )
+            .unwrap();
+            assert_eq!(request.uri().path(), "/_matrix/identity/v2/validate/email/submitToken");
+            assert_eq!(request.uri().query(), Some("sid=abc123&client_secret=secret&token=atoken"));
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::api::IncomingRequest;
+
+            use super::Request;
+
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .uri(
+                        "https://identity.example.org/_matrix/identity/v2/validate/email/submitToken\
+                         ?sid=abc123&client_secret=secret&token=atoken",
+                    )
+                    .body(&[] as &[u8])
+                    .unwrap(),
+                &[] as &[String],
+            )
+            .unwrap();
+            assert_eq!(req.sid, "abc123");
+            assert_eq!(req.client_secret, "secret");
+            assert_eq!(req.token, "atoken");
+        }
+    }
 }
@@ -1,4 +1,8 @@
 //! Endpoints to create association between a phone number and a Matrix ID on the identity server.
+//!
+//! This works like the [email validation flow](super::email): a session is created with
+//! [`create_msisdn_validation_session`], and the token sent by SMS is submitted with
+//! [`validate_msisdn`] or by the end-user with [`validate_msisdn_by_phone_number`].
 
 pub mod create_msisdn_validation_session;
 pub mod validate_msisdn;
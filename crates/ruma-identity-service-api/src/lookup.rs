@@ -1,6 +1,10 @@
 //! Endpoints to look up Matrix IDs bound to 3PIDs.
 
-use ruma_common::serde::StringEnum;
+use ruma_common::{
+    serde::{base64::UrlSafe, Base64, StringEnum},
+    thirdparty::Medium,
+};
+use sha2::{Digest, Sha256};
 
 use crate::PrivOwnedStr;
 
@@ -27,10 +31,58 @@ pub enum IdentifierHashingAlgorithm {
     _Custom(PrivOwnedStr),
 }
 
+impl IdentifierHashingAlgorithm {
+    /// Formats the given 3PID address for the `addresses` of a `lookup_3pid` request, with this
+    /// algorithm and the pepper from `get_hash_parameters`.
+    ///
+    /// With `sha256`, the address is hashed and encoded as unpadded URL-safe base64, with
+    /// `none` it is returned as plain text.
+    ///
+    /// Returns `None` if the algorithm is not supported.
+    pub fn hash_address(&self, address: &str, medium: &Medium, pepper: &str) -> Option<String> {
+        match self {
+            Self::Sha256 => {
+                let hash = Sha256::digest(format!("{address} {medium} {pepper}"));
+                Some(Base64::<UrlSafe, _>::new(hash).encode())
+            }
+            Self::None => Some(format!("{address} {medium}")),
+            Self::_Custom(_) => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use ruma_common::thirdparty::Medium;
+
     use super::IdentifierHashingAlgorithm;
 
+    #[test]
+    fn hash_address() {
+        let pepper = "matrixrocks";
+
+        assert_eq!(
+            IdentifierHashingAlgorithm::Sha256
+                .hash_address("alice@example.com", &Medium::Email, pepper)
+                .unwrap(),
+            "4kenr7N9drpCJ4AfalmlGQVsOn3o2RHjkADUpXJWZUc"
+        );
+        assert_eq!(
+            IdentifierHashingAlgorithm::None
+                .hash_address("alice@example.com", &Medium::Email, pepper)
+                .unwrap(),
+            "alice@example.com email"
+        );
+        assert_eq!(
+            IdentifierHashingAlgorithm::from("custom").hash_address(
+                "alice@example.com",
+                &Medium::Email,
+                pepper
+            ),
+            None
+        );
+    }
+
     #[test]
     fn parse_identifier_hashing_algorithm() {
         assert_eq!(IdentifierHashingAlgorithm::from("sha256"), IdentifierHashingAlgorithm::Sha256);
@@ -156,6 +156,14 @@ impl Request {
             vars(self.body_fields(), quote! { request_body })
         };
 
+        let raw_body_request_impl = self.raw_body_field().is_some().then(|| {
+            quote! {
+                #[automatically_derived]
+                #[cfg(feature = "server")]
+                impl #ruma_common::api::IncomingRawBodyRequest for Request {}
+            }
+        });
+
         quote! {
             #[automatically_derived]
             #[cfg(feature = "server")]
@@ -198,6 +206,8 @@ impl Request {
                     })
                 }
             }
+
+            #raw_body_request_impl
         }
     }
 }
@@ -62,7 +62,17 @@ impl Request {
         // `application/json` content-type would be wrong. It may also cause problems with CORS
         // policies that don't allow the `Content-Type` header (for things such as `.well-known`
         // that are commonly handled by something else than a homeserver).
-        let mut header_kvs = if self.raw_body_field().is_some() || self.has_body_fields() {
+        //
+        // Raw bodies can contain anything, so they get a generic content-type unless a header
+        // field overrides it.
+        let mut header_kvs = if self.raw_body_field().is_some() {
+            quote! {
+                req_headers.insert(
+                    #http::header::CONTENT_TYPE,
+                    #http::header::HeaderValue::from_static("application/octet-stream"),
+                );
+            }
+        } else if self.has_body_fields() {
             quote! {
                 req_headers.insert(
                     #http::header::CONTENT_TYPE,
@@ -117,6 +127,15 @@ impl Request {
 
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
+        let raw_body_request_impl = self.raw_body_field().is_some().then(|| {
+            quote! {
+                #[automatically_derived]
+                #[cfg(feature = "client")]
+                impl #impl_generics #ruma_common::api::OutgoingRawBodyRequest
+                    for Request #ty_generics #where_clause {}
+            }
+        });
+
         quote! {
             #[automatically_derived]
             #[cfg(feature = "client")]
@@ -150,6 +169,8 @@ impl Request {
                     Ok(http_request)
                 }
             }
+
+            #raw_body_request_impl
         }
     }
 }
@@ -52,6 +52,7 @@ impl Response {
                         }
                     }
                     ResponseFieldKind::Header(header_name) => {
+                        let header_name_string = header_name.to_string();
                         let optional_header = match &field.ty {
                             syn::Type::Path(syn::TypePath {
                                 path: syn::Path { segments, .. },
@@ -59,10 +60,9 @@ impl Response {
                             }) if segments.last().unwrap().ident == "Option" => {
                                 quote! {
                                     #( #cfg_attrs )*
-                                    #field_name: {
-                                        headers.remove(#header_name)
-                                            .map(|h| h.to_str().map(|s| s.to_owned()))
-                                            .transpose()?
+                                    #field_name: match headers.remove(#header_name) {
+                                        Some(header) => Some(header.to_str()?.parse()?),
+                                        None => None,
                                     }
                                 }
                             }
@@ -70,9 +70,13 @@ impl Response {
                                 #( #cfg_attrs )*
                                 #field_name: {
                                     headers.remove(#header_name)
-                                        .expect("response missing expected header")
+                                        .ok_or_else(|| {
+                                            #ruma_common::api::error::HeaderDeserializationError::MissingHeader(
+                                                #header_name_string.into()
+                                            )
+                                        })?
                                         .to_str()?
-                                        .to_owned()
+                                        .parse()?
                                 }
                             },
                         };
@@ -104,6 +108,14 @@ impl Response {
             }
         };
 
+        let raw_body_response_impl = self.has_raw_body().then(|| {
+            quote! {
+                #[automatically_derived]
+                #[cfg(feature = "client")]
+                impl #ruma_common::api::IncomingRawBodyResponse for Response {}
+            }
+        });
+
         quote! {
             #[automatically_derived]
             #[cfg(feature = "client")]
@@ -132,6 +144,8 @@ impl Response {
                     }
                 }
             }
+
+            #raw_body_response_impl
         }
     }
 }
@@ -21,7 +21,7 @@ impl Response {
                             if let Some(header) = self.#field_name {
                                 headers.insert(
                                     #header_name,
-                                    header.parse()?,
+                                    header.to_string().parse()?,
                                 );
                             }
                         }
@@ -29,16 +29,20 @@ impl Response {
                     _ => quote! {
                         headers.insert(
                             #header_name,
-                            self.#field_name.parse()?,
+                            self.#field_name.to_string().parse()?,
                         );
                     },
                 }
             })
         });
 
-        let body = if let Some(field) =
-            self.fields.iter().find_map(ResponseField::as_raw_body_field)
-        {
+        let raw_body_field = self.fields.iter().find_map(ResponseField::as_raw_body_field);
+
+        // The default content-type, header fields can override it.
+        let content_type =
+            if raw_body_field.is_some() { "application/octet-stream" } else { "application/json" };
+
+        let body = if let Some(field) = raw_body_field {
             let field_name = field.ident.as_ref().expect("expected field to have an identifier");
             quote! { #ruma_common::serde::slice_to_buf(&self.#field_name) }
         } else {
@@ -60,6 +64,14 @@ impl Response {
             }
         };
 
+        let raw_body_response_impl = raw_body_field.is_some().then(|| {
+            quote! {
+                #[automatically_derived]
+                #[cfg(feature = "server")]
+                impl #ruma_common::api::OutgoingRawBodyResponse for Response {}
+            }
+        });
+
         quote! {
             #[automatically_derived]
             #[cfg(feature = "server")]
@@ -68,7 +80,7 @@ impl Response {
                     self,
                 ) -> ::std::result::Result<#http::Response<T>, #ruma_common::api::error::IntoHttpError> {
                     let mut resp_builder = #http::Response::builder()
-                        .header(#http::header::CONTENT_TYPE, "application/json");
+                        .header(#http::header::CONTENT_TYPE, #content_type);
 
                     if let Some(mut headers) = resp_builder.headers_mut() {
                         #(#serialize_response_headers)*
@@ -77,6 +89,8 @@ impl Response {
                     ::std::result::Result::Ok(resp_builder.body(#body)?)
                 }
             }
+
+            #raw_body_response_impl
         }
     }
 }
@@ -64,7 +64,7 @@ pub fn expand_event_enums(input: &EventEnumDecl) -> syn::Result<TokenStream> {
             .unwrap_or_else(syn::Error::into_compile_error),
     );
 
-    if matches!(kind, EventKind::MessageLike | EventKind::State) {
+    if matches!(kind, EventKind::MessageLike | EventKind::State | EventKind::Ephemeral) {
         res.extend(
             expand_event_enum(kind, V::Sync, events, docs, attrs, variants, ruma_events)
                 .unwrap_or_else(syn::Error::into_compile_error),
@@ -79,13 +79,6 @@ pub fn expand_event_enums(input: &EventEnumDecl) -> syn::Result<TokenStream> {
         );
     }
 
-    if matches!(kind, EventKind::Ephemeral) {
-        res.extend(
-            expand_event_enum(kind, V::Sync, events, docs, attrs, variants, ruma_events)
-                .unwrap_or_else(syn::Error::into_compile_error),
-        );
-    }
-
     if matches!(kind, EventKind::State) {
         res.extend(expand_full_content_enum(kind, events, docs, attrs, variants, ruma_events));
         res.extend(
@@ -191,6 +184,8 @@ fn expand_deserialize_impl(
             {
                 use #serde::de::Error as _;
 
+                // Only the `type` field is parsed up front, the event itself is then deserialized
+                // once from the raw JSON into the matching variant.
                 let json = Box::<#serde_json::value::RawValue>::deserialize(deserializer)?;
                 let #ruma_events::EventTypeDeHelper { ev_type, .. } =
                     #ruma_common::serde::from_raw_json_value(&json)?;
@@ -53,11 +53,12 @@ impl EventKindVariation {
     }
 
     pub fn is_sync(self) -> bool {
-        matches!(self, Self::OriginalSync | Self::RedactedSync)
+        matches!(self, Self::Sync | Self::OriginalSync | Self::RedactedSync)
     }
 
     pub fn to_full(self) -> Self {
         match self {
+            EventKindVariation::Sync => EventKindVariation::None,
             EventKindVariation::OriginalSync => EventKindVariation::Original,
             EventKindVariation::RedactedSync => EventKindVariation::Redacted,
             _ => panic!("No original (unredacted) form of {self:?}"),
@@ -51,6 +51,10 @@ pub fn expand_event_type_enum(
         generate_enum("TimelineEventType", &timeline, &ruma_events)
             .unwrap_or_else(syn::Error::into_compile_error),
     );
+    res.extend(
+        generate_timeline_kind_checks(&state, &message)
+            .unwrap_or_else(syn::Error::into_compile_error),
+    );
     res.extend(
         generate_enum("StateEventType", &state, &ruma_events)
             .unwrap_or_else(syn::Error::into_compile_error),
@@ -79,6 +83,61 @@ pub fn expand_event_type_enum(
     Ok(res)
 }
 
+fn generate_timeline_kind_checks(
+    state: &[&Vec<EventEnumEntry>],
+    message: &[&Vec<EventEnumEntry>],
+) -> syn::Result<TokenStream> {
+    let match_arms = |input: &[&Vec<EventEnumEntry>]| {
+        let mut seen: Vec<&LitStr> = vec![];
+        input
+            .iter()
+            .copied()
+            .flatten()
+            .filter(|e| {
+                let is_new = !seen.contains(&&e.ev_type);
+                seen.push(&e.ev_type);
+                is_new
+            })
+            .map(|e| {
+                let start = e.to_variant()?.match_arm(quote! { Self });
+                let data = e.has_type_fragment().then(|| quote! { (_) });
+
+                Ok(quote! { #start #data => true })
+            })
+            .collect::<syn::Result<Vec<_>>>()
+    };
+
+    let state_match_arms = match_arms(state)?;
+    let message_match_arms = match_arms(message)?;
+
+    Ok(quote! {
+        #[allow(deprecated)]
+        impl TimelineEventType {
+            /// Whether this is the type of a state event.
+            ///
+            /// Always returns `false` for event types that are not known to Ruma, since their
+            /// kind can't be determined from their type alone.
+            pub fn is_state(&self) -> bool {
+                match self {
+                    #(#state_match_arms,)*
+                    _ => false,
+                }
+            }
+
+            /// Whether this is the type of a message-like event.
+            ///
+            /// Always returns `false` for event types that are not known to Ruma, since their
+            /// kind can't be determined from their type alone.
+            pub fn is_message_like(&self) -> bool {
+                match self {
+                    #(#message_match_arms,)*
+                    _ => false,
+                }
+            }
+        }
+    })
+}
+
 fn generate_enum(
     ident: &str,
     input: &[&Vec<EventEnumEntry>],
@@ -353,6 +353,13 @@ fn expand_owned_id(input: &ItemStruct) -> TokenStream {
             }
         }
 
+        #[automatically_derived]
+        impl #impl_generics From<std::rc::Rc<#id_ty>> for #owned_ty {
+            fn from(r: std::rc::Rc<#id_ty>) -> #owned_ty {
+                Self { inner: r.as_ref().into() }
+            }
+        }
+
         #[automatically_derived]
         impl #impl_generics From<#owned_ty> for Box<#id_ty> {
             fn from(a: #owned_ty) -> Box<#id_ty> {
@@ -373,6 +380,16 @@ fn expand_owned_id(input: &ItemStruct) -> TokenStream {
             }
         }
 
+        #[automatically_derived]
+        impl #impl_generics From<#owned_ty> for std::rc::Rc<#id_ty> {
+            fn from(a: #owned_ty) -> std::rc::Rc<#id_ty> {
+                #[cfg(not(any(ruma_identifiers_storage = "Arc")))]
+                { a.inner.into() }
+                #[cfg(ruma_identifiers_storage = "Arc")]
+                { a.inner.as_ref().into() }
+            }
+        }
+
         #[automatically_derived]
         impl #impl_generics std::cmp::PartialEq for #owned_ty {
             fn eq(&self, other: &Self) -> bool {
@@ -14,8 +14,8 @@ use proc_macro::TokenStream;
 use proc_macro2 as pm2;
 use quote::quote;
 use ruma_identifiers_validation::{
-    device_key_id, event_id, key_id, mxc_uri, room_alias_id, room_id, room_version_id, server_name,
-    user_id,
+    device_key_id, event_id, key_id, mxc_uri, room_alias_id, room_id, room_id_or_alias_id,
+    room_version_id, server_name, user_id,
 };
 use syn::{parse_macro_input, DeriveInput, ItemEnum, ItemStruct};
 
@@ -197,6 +197,19 @@ pub fn room_id(input: TokenStream) -> TokenStream {
     output.into()
 }
 
+/// Compile-time checked `RoomOrAliasId` construction.
+#[proc_macro]
+pub fn room_or_alias_id(input: TokenStream) -> TokenStream {
+    let IdentifierInput { dollar_crate, id } = parse_macro_input!(input as IdentifierInput);
+    assert!(room_id_or_alias_id::validate(&id.value()).is_ok(), "Invalid room_or_alias_id");
+
+    let output = quote! {
+        <&#dollar_crate::RoomOrAliasId as ::std::convert::TryFrom<&str>>::try_from(#id).unwrap()
+    };
+
+    output.into()
+}
+
 /// Compile-time checked `RoomVersionId` construction.
 #[proc_macro]
 pub fn room_version_id(input: TokenStream) -> TokenStream {
@@ -133,6 +133,20 @@ fn parse_xmatrix_field<'a>(tokens: &mut impl Tokens<Item = &'a u8>) -> Option<(S
     })
 }
 
+fn parse_xmatrix_field_separator<'a>(tokens: &mut impl Tokens<Item = &'a u8>) -> bool {
+    tokens
+        .optional(|t| {
+            // Optional whitespace is allowed around the separator of the list of auth params.
+            t.skip_tokens_while(|c| is_ows(**c));
+            if !t.token(&b',') {
+                return None;
+            }
+            t.skip_tokens_while(|c| is_ows(**c));
+            Some(())
+        })
+        .is_some()
+}
+
 fn parse_xmatrix<'a>(tokens: &mut impl Tokens<Item = &'a u8>) -> Option<XMatrix> {
     tokens.optional(|t| {
         if !t.tokens(b"X-Matrix ") {
@@ -144,7 +158,9 @@ fn parse_xmatrix<'a>(tokens: &mut impl Tokens<Item = &'a u8>) -> Option<XMatrix>
         let mut key = None;
         let mut sig = None;
 
-        for (name, value) in t.sep_by(|t| parse_xmatrix_field(t), |t| t.token(&b',')) {
+        for (name, value) in
+            t.sep_by(|t| parse_xmatrix_field(t), |t| parse_xmatrix_field_separator(t))
+        {
             match name.as_str() {
                 "origin" => {
                     if origin.is_some() {
@@ -194,6 +210,10 @@ fn is_tchar(c: u8) -> bool {
     is_alpha(c) || is_digit(c) || TOKEN_CHARS.contains(&c)
 }
 
+fn is_ows(c: u8) -> bool {
+    c == b' ' || c == b'\t'
+}
+
 fn is_qdtext(c: u8) -> bool {
     c == b'\t'
         || c == b' '
@@ -280,4 +300,14 @@ mod tests {
 
         assert_eq!(credentials.encode(), header);
     }
+
+    #[test]
+    fn xmatrix_auth_with_whitespace_around_separators() {
+        let header = HeaderValue::from_static("X-Matrix origin=origin.hs.example.com , destination=destination.hs.example.com,\tkey=\"ed25519:key1\", sig=\"ABCDEF...\"");
+        let credentials: XMatrix = Credentials::decode(&header).unwrap();
+        assert_eq!(credentials.origin, "origin.hs.example.com");
+        assert_eq!(credentials.destination.unwrap(), "destination.hs.example.com");
+        assert_eq!(credentials.key, "ed25519:key1");
+        assert_eq!(credentials.sig, "ABCDEF...");
+    }
 }
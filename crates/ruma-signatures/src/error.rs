@@ -41,6 +41,10 @@ pub enum Error {
     /// PDU was too large
     #[error("PDU is larger than maximum of 65535 bytes")]
     PduSize,
+
+    /// The room version of the event is not supported.
+    #[error("room version {0} is not supported")]
+    UnsupportedRoomVersion(RoomVersionId),
 }
 
 impl From<RedactionError> for Error {
@@ -9,6 +9,7 @@ use std::{
 use base64::{alphabet, Engine};
 use ruma_common::{
     canonical_json::{redact, JsonType},
+    room_version_rules::{EventFormatVersion, RoomVersionRules},
     serde::{base64::Standard, Base64},
     CanonicalJsonObject, CanonicalJsonValue, OwnedEventId, OwnedServerName, RoomVersionId, UserId,
 };
@@ -18,7 +19,7 @@ use sha2::{digest::Digest, Sha256};
 use crate::{
     keys::{KeyPair, PublicKeyMap},
     split_id,
-    verification::{Ed25519Verifier, Verified, Verifier},
+    verification::{Ed25519Verifier, Verified, VerifiedKeyMap, Verifier},
     Error, JsonError, ParseError, VerificationError,
 };
 
@@ -172,9 +173,13 @@ pub fn canonical_json(object: &CanonicalJsonObject) -> Result<String, Error> {
 /// "ed25519:1") then map to their respective public keys.
 /// * object: The JSON object that was signed.
 ///
+/// Entities of `public_key_map` that did not sign `object` are ignored. To require a signature
+/// from each of them, use [`verify_json_signatures`].
+///
 /// # Errors
 ///
-/// Returns an error if verification fails.
+/// Returns an error if verification fails, or if `object` contains a signature for which there is
+/// no public key in `public_key_map`.
 ///
 /// # Examples
 ///
@@ -209,11 +214,91 @@ pub fn verify_json(
     public_key_map: &PublicKeyMap,
     object: &CanonicalJsonObject,
 ) -> Result<(), Error> {
-    let signature_map = match object.get("signatures") {
-        Some(CanonicalJsonValue::Object(signatures)) => signatures.clone(),
-        Some(_) => return Err(JsonError::not_of_type("signatures", JsonType::Object)),
-        None => return Err(JsonError::field_missing_from_object("signatures")),
-    };
+    verify_signature_map(public_key_map, object, signature_map(object)?)?;
+
+    Ok(())
+}
+
+/// Uses a set of public keys to verify a signed JSON object, requiring a signature from each
+/// entity.
+///
+/// This is the same as [`verify_json`], except that `object` must contain at least one signature
+/// for each of the entities in `public_key_map`, and that the keys whose signatures were verified
+/// are returned.
+///
+/// # Parameters
+///
+/// * public_key_map: A map from entity identifiers to a map from key identifiers to public keys.
+/// Generally, entity identifiers are server names — the host/IP/port of a homeserver (e.g.
+/// "example.com") for which a signature must be verified. Key identifiers for each server (e.g.
+/// "ed25519:1") then map to their respective public keys.
+/// * object: The JSON object that was signed.
+///
+/// # Errors
+///
+/// Returns an error if verification fails, if `object` contains a signature for which there is no
+/// public key in `public_key_map`, or if `object` is missing signatures from one of the entities in
+/// `public_key_map`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use ruma_common::serde::Base64;
+///
+/// const PUBLIC_KEY: &[u8] = b"XGX0JRS2Af3be3knz2fBiRbApjm2Dh61gXDJA8kcJNI";
+///
+/// // Deserialize the signed JSON.
+/// let object = serde_json::from_str(
+///     r#"{
+///         "signatures": {
+///             "domain": {
+///                 "ed25519:1": "K8280/U9SSy9IVtjBuVeLr+HpOB4BQFWbg+UZaADMtTdGYI7Geitb76LTrr5QV/7Xg4ahLwYGYZzuHGZKM5ZAQ"
+///             }
+///         }
+///     }"#
+/// ).unwrap();
+///
+/// // Create the `PublicKeyMap` of the entities that must have signed the JSON.
+/// let mut public_key_set = BTreeMap::new();
+/// public_key_set.insert("ed25519:1".into(), Base64::parse(PUBLIC_KEY.to_owned()).unwrap());
+/// let mut public_key_map = BTreeMap::new();
+/// public_key_map.insert("domain".into(), public_key_set);
+///
+/// // Verify the signatures and get the keys that signed the JSON.
+/// let verified = ruma_signatures::verify_json_signatures(&public_key_map, &object).unwrap();
+/// assert!(verified["domain"].contains("ed25519:1"));
+/// ```
+pub fn verify_json_signatures(
+    public_key_map: &PublicKeyMap,
+    object: &CanonicalJsonObject,
+) -> Result<VerifiedKeyMap, Error> {
+    let signature_map = signature_map(object)?;
+
+    if let Some(entity_id) = public_key_map.keys().find(|e| !signature_map.contains_key(*e)) {
+        return Err(JsonError::key_missing("signatures", "signature set", entity_id));
+    }
+
+    verify_signature_map(public_key_map, object, signature_map)
+}
+
+/// Get the `signatures` of the given JSON object.
+fn signature_map(object: &CanonicalJsonObject) -> Result<CanonicalJsonObject, Error> {
+    match object.get("signatures") {
+        Some(CanonicalJsonValue::Object(signatures)) => Ok(signatures.clone()),
+        Some(_) => Err(JsonError::not_of_type("signatures", JsonType::Object)),
+        None => Err(JsonError::field_missing_from_object("signatures")),
+    }
+}
+
+/// Verify all the signatures in the given signature map, and return the keys that were verified.
+fn verify_signature_map(
+    public_key_map: &PublicKeyMap,
+    object: &CanonicalJsonObject,
+    signature_map: CanonicalJsonObject,
+) -> Result<VerifiedKeyMap, Error> {
+    let mut verified = VerifiedKeyMap::new();
 
     for (entity_id, signature_set) in signature_map {
         let signature_set = match signature_set {
@@ -251,10 +336,12 @@ pub fn verify_json(
                 signature.as_bytes(),
                 object,
             )?;
+
+            verified.entry(entity_id.clone()).or_default().insert(key_id.clone());
         }
     }
 
-    Ok(())
+    Ok(verified)
 }
 
 /// Uses a public key to verify a signed JSON object.
@@ -306,11 +393,13 @@ pub fn content_hash(object: &CanonicalJsonObject) -> Result<Base64<Standard, [u8
 
 /// Creates a *reference hash* for an event.
 ///
-/// Returns the hash as a base64-encoded string, using the standard character set, without padding.
+/// Returns the hash as a base64-encoded string without padding, using the standard character set
+/// for room versions 1 to 3 and the URL-safe character set for later room versions.
 ///
 /// The reference hash of an event covers the essential fields of an event, including content
 /// hashes. It is used to generate event identifiers and is described in the Matrix server-server
-/// specification.
+/// specification. For room versions 3 and later, the event ID can be constructed from the reference
+/// hash with [`EventId::from_reference_hash`](ruma_common::EventId::from_reference_hash).
 ///
 /// # Parameters
 ///
@@ -318,12 +407,14 @@ pub fn content_hash(object: &CanonicalJsonObject) -> Result<Base64<Standard, [u8
 ///
 /// # Errors
 ///
-/// Returns an error if the event is too large or redaction fails.
+/// Returns an error if the event is too large, redaction fails or the room version is not
+/// supported.
 pub fn reference_hash(
     value: &CanonicalJsonObject,
     version: &RoomVersionId,
 ) -> Result<String, Error> {
-    let redacted_value = redact(value.clone(), version, None)?;
+    let rules = room_version_rules(version)?;
+    let redacted_value = redact(value.clone(), &rules.redaction, None)?;
 
     let json =
         canonical_json_with_fields_to_remove(&redacted_value, REFERENCE_HASH_FIELDS_TO_REMOVE)?;
@@ -333,8 +424,8 @@ pub fn reference_hash(
 
     let hash = Sha256::digest(json.as_bytes());
 
-    let base64_alphabet = match version {
-        RoomVersionId::V1 | RoomVersionId::V2 | RoomVersionId::V3 => alphabet::STANDARD,
+    let base64_alphabet = match rules.event_format {
+        EventFormatVersion::V1 | EventFormatVersion::V2 => alphabet::STANDARD,
         // Room versions higher than version 3 are url safe base64 encoded
         _ => alphabet::URL_SAFE,
     };
@@ -367,6 +458,7 @@ pub fn reference_hash(
 /// * `object` contains a field called `hashes` that is not a JSON object.
 /// * `object` contains a field called `signatures` that is not a JSON object.
 /// * `object` is missing the `type` field or the field is not a JSON string.
+/// * `version` is not a supported room version.
 ///
 /// # Examples
 ///
@@ -463,7 +555,8 @@ where
         _ => return Err(JsonError::not_of_type("hashes", JsonType::Object)),
     };
 
-    let mut redacted = redact(object.clone(), version, None)?;
+    let rules = room_version_rules(version)?;
+    let mut redacted = redact(object.clone(), &rules.redaction, None)?;
 
     sign_json(entity_id, key_pair, &mut redacted)?;
 
@@ -544,7 +637,8 @@ pub fn verify_event(
     object: &CanonicalJsonObject,
     version: &RoomVersionId,
 ) -> Result<Verified, Error> {
-    let redacted = redact(object.clone(), version, None)?;
+    let rules = room_version_rules(version)?;
+    let redacted = redact(object.clone(), &rules.redaction, None)?;
 
     let hash = match object.get("hashes") {
         Some(hashes_value) => match hashes_value {
@@ -553,9 +647,9 @@ pub fn verify_event(
                     CanonicalJsonValue::String(hash) => hash,
                     _ => return Err(JsonError::not_of_type("sha256 hash", JsonType::String)),
                 },
-                None => return Err(JsonError::not_of_type("hashes", JsonType::Object)),
+                None => return Err(JsonError::field_missing_from_object("sha256")),
             },
-            _ => return Err(JsonError::field_missing_from_object("sha256")),
+            _ => return Err(JsonError::not_of_type("hashes", JsonType::Object)),
         },
         None => return Err(JsonError::field_missing_from_object("hashes")),
     };
@@ -566,7 +660,7 @@ pub fn verify_event(
         None => return Err(JsonError::field_missing_from_object("signatures")),
     };
 
-    let servers_to_check = servers_to_check_signatures(object, version)?;
+    let servers_to_check = servers_to_check_signatures(object, version, &rules)?;
     let canonical_json = from_json_str(&canonical_json(&redacted)?).map_err(JsonError::from)?;
 
     for entity_id in servers_to_check {
@@ -654,6 +748,7 @@ fn canonical_json_with_fields_to_remove(
 fn servers_to_check_signatures(
     object: &CanonicalJsonObject,
     version: &RoomVersionId,
+    rules: &RoomVersionRules,
 ) -> Result<BTreeSet<OwnedServerName>, Error> {
     let mut servers_to_check = BTreeSet::new();
 
@@ -669,8 +764,8 @@ fn servers_to_check_signatures(
         };
     }
 
-    match version {
-        RoomVersionId::V1 | RoomVersionId::V2 => match object.get("event_id") {
+    if rules.event_format == EventFormatVersion::V1 {
+        match object.get("event_id") {
             Some(CanonicalJsonValue::String(raw_event_id)) => {
                 let event_id: OwnedEventId =
                     raw_event_id.parse().map_err(|e| Error::from(ParseError::EventId(e)))?;
@@ -685,34 +780,35 @@ fn servers_to_check_signatures(
             _ => {
                 return Err(JsonError::field_missing_from_object("event_id"));
             }
-        },
-        RoomVersionId::V3
-        | RoomVersionId::V4
-        | RoomVersionId::V5
-        | RoomVersionId::V6
-        | RoomVersionId::V7 => {}
-        // TODO: And for all future versions that have join_authorised_via_users_server
-        RoomVersionId::V8 | RoomVersionId::V9 | RoomVersionId::V10 | RoomVersionId::V11 => {
-            if let Some(authorized_user) = object
-                .get("content")
-                .and_then(|c| c.as_object())
-                .and_then(|c| c.get("join_authorised_via_users_server"))
-            {
-                let authorized_user = authorized_user.as_str().ok_or_else(|| {
-                    JsonError::not_of_type("join_authorised_via_users_server", JsonType::String)
-                })?;
-                let authorized_user = <&UserId>::try_from(authorized_user)
-                    .map_err(|e| Error::from(ParseError::UserId(e)))?;
+        }
+    }
 
-                servers_to_check.insert(authorized_user.server_name().to_owned());
-            }
+    if rules.restricted_join_rules {
+        if let Some(authorized_user) = object
+            .get("content")
+            .and_then(|c| c.as_object())
+            .and_then(|c| c.get("join_authorised_via_users_server"))
+        {
+            let authorized_user = authorized_user.as_str().ok_or_else(|| {
+                JsonError::not_of_type("join_authorised_via_users_server", JsonType::String)
+            })?;
+            let authorized_user = <&UserId>::try_from(authorized_user)
+                .map_err(|e| Error::from(ParseError::UserId(e)))?;
+
+            servers_to_check.insert(authorized_user.server_name().to_owned());
         }
-        _ => unimplemented!(),
     }
 
     Ok(servers_to_check)
 }
 
+/// Get the rules of the given room version.
+///
+/// Returns an error if the room version is not supported.
+fn room_version_rules(version: &RoomVersionId) -> Result<RoomVersionRules, Error> {
+    version.rules().ok_or_else(|| Error::UnsupportedRoomVersion(version.clone()))
+}
+
 /// Checks if `object` contains an event of type `m.room.third_party_invite`
 fn is_third_party_invite(object: &CanonicalJsonObject) -> Result<bool, Error> {
     match object.get("type") {
@@ -733,7 +829,7 @@ mod tests {
 
     use super::canonical_json;
     use crate::{
-        sign_json, verify_event, Ed25519KeyPair, Error, PublicKeyMap, PublicKeySet,
+        sign_json, verify_event, Ed25519KeyPair, Error, JsonError, PublicKeyMap, PublicKeySet,
         VerificationError, Verified,
     };
 
@@ -1151,6 +1247,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn verify_event_fails_with_unsupported_room_version() {
+        let signed_event = serde_json::from_str(
+            r#"{
+                "auth_events": [],
+                "content": {},
+                "depth": 3,
+                "hashes": {
+                    "sha256": "5jM4wQpv6lnBo7CLIghJuHdW+s2CMBJPUOGOC89ncos"
+                },
+                "origin": "domain",
+                "origin_server_ts": 1000000,
+                "prev_events": [],
+                "room_id": "!x:domain",
+                "sender": "@name:domain-sender",
+                "signatures": {},
+                "type": "X",
+                "unsigned": {
+                    "age_ts": 1000000
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let public_key_map = BTreeMap::new();
+        let room_version = RoomVersionId::try_from("io.ruma.unknown").unwrap();
+
+        let verification_result = verify_event(&public_key_map, &signed_event, &room_version);
+        assert_matches!(verification_result, Err(Error::UnsupportedRoomVersion(version)));
+        assert_eq!(version, room_version);
+    }
+
+    #[test]
+    fn verify_event_fails_with_missing_sha256_hash() {
+        let signed_event = serde_json::from_str(
+            r#"{
+                "auth_events": [],
+                "content": {},
+                "depth": 3,
+                "hashes": {},
+                "origin": "domain",
+                "origin_server_ts": 1000000,
+                "prev_events": [],
+                "room_id": "!x:domain",
+                "sender": "@name:domain-sender",
+                "signatures": {},
+                "type": "X",
+                "unsigned": {
+                    "age_ts": 1000000
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let public_key_map = BTreeMap::new();
+
+        let verification_result = verify_event(&public_key_map, &signed_event, &RoomVersionId::V6);
+        assert_matches!(
+            verification_result,
+            Err(Error::Json(JsonError::JsonFieldMissingFromObject(field)))
+        );
+        assert_eq!(field, "sha256");
+    }
+
     fn generate_key_pair(name: &str) -> Ed25519KeyPair {
         let key_content = Ed25519KeyPair::generate().unwrap();
         Ed25519KeyPair::from_der(&key_content, name.to_owned())
@@ -151,6 +151,11 @@ impl Ed25519KeyPair {
     pub fn public_key(&self) -> [u8; PUBLIC_KEY_LENGTH] {
         self.signing_key.verifying_key().to_bytes()
     }
+
+    /// Returns the public key as unpadded base64, the form used in [`PublicKeySet`]s.
+    pub fn public_key_base64(&self) -> Base64 {
+        Base64::new(self.public_key().to_vec())
+    }
 }
 
 impl KeyPair for Ed25519KeyPair {
@@ -214,6 +219,11 @@ mod tests {
         let keypair = Ed25519KeyPair::from_der(WELL_FORMED_DOC, "".to_owned()).unwrap();
 
         assert_eq!(keypair.public_key(), WELL_FORMED_PUBKEY);
+        assert_eq!(keypair.public_key_base64().as_bytes(), WELL_FORMED_PUBKEY);
+        assert_eq!(
+            keypair.public_key_base64().encode(),
+            "Gb9ECWmEzf6FQbrBZ9w7lshQhqowtrbLDFw4rXAxZuE"
+        );
     }
 
     #[cfg(feature = "ring-compat")]
@@ -38,7 +38,8 @@
 //! When a homeserver receives data from another homeserver via the federation, it's necessary to
 //! verify the authenticity and integrity of the data by verifying their signatures.
 //!
-//! To verify a signature on arbitrary JSON, use the `verify_json` function. To verify the
+//! To verify a signature on arbitrary JSON, use the `verify_json` function, or the
+//! `verify_json_signatures` function to also require a signature from each entity. To verify the
 //! signatures and hashes on an event, use the `verify_event` function. See the documentation for
 //! these respective functions for more details and full examples of use.
 
@@ -50,11 +51,11 @@ pub use self::{
     error::{Error, JsonError, ParseError, VerificationError},
     functions::{
         canonical_json, content_hash, hash_and_sign_event, reference_hash, sign_json, verify_event,
-        verify_json,
+        verify_json, verify_json_signatures,
     },
     keys::{Ed25519KeyPair, KeyPair, PublicKeyMap, PublicKeySet},
     signatures::Signature,
-    verification::Verified,
+    verification::{Verified, VerifiedKeyMap},
 };
 
 mod error;
@@ -109,6 +110,7 @@ fn split_id(id: &str) -> Result<(Algorithm, String), Error> {
 mod tests {
     use std::collections::BTreeMap;
 
+    use assert_matches2::assert_matches;
     use pkcs8::{der::Decode, PrivateKeyInfo};
     use ruma_common::{
         serde::{base64::Standard, Base64},
@@ -117,7 +119,8 @@ mod tests {
     use serde_json::{from_str as from_json_str, to_string as to_json_string};
 
     use super::{
-        canonical_json, hash_and_sign_event, sign_json, verify_event, verify_json, Ed25519KeyPair,
+        canonical_json, content_hash, hash_and_sign_event, reference_hash, sign_json, verify_event,
+        verify_json, verify_json_signatures, Ed25519KeyPair, Error, JsonError,
     };
 
     fn pkcs8() -> Vec<u8> {
@@ -312,6 +315,42 @@ mod tests {
         verify_json(&public_key_map, &value).unwrap_err();
     }
 
+    #[test]
+    fn verify_json_signatures_report() {
+        let value = from_json_str(r#"{"signatures":{"domain":{"ed25519:1":"lXjsnvhVlz8t3etR+6AEJ0IT70WujeHC1CFjDDsVx0xSig1Bx7lvoi1x3j/2/GPNjQM4a2gD34UqsXFluaQEBA"}}}"#).unwrap();
+
+        let mut signature_set = BTreeMap::new();
+        signature_set.insert("ed25519:1".into(), public_key_string());
+
+        let mut public_key_map = BTreeMap::new();
+        public_key_map.insert("domain".into(), signature_set);
+
+        let verified = verify_json_signatures(&public_key_map, &value).unwrap();
+        assert_eq!(verified.len(), 1);
+        assert_eq!(verified["domain"].iter().collect::<Vec<_>>(), ["ed25519:1"]);
+    }
+
+    #[test]
+    fn fail_verify_json_signatures_with_missing_signatures_from_entity() {
+        let value = from_json_str(r#"{"signatures":{"domain":{"ed25519:1":"lXjsnvhVlz8t3etR+6AEJ0IT70WujeHC1CFjDDsVx0xSig1Bx7lvoi1x3j/2/GPNjQM4a2gD34UqsXFluaQEBA"}}}"#).unwrap();
+
+        let mut signature_set = BTreeMap::new();
+        signature_set.insert("ed25519:1".into(), public_key_string());
+
+        let mut public_key_map = BTreeMap::new();
+        public_key_map.insert("domain".into(), signature_set.clone());
+        public_key_map.insert("other.domain".into(), signature_set);
+
+        // `verify_json` ignores the entities that did not sign the JSON.
+        verify_json(&public_key_map, &value).unwrap();
+
+        assert_matches!(
+            verify_json_signatures(&public_key_map, &value),
+            Err(Error::Json(JsonError::JsonKeyMissing { with_key, .. }))
+        );
+        assert_eq!(with_key, "other.domain");
+    }
+
     #[test]
     fn sign_minimal_event() {
         let key_pair = Ed25519KeyPair::from_der(&pkcs8(), "1".into()).unwrap();
@@ -342,6 +381,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn content_hash_ignores_hashes_signatures_and_unsigned() {
+        let json = r#"{
+            "room_id": "!x:domain",
+            "sender": "@a:domain",
+            "origin": "domain",
+            "origin_server_ts": 1000000,
+            "signatures": {
+                "domain": {
+                    "ed25519:1": "PxOFMn6ORll8PFSQp0IRF6037MEZt3Mfzu/ROiT/gb/ccs1G+f6Ddoswez4KntLPBI3GKCGIkhctiK37JOy2Aw"
+                }
+            },
+            "hashes": {
+                "sha256": "invalid"
+            },
+            "type": "X",
+            "content": {},
+            "prev_events": [],
+            "auth_events": [],
+            "depth": 3,
+            "unsigned": {
+                "age_ts": 1000000,
+                "transaction_id": "abcd"
+            }
+        }"#;
+
+        let object = from_json_str(json).unwrap();
+
+        assert_eq!(
+            content_hash(&object).unwrap().encode(),
+            "5jM4wQpv6lnBo7CLIghJuHdW+s2CMBJPUOGOC89ncos"
+        );
+    }
+
+    #[test]
+    fn content_hash_fails_for_too_large_event() {
+        let json = format!(
+            r#"{{
+                "room_id": "!x:domain",
+                "sender": "@a:domain",
+                "type": "X",
+                "content": {{ "body": "{}" }}
+            }}"#,
+            "a".repeat(65_535)
+        );
+
+        let object = from_json_str(&json).unwrap();
+
+        assert_matches!(content_hash(&object), Err(Error::PduSize));
+    }
+
+    #[test]
+    fn reference_hash_alphabet_depends_on_room_version() {
+        let json = r#"{
+            "room_id": "!x:domain",
+            "sender": "@a:domain",
+            "origin": "domain",
+            "origin_server_ts": 1000000,
+            "signatures": {},
+            "hashes": {
+                "sha256": "5jM4wQpv6lnBo7CLIghJuHdW+s2CMBJPUOGOC89ncos"
+            },
+            "type": "X",
+            "content": {},
+            "prev_events": [],
+            "auth_events": [],
+            "depth": 5,
+            "unsigned": {
+                "age_ts": 1000000
+            }
+        }"#;
+
+        let object = from_json_str(json).unwrap();
+
+        let v3_hash = reference_hash(&object, &RoomVersionId::V3).unwrap();
+        let v6_hash = reference_hash(&object, &RoomVersionId::V6).unwrap();
+        assert_eq!(v3_hash, "Dst8nddHyB+rq/NxCgoksQtbOXBhAX8zIOGo/f9ak8o");
+        assert_eq!(v6_hash, "Dst8nddHyB-rq_NxCgoksQtbOXBhAX8zIOGo_f9ak8o");
+    }
+
     #[test]
     fn sign_redacted_event() {
         let key_pair = Ed25519KeyPair::from_der(&pkcs8(), "1".into()).unwrap();
@@ -1,5 +1,7 @@
 //! Verification of digital signatures.
 
+use std::collections::{BTreeMap, BTreeSet};
+
 use ed25519_dalek::{Verifier as _, VerifyingKey};
 
 use crate::{Error, ParseError, VerificationError};
@@ -44,6 +46,12 @@ impl Verifier for Ed25519Verifier {
     }
 }
 
+/// A map from entity names to the IDs of the keys whose signatures were verified, as returned by
+/// [`verify_json_signatures`](crate::verify_json_signatures).
+///
+/// "Entity" is generally a homeserver, e.g. "example.com".
+pub type VerifiedKeyMap = BTreeMap<String, BTreeSet<String>>;
+
 /// A value returned when an event is successfully verified.
 ///
 /// Event verification involves verifying both signatures and a content hash. It is possible for
@@ -30,6 +30,7 @@ pub enum Error {
 }
 
 impl Error {
+    /// Creates a custom error from the given error.
     pub fn custom<E: std::error::Error + 'static>(e: E) -> Self {
         Self::Custom(Box::new(e))
     }
@@ -2,6 +2,7 @@ use std::{borrow::Borrow, collections::BTreeSet};
 
 use js_int::{int, Int};
 use ruma_common::{
+    room_version_rules::RoomVersionRules,
     serde::{Base64, Raw},
     OwnedUserId, RoomVersionId, UserId,
 };
@@ -24,7 +25,6 @@ use crate::{
         deserialize_power_levels, deserialize_power_levels_content_fields,
         deserialize_power_levels_content_invite, deserialize_power_levels_content_redact,
     },
-    room_version::RoomVersion,
     Error, Event, Result, StateEventType, TimelineEventType,
 };
 
@@ -122,7 +122,7 @@ pub fn auth_types_for_event(
 /// The `fetch_state` closure should gather state from a state snapshot. We need to know if the
 /// event passes auth against some state not a recursive collection of auth_events fields.
 pub fn auth_check<E: Event>(
-    room_version: &RoomVersion,
+    room_version: &RoomVersionRules,
     incoming_event: impl Event,
     current_third_party_invite: Option<impl Event>,
     fetch_state: impl Fn(&StateEventType, &str) -> Option<E>,
@@ -449,7 +449,7 @@ pub fn auth_check<E: Event>(
 /// State.
 #[allow(clippy::too_many_arguments)]
 fn valid_membership_change(
-    room_version: &RoomVersion,
+    room_version: &RoomVersionRules,
     target_user: &UserId,
     target_user_membership_event: Option<impl Event>,
     sender: &UserId,
@@ -695,8 +695,8 @@ fn valid_membership_change(
         MembershipState::Knock if room_version.allow_knocking => {
             // 1. If the `join_rule` is anything other than `knock` or `knock_restricted`, reject.
             if join_rules != JoinRule::Knock
-                || room_version.knock_restricted_join_rule
-                    && matches!(join_rules, JoinRule::KnockRestricted(_))
+                && !(room_version.knock_restricted_join_rule
+                    && matches!(join_rules, JoinRule::KnockRestricted(_)))
             {
                 warn!("Join rule is not set to knock or knock_restricted, knocking is not allowed");
                 false
@@ -753,7 +753,7 @@ fn can_send_event(event: impl Event, ple: Option<impl Event>, user_level: Int) -
 
 /// Confirm that the event sender has the required power levels.
 fn check_power_levels(
-    room_version: &RoomVersion,
+    room_version: &RoomVersionRules,
     power_event: impl Event,
     previous_power_event: Option<impl Event>,
     user_level: Int,
@@ -906,7 +906,7 @@ fn get_deserialize_levels(
 
 /// Does the event redacting come from a user with enough power to redact the given event.
 fn check_redaction(
-    _room_version: &RoomVersion,
+    _room_version: &RoomVersionRules,
     redaction_event: impl Event,
     user_level: Int,
     redact_level: Int,
@@ -1010,6 +1010,7 @@ fn verify_third_party_invite(
 mod tests {
     use std::sync::Arc;
 
+    use ruma_common::room_version_rules::RoomVersionRules;
     use ruma_events::{
         room::{
             join_rules::{
@@ -1027,7 +1028,7 @@ mod tests {
             alice, charlie, ella, event_id, member_content_ban, member_content_join, room_id,
             to_pdu_event, PduEvent, INITIAL_EVENTS, INITIAL_EVENTS_CREATE_ROOM,
         },
-        Event, EventTypeExt, RoomVersion, StateMap,
+        Event, EventTypeExt, StateMap,
     };
 
     #[test]
@@ -1056,7 +1057,7 @@ mod tests {
         let sender = alice();
 
         assert!(valid_membership_change(
-            &RoomVersion::V6,
+            &RoomVersionRules::V6,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1098,7 +1099,7 @@ mod tests {
         let sender = charlie();
 
         assert!(!valid_membership_change(
-            &RoomVersion::V6,
+            &RoomVersionRules::V6,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1140,7 +1141,7 @@ mod tests {
         let sender = alice();
 
         assert!(valid_membership_change(
-            &RoomVersion::V6,
+            &RoomVersionRules::V6,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1182,7 +1183,7 @@ mod tests {
         let sender = charlie();
 
         assert!(!valid_membership_change(
-            &RoomVersion::V6,
+            &RoomVersionRules::V6,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1241,7 +1242,7 @@ mod tests {
         let sender = ella();
 
         assert!(valid_membership_change(
-            &RoomVersion::V9,
+            &RoomVersionRules::V9,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1257,7 +1258,7 @@ mod tests {
         .unwrap());
 
         assert!(!valid_membership_change(
-            &RoomVersion::V9,
+            &RoomVersionRules::V9,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1308,7 +1309,80 @@ mod tests {
         let sender = ella();
 
         assert!(valid_membership_change(
-            &RoomVersion::V7,
+            &RoomVersionRules::V7,
+            target_user,
+            fetch_state(StateEventType::RoomMember, target_user.to_string()),
+            sender,
+            fetch_state(StateEventType::RoomMember, sender.to_string()),
+            &requester,
+            None::<PduEvent>,
+            fetch_state(StateEventType::RoomPowerLevels, "".to_owned()),
+            fetch_state(StateEventType::RoomJoinRules, "".to_owned()),
+            None,
+            &MembershipState::Leave,
+            fetch_state(StateEventType::RoomCreate, "".to_owned()).unwrap(),
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_knock_restricted() {
+        let _ =
+            tracing::subscriber::set_default(tracing_subscriber::fmt().with_test_writer().finish());
+        let mut events = INITIAL_EVENTS();
+        *events.get_mut(&event_id("IJR")).unwrap() = to_pdu_event(
+            "IJR",
+            alice(),
+            TimelineEventType::RoomJoinRules,
+            Some(""),
+            to_raw_json_value(&RoomJoinRulesEventContent::new(JoinRule::KnockRestricted(
+                Restricted::new(vec![AllowRule::RoomMembership(RoomMembership::new(
+                    room_id().to_owned(),
+                ))]),
+            )))
+            .unwrap(),
+            &["CREATE", "IMA", "IPOWER"],
+            &["IPOWER"],
+        );
+
+        let auth_events = events
+            .values()
+            .map(|ev| (ev.event_type().with_state_key(ev.state_key().unwrap()), Arc::clone(ev)))
+            .collect::<StateMap<_>>();
+
+        let requester = to_pdu_event(
+            "HELLO",
+            ella(),
+            TimelineEventType::RoomMember,
+            Some(ella().as_str()),
+            to_raw_json_value(&RoomMemberEventContent::new(MembershipState::Knock)).unwrap(),
+            &[],
+            &["IMC"],
+        );
+
+        let fetch_state = |ty, key| auth_events.get(&(ty, key)).cloned();
+        let target_user = ella();
+        let sender = ella();
+
+        // The knock_restricted join rule was introduced in room version 10.
+        assert!(valid_membership_change(
+            &RoomVersionRules::V10,
+            target_user,
+            fetch_state(StateEventType::RoomMember, target_user.to_string()),
+            sender,
+            fetch_state(StateEventType::RoomMember, sender.to_string()),
+            &requester,
+            None::<PduEvent>,
+            fetch_state(StateEventType::RoomPowerLevels, "".to_owned()),
+            fetch_state(StateEventType::RoomJoinRules, "".to_owned()),
+            None,
+            &MembershipState::Leave,
+            fetch_state(StateEventType::RoomCreate, "".to_owned()).unwrap(),
+        )
+        .unwrap());
+
+        assert!(!valid_membership_change(
+            &RoomVersionRules::V9,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
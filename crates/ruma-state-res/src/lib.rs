@@ -1,13 +1,39 @@
+#![doc(html_favicon_url = "https://ruma.io/favicon.ico")]
+#![doc(html_logo_url = "https://ruma.io/images/logo.png")]
+//! [State resolution v2] and event authorization for [Matrix](https://matrix.org/) homeservers.
+//!
+//! The main entry point is [`resolve`], which computes the resolved state of a room from several
+//! conflicting state sets. It follows the algorithm of the specification:
+//!
+//! 1. The unconflicted state and the full conflicted set are computed.
+//! 2. The control events of the full conflicted set are sorted with the reverse topological power
+//!    ordering, and [authorized][auth_check] one after the other.
+//! 3. The remaining events are sorted with the mainline ordering, and authorized one after the
+//!    other.
+//!
+//! Events are accessed through the [`Event`] trait, and fetched with a callback, so this crate can
+//! be used with any storage backend. The auth chains of the state sets, that are needed to
+//! compute the full conflicted set, can be computed with [`auth_chain`] or its cached and async
+//! variants.
+//!
+//! [State resolution v2]: https://spec.matrix.org/latest/rooms/v2/#state-resolution
+
 use std::{
     borrow::Borrow,
     cmp::Reverse,
     collections::{BinaryHeap, HashMap, HashSet},
+    future::{ready, Future},
     hash::Hash,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
 };
 
 use itertools::Itertools;
 use js_int::{int, Int};
-use ruma_common::{EventId, MilliSecondsSinceUnixEpoch, RoomVersionId};
+use ruma_common::{
+    room_version_rules::RoomVersionRules, EventId, MilliSecondsSinceUnixEpoch, RoomVersionId,
+};
 use ruma_events::{
     room::member::{MembershipState, RoomMemberEventContent},
     StateEventType, TimelineEventType,
@@ -18,7 +44,6 @@ use tracing::{debug, info, trace, warn};
 mod error;
 pub mod event_auth;
 mod power_levels;
-pub mod room_version;
 mod state_event;
 #[cfg(test)]
 mod test_utils;
@@ -26,7 +51,6 @@ mod test_utils;
 pub use error::{Error, Result};
 pub use event_auth::{auth_check, auth_types_for_event};
 use power_levels::PowerLevelsContentFields;
-pub use room_version::RoomVersion;
 pub use state_event::Event;
 
 /// A mapping of event type and state_key to some value `T`, usually an `EventId`.
@@ -34,8 +58,7 @@ pub type StateMap<T> = HashMap<(StateEventType, String), T>;
 
 /// Resolve sets of state events as they come in.
 ///
-/// Internally `StateResolution` builds a graph and an auth chain to allow for state conflict
-/// resolution.
+/// Internally, this builds a graph and an auth chain to allow for state conflict resolution.
 ///
 /// ## Arguments
 ///
@@ -45,13 +68,13 @@ pub type StateMap<T> = HashMap<(StateEventType, String), T>;
 /// * `auth_chain_sets` - The full recursive set of `auth_events` for each event in the
 ///   `state_sets`.
 ///
-/// * `fetch_event` - Any event not found in the `event_map` will defer to this closure to find the
-///   event.
+/// * `fetch_event` - A closure to get an event by its ID. Events that cannot be found are ignored
+///   unless they are needed to sort or authorize other events, in which case an error is returned.
 ///
 /// ## Invariants
 ///
-/// The caller of `resolve` must ensure that all the events are from the same room. Although this
-/// function takes a `RoomId` it does not check that each event is part of the same room.
+/// The caller of `resolve` must ensure that all the events are from the same room. This function
+/// does not check that each event is part of the same room.
 pub fn resolve<'a, E, SetIter>(
     room_version: &RoomVersionId,
     state_sets: impl IntoIterator<IntoIter = SetIter>,
@@ -107,7 +130,9 @@ where
     debug!("sorted control events: {}", sorted_control_levels.len());
     trace!("{sorted_control_levels:?}");
 
-    let room_version = RoomVersion::new(room_version)?;
+    let room_version = room_version
+        .rules()
+        .ok_or_else(|| Error::Unsupported(format!("found version `{room_version}`")))?;
     // Sequentially auth check each control event.
     let resolved_control =
         iterative_auth_check(&room_version, &sorted_control_levels, clean.clone(), &fetch_event)?;
@@ -187,8 +212,169 @@ where
     (unconflicted_state, conflicted_state)
 }
 
-/// Returns a Vec of deduped EventIds that appear in some chains but not others.
-fn get_auth_chain_diff<Id>(auth_chain_sets: Vec<HashSet<Id>>) -> impl Iterator<Item = Id>
+/// Computes the full auth chain of the given events.
+///
+/// The auth chain of an event is the set of its `auth_events`, and of their own auth chains. The
+/// given events are not part of the returned set, unless they are in the auth chain of another one
+/// of the given events.
+///
+/// This can be used to compute the `auth_chain_sets` argument of [`resolve`], by calling it with
+/// the events of each state set. To reuse the auth chains computed by previous calls, use
+/// [`auth_chain_with_cache`]. To fetch the events asynchronously, use [`auth_chain_async`].
+///
+/// # Errors
+///
+/// Returns an error if one of the events cannot be found with `fetch_event`.
+pub fn auth_chain<E: Event>(
+    event_ids: impl IntoIterator<Item = E::Id>,
+    fetch_event: impl Fn(&EventId) -> Option<E>,
+) -> Result<HashSet<E::Id>> {
+    auth_chain_with_cache(event_ids, fetch_event, &mut HashMap::new())
+}
+
+/// Computes the full auth chain of the given events, using and filling the given cache.
+///
+/// The auth chain of each of the given events is looked up in the cache, and computed and inserted
+/// into it if it is missing. Auth chains found in the cache for the auth events are also reused
+/// instead of fetching all their events again.
+///
+/// See [`auth_chain`] for more details.
+///
+/// # Errors
+///
+/// Returns an error if one of the events cannot be found with `fetch_event`.
+pub fn auth_chain_with_cache<E: Event>(
+    event_ids: impl IntoIterator<Item = E::Id>,
+    fetch_event: impl Fn(&EventId) -> Option<E>,
+    cache: &mut impl AuthChainCache<E::Id>,
+) -> Result<HashSet<E::Id>> {
+    // The events and the cached auth chains are available synchronously, so all the futures are
+    // ready as soon as they are polled.
+    now_or_never(auth_chain_async(event_ids, |event_id| ready(fetch_event(event_id)), cache))
+        .expect("the futures of synchronous callbacks should be ready")
+}
+
+/// Computes the full auth chain of the given events, fetching the events asynchronously and using
+/// and filling the given cache.
+///
+/// This is the same as [`auth_chain_with_cache`], for storage backends that can only be accessed
+/// asynchronously. Any [`AuthChainCache`] can also be used as the cache.
+///
+/// # Errors
+///
+/// Returns an error if one of the events cannot be found with `fetch_event`.
+pub async fn auth_chain_async<E, F, Fut>(
+    event_ids: impl IntoIterator<Item = E::Id>,
+    fetch_event: F,
+    cache: &mut impl AsyncAuthChainCache<E::Id>,
+) -> Result<HashSet<E::Id>>
+where
+    E: Event,
+    F: Fn(&EventId) -> Fut,
+    Fut: Future<Output = Option<E>>,
+{
+    let mut auth_chain = HashSet::new();
+
+    for event_id in event_ids {
+        if let Some(event_auth_chain) = cache.get(&event_id).await {
+            auth_chain.extend(event_auth_chain.iter().cloned());
+            continue;
+        }
+
+        let mut event_auth_chain = HashSet::new();
+        let mut stack = vec![event_id.clone()];
+
+        while let Some(event_id) = stack.pop() {
+            let event = fetch_event(event_id.borrow())
+                .await
+                .ok_or_else(|| Error::NotFound(format!("Failed to find {event_id}")))?;
+
+            for auth_event_id in event.auth_events() {
+                if !event_auth_chain.insert(auth_event_id.clone()) {
+                    continue;
+                }
+
+                match cache.get(auth_event_id).await {
+                    Some(cached) => event_auth_chain.extend(cached.iter().cloned()),
+                    None => stack.push(auth_event_id.clone()),
+                }
+            }
+        }
+
+        auth_chain.extend(event_auth_chain.iter().cloned());
+        cache.insert(event_id, Arc::new(event_auth_chain)).await;
+    }
+
+    Ok(auth_chain)
+}
+
+/// Polls the given future once, and returns its output if it is ready.
+fn now_or_never<F: Future>(future: F) -> Option<F::Output> {
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+
+    match pin!(future).poll(&mut Context::from_waker(&waker)) {
+        Poll::Ready(output) => Some(output),
+        Poll::Pending => None,
+    }
+}
+
+/// A cache for the auth chains of events.
+///
+/// This is used by [`auth_chain_with_cache`] and [`auth_chain_async`], so servers can keep the
+/// auth chains they computed in memory. For caches that can only be accessed asynchronously, like
+/// a database, implement [`AsyncAuthChainCache`] instead.
+pub trait AuthChainCache<Id> {
+    /// Get the auth chain of the event with the given ID, if it is in the cache.
+    fn get(&self, event_id: &Id) -> Option<Arc<HashSet<Id>>>;
+
+    /// Insert the auth chain of the event with the given ID in the cache.
+    fn insert(&mut self, event_id: Id, auth_chain: Arc<HashSet<Id>>);
+}
+
+impl<Id: Eq + Hash> AuthChainCache<Id> for HashMap<Id, Arc<HashSet<Id>>> {
+    fn get(&self, event_id: &Id) -> Option<Arc<HashSet<Id>>> {
+        HashMap::get(self, event_id).cloned()
+    }
+
+    fn insert(&mut self, event_id: Id, auth_chain: Arc<HashSet<Id>>) {
+        HashMap::insert(self, event_id, auth_chain);
+    }
+}
+
+/// A cache for the auth chains of events that is accessed asynchronously.
+///
+/// This is used by [`auth_chain_async`], so servers can keep the auth chains they computed in
+/// their storage. It is implemented for all the [`AuthChainCache`]s.
+pub trait AsyncAuthChainCache<Id> {
+    /// Get the auth chain of the event with the given ID, if it is in the cache.
+    fn get(&self, event_id: &Id) -> impl Future<Output = Option<Arc<HashSet<Id>>>>;
+
+    /// Insert the auth chain of the event with the given ID in the cache.
+    fn insert(&mut self, event_id: Id, auth_chain: Arc<HashSet<Id>>) -> impl Future<Output = ()>;
+}
+
+impl<Id, C: AuthChainCache<Id>> AsyncAuthChainCache<Id> for C {
+    fn get(&self, event_id: &Id) -> impl Future<Output = Option<Arc<HashSet<Id>>>> {
+        ready(AuthChainCache::get(self, event_id))
+    }
+
+    fn insert(&mut self, event_id: Id, auth_chain: Arc<HashSet<Id>>) -> impl Future<Output = ()> {
+        AuthChainCache::insert(self, event_id, auth_chain);
+        ready(())
+    }
+}
+
+/// Returns the deduped event IDs that appear in some of the given auth chains but not in all of
+/// them.
+///
+/// This is the auth difference of the state sets that the auth chains were computed for.
+pub fn get_auth_chain_diff<Id>(auth_chain_sets: Vec<HashSet<Id>>) -> impl Iterator<Item = Id>
 where
     Id: Eq + Hash,
 {
@@ -239,8 +425,11 @@ fn reverse_topological_power_sort<E: Event>(
     }
 
     lexicographical_topological_sort(&graph, |event_id| {
-        let ev = fetch_event(event_id).ok_or_else(|| Error::NotFound("".into()))?;
-        let pl = *event_to_pl.get(event_id).ok_or_else(|| Error::NotFound("".into()))?;
+        let ev = fetch_event(event_id)
+            .ok_or_else(|| Error::NotFound(format!("Failed to find {event_id}")))?;
+        let pl = *event_to_pl.get(event_id).ok_or_else(|| {
+            Error::NotFound(format!("Failed to find the power level of {event_id}"))
+        })?;
         Ok((pl, ev.origin_server_ts()))
     })
 }
@@ -382,7 +571,7 @@ fn get_power_level_for_sender<E: Event>(
 /// For each `events_to_check` event we gather the events needed to auth it from the the
 /// `fetch_event` closure and verify each event using the `event_auth::auth_check` function.
 fn iterative_auth_check<E: Event + Clone>(
-    room_version: &RoomVersion,
+    room_version: &RoomVersionRules,
     events_to_check: &[E::Id],
     unconflicted_state: StateMap<E::Id>,
     fetch_event: impl Fn(&EventId) -> Option<E>,
@@ -637,6 +826,7 @@ where
 #[cfg(test)]
 mod tests {
     use std::{
+        cell::Cell,
         collections::{HashMap, HashSet},
         sync::Arc,
     };
@@ -644,7 +834,10 @@ mod tests {
     use js_int::{int, uint};
     use maplit::{hashmap, hashset};
     use rand::seq::SliceRandom;
-    use ruma_common::{MilliSecondsSinceUnixEpoch, OwnedEventId, RoomVersionId};
+    use ruma_common::{
+        room_version_rules::RoomVersionRules, EventId, MilliSecondsSinceUnixEpoch, OwnedEventId,
+        RoomVersionId,
+    };
     use ruma_events::{
         room::join_rules::{JoinRule, RoomJoinRulesEventContent},
         StateEventType, TimelineEventType,
@@ -654,12 +847,11 @@ mod tests {
 
     use crate::{
         is_power_event,
-        room_version::RoomVersion,
         test_utils::{
             alice, bob, charlie, do_check, ella, event_id, member_content_ban, member_content_join,
             room_id, to_init_pdu_event, to_pdu_event, zara, PduEvent, TestStore, INITIAL_EVENTS,
         },
-        Event, EventTypeExt, StateMap,
+        AsyncAuthChainCache, Event, EventTypeExt, StateMap,
     };
 
     fn test_event_sort() {
@@ -687,7 +879,7 @@ mod tests {
             .unwrap();
 
         let resolved_power = crate::iterative_auth_check(
-            &RoomVersion::V6,
+            &RoomVersionRules::V6,
             &sorted_power_events,
             HashMap::new(), // unconflicted events
             |id| events.get(id).cloned(),
@@ -1194,6 +1386,122 @@ mod tests {
         do_check(&join_rule.values().cloned().collect::<Vec<_>>(), edges, expected_state_ids);
     }
 
+    #[test]
+    fn auth_chain_and_diff() {
+        let events = INITIAL_EVENTS();
+        let fetch_event = |id: &EventId| events.get(id).cloned();
+
+        let auth_chain_imb = crate::auth_chain([event_id("IMB")], fetch_event).unwrap();
+        assert_eq!(
+            auth_chain_imb,
+            hashset![event_id("CREATE"), event_id("IMA"), event_id("IJR"), event_id("IPOWER")]
+        );
+
+        let auth_chain_ipower = crate::auth_chain([event_id("IPOWER")], fetch_event).unwrap();
+        assert_eq!(auth_chain_ipower, hashset![event_id("CREATE"), event_id("IMA")]);
+
+        let diff = crate::get_auth_chain_diff(vec![auth_chain_imb, auth_chain_ipower])
+            .collect::<HashSet<_>>();
+        assert_eq!(diff, hashset![event_id("IJR"), event_id("IPOWER")]);
+
+        crate::auth_chain([event_id("NOT_FOUND")], fetch_event).unwrap_err();
+    }
+
+    #[test]
+    fn auth_chain_with_cache() {
+        let events = INITIAL_EVENTS();
+        let fetched = Cell::new(0);
+        let fetch_event = |id: &EventId| {
+            fetched.set(fetched.get() + 1);
+            events.get(id).cloned()
+        };
+        let mut cache = HashMap::new();
+
+        let auth_chain_ipower =
+            crate::auth_chain_with_cache([event_id("IPOWER")], fetch_event, &mut cache).unwrap();
+        assert_eq!(auth_chain_ipower, hashset![event_id("CREATE"), event_id("IMA")]);
+        assert_eq!(cache.get(&event_id("IPOWER")).map(|c| &**c), Some(&auth_chain_ipower));
+        assert_eq!(fetched.replace(0), 3);
+
+        // IPOWER and IMA, which is only in the auth chain of IPOWER, are not fetched again.
+        let auth_chain_imb =
+            crate::auth_chain_with_cache([event_id("IMB")], fetch_event, &mut cache).unwrap();
+        assert_eq!(
+            auth_chain_imb,
+            hashset![event_id("CREATE"), event_id("IMA"), event_id("IJR"), event_id("IPOWER")]
+        );
+        assert_eq!(fetched.replace(0), 3);
+
+        let auth_chain_imb_cached =
+            crate::auth_chain_with_cache([event_id("IMB")], fetch_event, &mut cache).unwrap();
+        assert_eq!(auth_chain_imb_cached, auth_chain_imb);
+        assert_eq!(fetched.get(), 0);
+    }
+
+    #[tokio::test]
+    async fn auth_chain_async() {
+        let events = INITIAL_EVENTS();
+        let fetch_event = |id: &EventId| {
+            let event = events.get(id).cloned();
+            async move { event }
+        };
+        let mut cache = HashMap::new();
+
+        let auth_chain_imb =
+            crate::auth_chain_async([event_id("IMB")], fetch_event, &mut cache).await.unwrap();
+        assert_eq!(
+            auth_chain_imb,
+            hashset![event_id("CREATE"), event_id("IMA"), event_id("IJR"), event_id("IPOWER")]
+        );
+        assert_eq!(cache.get(&event_id("IMB")).map(|c| &**c), Some(&auth_chain_imb));
+
+        crate::auth_chain_async([event_id("NOT_FOUND")], fetch_event, &mut cache)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn auth_chain_async_with_async_cache() {
+        /// A cache that yields before each access, like a database would.
+        #[derive(Default)]
+        struct YieldingCache(HashMap<OwnedEventId, Arc<HashSet<OwnedEventId>>>);
+
+        impl AsyncAuthChainCache<OwnedEventId> for YieldingCache {
+            async fn get(&self, event_id: &OwnedEventId) -> Option<Arc<HashSet<OwnedEventId>>> {
+                tokio::task::yield_now().await;
+                self.0.get(event_id).cloned()
+            }
+
+            async fn insert(
+                &mut self,
+                event_id: OwnedEventId,
+                auth_chain: Arc<HashSet<OwnedEventId>>,
+            ) {
+                tokio::task::yield_now().await;
+                self.0.insert(event_id, auth_chain);
+            }
+        }
+
+        let events = INITIAL_EVENTS();
+        let fetch_event = |id: &EventId| {
+            let event = events.get(id).cloned();
+            async move { event }
+        };
+        let mut cache = YieldingCache::default();
+
+        let auth_chain_ipower =
+            crate::auth_chain_async([event_id("IPOWER")], fetch_event, &mut cache).await.unwrap();
+        assert_eq!(auth_chain_ipower, hashset![event_id("CREATE"), event_id("IMA")]);
+        assert_eq!(cache.0.get(&event_id("IPOWER")).map(|c| &**c), Some(&auth_chain_ipower));
+
+        let auth_chain_imb =
+            crate::auth_chain_async([event_id("IMB")], fetch_event, &mut cache).await.unwrap();
+        assert_eq!(
+            auth_chain_imb,
+            hashset![event_id("CREATE"), event_id("IMA"), event_id("IJR"), event_id("IPOWER")]
+        );
+    }
+
     #[allow(non_snake_case)]
     fn BAN_STATE_SET() -> HashMap<OwnedEventId, Arc<PduEvent>> {
         vec![
@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use js_int::Int;
 use ruma_common::{
     power_levels::{default_power_level, NotificationPowerLevels},
+    room_version_rules::RoomVersionRules,
     serde::{btreemap_deserialize_v1_powerlevel_values, deserialize_v1_powerlevel},
     OwnedUserId,
 };
@@ -11,8 +12,6 @@ use serde::Deserialize;
 use serde_json::{from_str as from_json_str, Error};
 use tracing::error;
 
-use crate::RoomVersion;
-
 #[derive(Deserialize)]
 struct IntRoomPowerLevelsEventContent {
     #[serde(default = "default_power_level")]
@@ -100,7 +99,7 @@ impl From<IntNotificationPowerLevels> for NotificationPowerLevels {
 
 pub(crate) fn deserialize_power_levels(
     content: &str,
-    room_version: &RoomVersion,
+    room_version: &RoomVersionRules,
 ) -> Option<RoomPowerLevelsEventContent> {
     if room_version.integer_power_levels {
         match from_json_str::<IntRoomPowerLevelsEventContent>(content) {
@@ -150,7 +149,7 @@ impl From<IntPowerLevelsContentFields> for PowerLevelsContentFields {
 
 pub(crate) fn deserialize_power_levels_content_fields(
     content: &str,
-    room_version: &RoomVersion,
+    room_version: &RoomVersionRules,
 ) -> Result<PowerLevelsContentFields, Error> {
     if room_version.integer_power_levels {
         from_json_str::<IntPowerLevelsContentFields>(content).map(|r| r.into())
@@ -180,7 +179,7 @@ impl From<IntPowerLevelsContentInvite> for PowerLevelsContentInvite {
 
 pub(crate) fn deserialize_power_levels_content_invite(
     content: &str,
-    room_version: &RoomVersion,
+    room_version: &RoomVersionRules,
 ) -> Result<PowerLevelsContentInvite, Error> {
     if room_version.integer_power_levels {
         from_json_str::<IntPowerLevelsContentInvite>(content).map(|r| r.into())
@@ -210,7 +209,7 @@ impl From<IntPowerLevelsContentRedact> for PowerLevelsContentRedact {
 
 pub(crate) fn deserialize_power_levels_content_redact(
     content: &str,
-    room_version: &RoomVersion,
+    room_version: &RoomVersionRules,
 ) -> Result<PowerLevelsContentRedact, Error> {
     if room_version.integer_power_levels {
         from_json_str::<IntPowerLevelsContentRedact>(content).map(|r| r.into())
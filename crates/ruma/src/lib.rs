@@ -29,10 +29,19 @@
 //!   * `client-api-c` -- The Client-Server API optimized for the client side.
 //!   * `client-api-s` -- The Client-Server API optimized for the server side.
 //!
-//! # Compatibility feature
+//! # Compatibility features
 //!
-//! * `compat` increases compatibility with other parts of the Matrix ecosystem, at the expense of
-//!   deviating from the specification.
+//! The `compat-*` features increase compatibility with other parts of the Matrix ecosystem, at the
+//! expense of deviating from the specification. Each of them enables a single workaround, for
+//! example:
+//!
+//! * `compat-empty-string-null` -- Allow some mandatory fields to be missing, defaulting them to an
+//!   empty string in deserialization.
+//! * `compat-float-integers` -- Accept integers encoded as floating-point numbers without a
+//!   fractional part, like `1.0`.
+//!
+//! See the crate's `Cargo.toml` for the full list. The deprecated `compat` feature enables most of
+//! them at once.
 //!
 //! # Convenience features
 //!
@@ -62,6 +71,19 @@
 //! * `events`
 //! * `signatures`
 //!
+//! # Other crates
+//!
+//! The following features activate the re-export of the corresponding crate:
+//!
+//! * `client` -- [`ruma::client`][client], a Matrix client library.
+//! * `html` -- [`ruma::html`][html], to sanitize and parse HTML in messages.
+//! * `server-util` -- [`ruma::server_util`][server_util], utilities for homeserver implementations.
+//! * `state-res` -- [`ruma::state_res`][state_res], an implementation of the state resolution
+//!   algorithm.
+//!
+//! The `full` feature enables all of these, along with all the API, common and convenience
+//! features.
+//!
 //! # `ruma-client` features
 //!
 //! The `client` feature activates [`ruma::client`][client], and `client-ext-client-api` activates
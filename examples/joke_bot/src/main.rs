@@ -77,7 +77,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
 
     let mut sync_stream = Box::pin(matrix_client.sync(
         Some(filter),
-        initial_sync_response.next_batch,
+        Some(initial_sync_response.next_batch),
         PresenceState::Online,
         Some(Duration::from_secs(30)),
     ));
@@ -33,7 +33,7 @@ async fn log_messages(
 
     let mut sync_stream = Box::pin(client.sync(
         None,
-        initial_sync_response.next_batch,
+        Some(initial_sync_response.next_batch),
         PresenceState::Online,
         Some(Duration::from_secs(30)),
     ));
@@ -42,6 +42,8 @@ pub enum CiCmd {
     StableClient,
     /// Check ruma-common with only the required features (stable)
     StableCommon,
+    /// Check ruma-events without default features (stable)
+    StableEvents,
     /// Run all tests with almost all features (stable)
     TestAll,
     /// Run doc tests with almost all features (stable)
@@ -56,7 +58,7 @@ pub enum CiCmd {
     NightlyAll,
     /// Lint default features with clippy (nightly)
     ClippyDefault,
-    /// Lint ruma-common with clippy on a wasm target (nightly)
+    /// Lint ruma-common and ruma-client with clippy on a wasm target (nightly)
     ClippyWasm,
     /// Lint almost all features with clippy (nightly)
     ClippyAll,
@@ -99,6 +101,7 @@ impl CiTask {
             Some(CiCmd::StableAll) => self.stable_all()?,
             Some(CiCmd::StableClient) => self.stable_client()?,
             Some(CiCmd::StableCommon) => self.stable_common()?,
+            Some(CiCmd::StableEvents) => self.stable_events()?,
             Some(CiCmd::TestAll) => self.test_all()?,
             Some(CiCmd::TestDoc) => self.test_doc()?,
             Some(CiCmd::Nightly) => self.nightly()?,
@@ -166,6 +169,7 @@ impl CiTask {
         self.stable_all()?;
         self.stable_client()?;
         self.stable_common()?;
+        self.stable_events()?;
         self.test_all()?;
         self.test_doc()?;
         Ok(())
@@ -193,9 +197,22 @@ impl CiTask {
         .map_err(Into::into)
     }
 
+    /// Check ruma-events without default features with the stable version.
+    fn stable_events(&self) -> Result<()> {
+        cmd!("rustup run stable cargo check -p ruma-events --no-default-features")
+            .run()
+            .map_err(Into::into)
+    }
+
     /// Run tests on all crates with almost all features with the stable version.
+    ///
+    /// The `compat-float-integers` feature is tested separately, because it disables the tests
+    /// that check that integers encoded as floats are rejected by default.
     fn test_all(&self) -> Result<()> {
-        cmd!("rustup run stable cargo test --tests --features __ci").run().map_err(Into::into)
+        cmd!("rustup run stable cargo test --tests --features __ci").run()?;
+        cmd!("rustup run stable cargo test -p ruma-common --lib --features compat-float-integers")
+            .run()
+            .map_err(Into::into)
     }
 
     /// Run doctests on all crates with almost all features with the stable version.
@@ -269,10 +286,10 @@ impl CiTask {
         .map_err(Into::into)
     }
 
-    /// Lint ruma-common with clippy with the nightly version and wasm target.
+    /// Lint ruma-common and ruma-client with clippy with the nightly version and wasm target.
     ///
-    /// ruma-common is currently the only crate with wasm-specific code. If that changes, this
-    /// method should be updated.
+    /// ruma-common and ruma-client are currently the only crates with wasm-specific code. If that
+    /// changes, this method should be updated.
     fn clippy_wasm(&self) -> Result<()> {
         cmd!(
             "
@@ -280,6 +297,14 @@ impl CiTask {
                 -p ruma-common --features api,js,rand
             "
         )
+        .run()?;
+
+        cmd!(
+            "
+            rustup run {NIGHTLY} cargo clippy --target wasm32-unknown-unknown
+                -p ruma-client --features client-api,reqwest
+            "
+        )
         .run()
         .map_err(Into::into)
     }